@@ -3,16 +3,26 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::env;
 use std::fs;
 use std::fs::File;
 use std::io::{Read, Write};
 use std::path;
+use std::process::Command;
 use std::rc::Rc;
 
-use crate::bindgen::config::{Config, Language};
+use indexmap::IndexMap;
+
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{
+    macro_name, AutogenWarningPlacement, Config, DeclarationOrder, ExternCBlocks, Language,
+    ModuleIncludePath,
+};
+use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
-    Constant, Function, ItemContainer, ItemMap, Path as BindgenPath, Static, Struct,
+    ConditionWrite, Constant, Documentation, Function, Item, ItemContainer, ItemMap, OpaqueItem,
+    Path as BindgenPath, PrimitiveType, ReprStyle, Static, Struct, ToCondition, Type,
 };
 use crate::bindgen::writer::{Source, SourceWriter};
 
@@ -27,6 +37,28 @@ pub struct Bindings {
     constants: Vec<Constant>,
     items: Vec<ItemContainer>,
     functions: Vec<Function>,
+    /// The top-level module of each item, used when splitting the bindings by
+    /// module.
+    modules: HashMap<BindgenPath, String>,
+    /// The modules that the items of each module depend on.
+    module_dependencies: HashMap<Option<String>, BTreeSet<Option<String>>>,
+    /// The items which are used behind pointers before being defined, and so
+    /// are declared before the definitions of types.
+    forward_declarations: HashSet<BindgenPath>,
+    /// The `//!` documentation of the binding crate.
+    crate_documentation: Documentation,
+}
+
+/// An item of the header, in the manifest written by `write_manifest`.
+#[derive(Serialize)]
+struct ManifestEntry<'a> {
+    kind: &'static str,
+    c_name: &'a str,
+    rust_name: &'a str,
+    module: Option<&'a str>,
+    /// Whether a function is `#[inline]`, only written if it is.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    inline: bool,
 }
 
 #[derive(PartialEq)]
@@ -36,6 +68,7 @@ enum NamespaceOperation {
 }
 
 impl Bindings {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         config: Config,
         struct_map: ItemMap<Struct>,
@@ -43,6 +76,10 @@ impl Bindings {
         globals: Vec<Static>,
         items: Vec<ItemContainer>,
         functions: Vec<Function>,
+        modules: HashMap<BindgenPath, String>,
+        module_dependencies: HashMap<Option<String>, BTreeSet<Option<String>>>,
+        forward_declarations: HashSet<BindgenPath>,
+        crate_documentation: Documentation,
     ) -> Bindings {
         Bindings {
             config,
@@ -52,6 +89,10 @@ impl Bindings {
             constants,
             items,
             functions,
+            modules,
+            module_dependencies,
+            forward_declarations,
+            crate_documentation,
         }
     }
 
@@ -62,12 +103,34 @@ impl Bindings {
         any
     }
 
+    /// Whether the type at `path` is declared before it's defined, in which
+    /// case, in C, its definition doesn't repeat the typedef of the
+    /// declaration.
+    pub fn is_forward_declared(&self, path: &BindgenPath) -> bool {
+        if self.functions_first() {
+            return self.items.iter().any(|item| {
+                item.deref().path() == path
+                    && Self::is_exported(item)
+                    && Self::can_forward_declare(item)
+            });
+        }
+        self.forward_declarations.contains(path)
+    }
+
     pub fn struct_exists(&self, path: &BindgenPath) -> bool {
         let mut any = false;
         self.struct_map.for_items(path, |_| any = true);
         any
     }
 
+    /// The fields of the union at `path`, if it's written to the bindings.
+    pub fn union_fields(&self, path: &BindgenPath) -> Option<Vec<(String, Type, Documentation)>> {
+        self.items.iter().find_map(|item| match *item {
+            ItemContainer::Union(ref union) if union.path == *path => Some(union.fields.clone()),
+            _ => None,
+        })
+    }
+
     pub fn struct_field_names(&self, path: &BindgenPath) -> Rc<Vec<String>> {
         let mut memos = self.struct_fileds_memo.borrow_mut();
         if let Some(memo) = memos.get(path) {
@@ -92,19 +155,194 @@ impl Bindings {
         fields
     }
 
+    /// Checks that the bindings compile, by writing them to a temporary
+    /// directory as `write_to_file` would, and compiling a file which only
+    /// includes each header with `compiler` and `compiler_flags`, or `$CC` or
+    /// `$CXX` depending on the language. The check is skipped with a warning if
+    /// no compiler is configured. On failure, the error has the diagnostics of
+    /// the compiler.
+    pub fn verify_compile(&self) -> Result<(), Error> {
+        let env_var = match self.config.language {
+            Language::C => "CC",
+            Language::Cxx => "CXX",
+        };
+        let compiler = match self
+            .config
+            .compiler
+            .clone()
+            .or_else(|| env::var(env_var).ok())
+        {
+            Some(compiler) => compiler,
+            None => {
+                warn!(
+                    "Not checking that the bindings compile, since neither `compiler` nor ${} \
+                     is set.",
+                    env_var
+                );
+                return Ok(());
+            }
+        };
+        let failed = |diagnostics: String| Error::CompileFailed {
+            compiler: compiler.clone(),
+            diagnostics,
+        };
+
+        let dir = tempfile::tempdir().map_err(|e| failed(e.to_string()))?;
+        // The headers of split modules include each other with
+        // `module_include_prefix`, so they're written below it and the
+        // temporary directory is searched for includes.
+        let header_dir = match self.config.module_include_prefix {
+            Some(ref prefix)
+                if path::Path::new(prefix)
+                    .components()
+                    .all(|x| matches!(x, path::Component::Normal(..))) =>
+            {
+                dir.path().join(prefix)
+            }
+            _ => dir.path().to_owned(),
+        };
+        self.write_to_file(header_dir.join("bindings.h"));
+        let mut headers = fs::read_dir(&header_dir)
+            .and_then(|entries| {
+                entries
+                    .map(|entry| entry.map(|x| x.path()))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(|e| failed(e.to_string()))?;
+        headers.retain(|x| x.extension().and_then(|extension| extension.to_str()) != Some("json"));
+        headers.sort();
+
+        let source = dir.path().join(match self.config.language {
+            Language::C => "verify.c",
+            Language::Cxx => "verify.cpp",
+        });
+        for header in &headers {
+            fs::write(&source, format!("#include \"{}\"\n", header.display()))
+                .map_err(|e| failed(e.to_string()))?;
+            let output = Command::new(&compiler)
+                .args(&self.config.compiler_flags)
+                .arg("-I")
+                .arg(dir.path())
+                .arg("-c")
+                .arg(&source)
+                .arg("-o")
+                .arg(dir.path().join("verify.o"))
+                .output()
+                .map_err(|e| failed(e.to_string()))?;
+            if !output.status.success() {
+                let mut diagnostics = String::from_utf8_lossy(&output.stderr).into_owned();
+                diagnostics.push_str(&String::from_utf8_lossy(&output.stdout));
+                return Err(failed(diagnostics));
+            }
+        }
+        Ok(())
+    }
+
     pub fn write_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        let manifest_changed = self.config.manifest && {
+            let mut manifest = Vec::new();
+            self.write_manifest(&mut manifest);
+            Self::update_file(path.as_ref().with_extension("json"), manifest)
+        };
+
+        let changed = if self.config.split_by_module {
+            self.write_split_to_files(path.as_ref())
+        } else {
+            if self.config.umbrella_header.is_some() {
+                warn!("Not writing an umbrella header, since split_by_module isn't set.");
+            }
+            Self::update_file(path, self.to_string().into_bytes())
+        };
+        changed || manifest_changed
+    }
+
+    /// Writes a JSON list of the constants, types, globals and functions in
+    /// the header, with their C and Rust names and the module they come from.
+    pub fn write_manifest<F: Write>(&self, mut out: F) {
+        let module = |path: &BindgenPath| self.modules.get(path).map(String::as_str);
+        let constants = self.constants.iter().map(|x| ManifestEntry {
+            kind: "constant",
+            c_name: x.export_name(),
+            rust_name: x.path.name(),
+            module: module(&x.path),
+            inline: false,
+        });
+        let items = self
+            .items
+            .iter()
+            .filter(|x| Self::is_exported(x))
+            .map(|x| ManifestEntry {
+                kind: match *x {
+                    ItemContainer::Constant(..) => "constant",
+                    ItemContainer::Static(..) => "global",
+                    ItemContainer::Enum(..) => "enum",
+                    ItemContainer::Struct(..) => "struct",
+                    ItemContainer::Union(..) => "union",
+                    ItemContainer::OpaqueItem(..) => "opaque",
+                    ItemContainer::Typedef(..) => "typedef",
+                },
+                c_name: x.deref().export_name(),
+                rust_name: x.deref().path().name(),
+                module: module(x.deref().path()),
+                inline: false,
+            });
+        let globals = self.globals.iter().map(|x| ManifestEntry {
+            kind: "global",
+            c_name: x.export_name(),
+            rust_name: &x.rust_name,
+            module: module(&x.path),
+            inline: false,
+        });
+        let functions = self.functions.iter().map(|x| ManifestEntry {
+            kind: "function",
+            c_name: x.path().name(),
+            rust_name: &x.rust_name,
+            module: module(x.path()),
+            inline: x.is_inline(),
+        });
+        let entries: Vec<_> = constants
+            .chain(items)
+            .chain(globals)
+            .chain(functions)
+            .collect();
+
+        serde_json::to_writer_pretty(&mut out, &entries).unwrap();
+        out.write_all(b"\n").unwrap();
+    }
+
+    /// Renders the whole header to a string, exactly as `write_to_file` would
+    /// write it (without `split_by_module`).
+    #[allow(clippy::inherent_to_string)]
+    pub fn to_string(&self) -> String {
+        let mut contents = Vec::new();
+        self.write(&mut contents);
+        // Everything we write comes from strings, so it's valid UTF-8.
+        String::from_utf8(contents).unwrap()
+    }
+
+    /// Like `write_to_file`, but for `write_forward_declarations`.
+    #[allow(unused)]
+    pub fn write_forward_declarations_to_file<P: AsRef<path::Path>>(&self, path: P) -> bool {
+        let mut new_file_contents = Vec::new();
+        self.write_forward_declarations(&mut new_file_contents);
+        Self::update_file(path, new_file_contents)
+    }
+
+    /// Writes `new_file_contents` to `path` unless it already has them, and
+    /// returns whether it did.
+    fn update_file<P: AsRef<path::Path>>(path: P, new_file_contents: Vec<u8>) -> bool {
         // Don't compare files if we've never written this file before
         if !path.as_ref().is_file() {
             if let Some(parent) = path::Path::new(path.as_ref()).parent() {
                 fs::create_dir_all(parent).unwrap();
             }
-            self.write(File::create(path).unwrap());
+            File::create(path)
+                .unwrap()
+                .write_all(&new_file_contents)
+                .unwrap();
             return true;
         }
 
-        let mut new_file_contents = Vec::new();
-        self.write(&mut new_file_contents);
-
         let mut old_file_contents = Vec::new();
         {
             let mut old_file = File::open(&path).unwrap();
@@ -120,13 +358,187 @@ impl Bindings {
         }
     }
 
+    /// Writes the items of each top-level module to their own header next to
+    /// `path`, and the remaining ones to `path` itself.
+    fn write_split_to_files(&self, path: &path::Path) -> bool {
+        let mut modules = BTreeSet::new();
+        modules.insert(None);
+        modules.extend(self.modules.values().cloned().map(Some));
+
+        let module_file_name = |module: &Option<String>| -> String {
+            let file_name = path.file_name().unwrap().to_string_lossy();
+            match *module {
+                Some(ref module) => match path.extension() {
+                    Some(extension) => format!(
+                        "{}_{}.{}",
+                        path.file_stem().unwrap().to_string_lossy(),
+                        module,
+                        extension.to_string_lossy()
+                    ),
+                    None => format!("{}_{}", file_name, module),
+                },
+                None => file_name.into_owned(),
+            }
+        };
+
+        let module_include = |module: &Option<String>| -> String {
+            match self.config.module_include_path {
+                ModuleIncludePath::FileName => format!(
+                    "{}{}",
+                    self.config.module_include_prefix.as_deref().unwrap_or(""),
+                    module_file_name(module)
+                ),
+                ModuleIncludePath::Absolute => {
+                    let path = path.with_file_name(module_file_name(module));
+                    match env::current_dir() {
+                        Ok(dir) => dir.join(path).display().to_string(),
+                        Err(..) => path.display().to_string(),
+                    }
+                }
+            }
+        };
+
+        let mut changed = false;
+        for module in &modules {
+            let is_in_module = |path: &BindgenPath| self.modules.get(path) == module.as_ref();
+
+            let mut config = self.config.clone();
+            config.split_by_module = false;
+            if let Some(ref module) = *module {
+                if let Some(ref mut include_guard) = config.include_guard {
+                    include_guard.push('_');
+                    include_guard.push_str(&module.to_uppercase());
+                }
+            }
+            if let Some(dependencies) = self.module_dependencies.get(module) {
+                config
+                    .includes
+                    .extend(dependencies.iter().map(module_include));
+            }
+
+            let bindings = Bindings::new(
+                config,
+                self.struct_map.clone(),
+                self.constants
+                    .iter()
+                    .filter(|x| is_in_module(&x.path))
+                    .cloned()
+                    .collect(),
+                self.globals
+                    .iter()
+                    .filter(|x| is_in_module(&x.path))
+                    .cloned()
+                    .collect(),
+                self.items
+                    .iter()
+                    .filter(|x| is_in_module(x.deref().path()))
+                    .cloned()
+                    .collect(),
+                self.functions
+                    .iter()
+                    .filter(|x| is_in_module(&x.path))
+                    .cloned()
+                    .collect(),
+                HashMap::new(),
+                HashMap::new(),
+                self.forward_declarations.clone(),
+                self.crate_documentation.clone(),
+            );
+            changed |= bindings.write_to_file(path.with_file_name(module_file_name(module)));
+        }
+
+        if let Some(ref umbrella_header) = self.config.umbrella_header {
+            if *umbrella_header == module_file_name(&None) {
+                warn!(
+                    "Not writing the umbrella header {}, since it's the main header.",
+                    umbrella_header
+                );
+            } else {
+                let mut order = Vec::with_capacity(modules.len());
+                for module in &modules {
+                    self.visit_module(module, &mut order);
+                }
+                let includes = order.into_iter().map(module_include).collect();
+                let mut contents = Vec::new();
+                self.write_umbrella(&mut contents, umbrella_header, includes);
+                changed |= Self::update_file(path.with_file_name(umbrella_header), contents);
+            }
+        }
+        changed
+    }
+
+    /// Adds `module` to `order`, after the modules it depends on.
+    fn visit_module<'a>(&'a self, module: &'a Option<String>, order: &mut Vec<&'a Option<String>>) {
+        if order.contains(&module) {
+            return;
+        }
+        // Modules which depend on each other aren't split, so there are no
+        // cycles here.
+        if let Some(dependencies) = self.module_dependencies.get(module) {
+            for dependency in dependencies {
+                self.visit_module(dependency, order);
+            }
+        }
+        order.push(module);
+    }
+
+    /// Writes a header which only includes `includes`, with its own include
+    /// guard.
+    fn write_umbrella<F: Write>(&self, file: F, file_name: &str, includes: Vec<String>) {
+        let include_guard = self
+            .config
+            .include_guard
+            .as_ref()
+            .map(|x| format!("{}_UMBRELLA", x));
+        let config = Config {
+            split_by_module: false,
+            no_includes: true,
+            base_header: None,
+            sys_includes: Vec::new(),
+            includes,
+            after_includes: None,
+            include_guard: Some(
+                include_guard.unwrap_or_else(|| macro_name(&file_name.to_uppercase())),
+            ),
+            ..self.config.clone()
+        };
+        let bindings = Bindings::new(
+            config,
+            self.struct_map.clone(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            HashMap::new(),
+            HashMap::new(),
+            HashSet::new(),
+            self.crate_documentation.clone(),
+        );
+        let mut out = SourceWriter::new(file, &bindings);
+        bindings.write_headers(&mut out);
+        bindings.write_autogen_warning(&mut out, AutogenWarningPlacement::TopAndBottom);
+        bindings.write_trailers(&mut out, bindings.config.include_guard.as_deref());
+    }
+
     pub fn write_headers<F: Write>(&self, out: &mut SourceWriter<F>) {
+        self.write_headers_with_guard(out, self.config.include_guard.as_deref());
+    }
+
+    fn write_headers_with_guard<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        include_guard: Option<&str>,
+    ) {
         if let Some(ref f) = self.config.header {
             out.new_line_if_not_start();
             write!(out, "{}", f);
             out.new_line();
         }
-        if let Some(ref f) = self.config.include_guard {
+        if self.config.crate_documentation && !self.crate_documentation.doc_comment.is_empty() {
+            out.new_line_if_not_start();
+            self.crate_documentation.write(&self.config, out);
+        }
+        if let Some(f) = include_guard {
             out.new_line_if_not_start();
             write!(out, "#ifndef {}", f);
             out.new_line();
@@ -147,13 +559,10 @@ impl Bindings {
             );
             out.new_line();
         }
-        if let Some(ref f) = self.config.autogen_warning {
-            out.new_line_if_not_start();
-            write!(out, "{}", f);
-            out.new_line();
-        }
+        self.write_autogen_warning(out, AutogenWarningPlacement::Top);
 
         if self.config.no_includes
+            && self.config.base_header.is_none()
             && self.config.sys_includes.is_empty()
             && self.config.includes.is_empty()
             && self.config.after_includes.is_none()
@@ -163,45 +572,56 @@ impl Bindings {
 
         out.new_line_if_not_start();
 
+        // The includes of the config are written after the ones cbindgen
+        // needs, skipping any that were already written.
+        let mut includes = Vec::new();
+        if let Some(ref base_header) = self.config.base_header {
+            includes.push(format!("#include \"{}\"", base_header));
+        }
+
         if !self.config.no_includes {
-            if self.config.language == Language::C {
-                out.write("#include <stdarg.h>");
-                out.new_line();
-                out.write("#include <stdbool.h>");
-                out.new_line();
-                out.write("#include <stdint.h>");
-                out.new_line();
-                out.write("#include <stdlib.h>");
-                out.new_line();
+            let sys_includes: &[&str] = if self.config.language == Language::C {
+                &["stdarg.h", "stdbool.h", "stdint.h", "stdlib.h"]
             } else {
-                out.write("#include <cstdarg>");
-                out.new_line();
-                out.write("#include <cstdint>");
-                out.new_line();
-                out.write("#include <cstdlib>");
-                out.new_line();
-                out.write("#include <ostream>");
-                out.new_line();
-                out.write("#include <new>");
-                out.new_line();
+                &["cstdarg", "cstdint", "cstdlib", "ostream", "new"]
+            };
+            includes.extend(sys_includes.iter().map(|x| format!("#include <{}>", x)));
+            if self.config.language == Language::Cxx {
+                if self
+                    .unique_ptr_destructors()
+                    .iter()
+                    .any(|&(item, function)| Self::is_destructor_of(function, item))
+                {
+                    includes.push("#include <memory>".to_owned());
+                }
                 if self.config.enumeration.cast_assert_name.is_none()
                     && (self.config.enumeration.derive_mut_casts
                         || self.config.enumeration.derive_const_casts)
                 {
-                    out.write("#include <cassert>");
-                    out.new_line();
+                    includes.push("#include <cassert>".to_owned());
                 }
             }
         }
 
-        for include in &self.config.sys_includes {
-            write!(out, "#include <{}>", include);
-            out.new_line();
-        }
+        includes.extend(
+            self.config
+                .sys_includes
+                .iter()
+                .map(|x| format!("#include <{}>", x)),
+        );
+        includes.extend(
+            self.config
+                .includes
+                .iter()
+                .map(|x| format!("#include \"{}\"", x)),
+        );
 
-        for include in &self.config.includes {
-            write!(out, "#include \"{}\"", include);
-            out.new_line();
+        let mut written = HashSet::new();
+        for include in includes {
+            if written.insert(include.clone()) {
+                write!(out, "{}", include);
+                out.new_line();
+            }
         }
 
         if let Some(ref line) = self.config.after_includes {
@@ -217,51 +637,194 @@ impl Bindings {
 
         self.open_namespaces(&mut out);
 
+        let has_primitive_constants = self.constants.iter().any(|x| x.uses_only_primitive_types());
+        self.write_region_start(&mut out, "Constants", has_primitive_constants);
         for constant in &self.constants {
             if constant.uses_only_primitive_types() {
-                out.new_line_if_not_start();
+                out.new_lines_between_items();
+                self.write_before(&mut out, &constant.path);
                 constant.write(&self.config, &mut out, None);
                 out.new_line();
+                self.write_after(&mut out, &constant.path);
             }
         }
+        self.write_region_end(&mut out, "Constants", has_primitive_constants);
 
-        for item in &self.items {
-            if item
-                .deref()
-                .annotations()
-                .bool("no-export")
-                .unwrap_or(false)
-            {
-                continue;
+        let has_types = self.items.iter().any(Self::is_exported);
+        if self.functions_first() {
+            // Declare the types for the functions, and define them after.
+            self.write_region_start(&mut out, "Types", has_types);
+            for item in &self.items {
+                if !Self::is_exported(item) {
+                    continue;
+                }
+                out.new_lines_between_items();
+                if Self::can_forward_declare(item) {
+                    self.write_forward_declaration(&mut out, item, false);
+                    out.new_line();
+                } else {
+                    self.write_item(&mut out, item);
+                }
             }
+            self.write_region_end(&mut out, "Types", has_types);
 
-            out.new_line_if_not_start();
-            match *item {
-                ItemContainer::Constant(..) => unreachable!(),
-                ItemContainer::Static(..) => unreachable!(),
-                ItemContainer::Enum(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Struct(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Union(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::OpaqueItem(ref x) => x.write(&self.config, &mut out),
-                ItemContainer::Typedef(ref x) => x.write(&self.config, &mut out),
+            self.write_functions(&mut out);
+            self.write_autogen_warning(&mut out, AutogenWarningPlacement::Everywhere);
+
+            let has_definitions = self.items.iter().any(|x| {
+                Self::is_exported(x)
+                    && Self::can_forward_declare(x)
+                    && !Self::is_defined_by_forward_declaration(x)
+            });
+            self.write_region_start(&mut out, "Types", has_definitions);
+            for item in &self.items {
+                if Self::is_exported(item)
+                    && Self::can_forward_declare(item)
+                    && !Self::is_defined_by_forward_declaration(item)
+                {
+                    out.new_lines_between_items();
+                    self.write_item(&mut out, item);
+                }
             }
+            self.write_region_end(&mut out, "Types", has_definitions);
+            self.write_non_primitive_constants(&mut out);
+        } else {
+            self.write_region_start(&mut out, "Types", has_types);
+            for item in &self.items {
+                if Self::is_exported(item)
+                    && self.forward_declarations.contains(item.deref().path())
+                {
+                    out.new_lines_between_items();
+                    self.write_forward_declaration(&mut out, item, false);
+                    out.new_line();
+                }
+            }
+            for item in &self.items {
+                if Self::is_exported(item) {
+                    out.new_lines_between_items();
+                    self.write_item(&mut out, item);
+                }
+            }
+            self.write_region_end(&mut out, "Types", has_types);
+            self.write_non_primitive_constants(&mut out);
+            self.write_autogen_warning(&mut out, AutogenWarningPlacement::Everywhere);
+
+            self.write_functions(&mut out);
+        }
+
+        self.close_namespaces(&mut out);
+
+        self.write_autogen_warning(&mut out, AutogenWarningPlacement::TopAndBottom);
+
+        self.write_trailers(&mut out, self.config.include_guard.as_deref());
+    }
+
+    /// Writes the autogen warning if it's configured to be written at least
+    /// as often as `placement`.
+    fn write_autogen_warning<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        placement: AutogenWarningPlacement,
+    ) {
+        let written = match (self.config.autogen_warning_placement, placement) {
+            (AutogenWarningPlacement::Everywhere, _) => true,
+            (AutogenWarningPlacement::TopAndBottom, AutogenWarningPlacement::Everywhere) => false,
+            (AutogenWarningPlacement::TopAndBottom, _) => true,
+            (AutogenWarningPlacement::Top, placement) => placement == AutogenWarningPlacement::Top,
+        };
+        if let (true, Some(ref f)) = (written, &self.config.autogen_warning) {
+            out.new_line_if_not_start();
+            write!(out, "{}", f);
+            out.new_line();
+        }
+    }
+
+    /// Whether functions are written before the definitions of types.
+    fn functions_first(&self) -> bool {
+        self.config.declaration_order == DeclarationOrder::FunctionsFirst
+            && (self.config.language == Language::Cxx || self.config.style.generate_tag())
+    }
+
+    fn is_exported(item: &ItemContainer) -> bool {
+        !item
+            .deref()
+            .annotations()
+            .bool("no-export")
+            .unwrap_or(false)
+    }
+
+    /// Fieldless enums without a repr can't be forward-declared.
+    fn can_forward_declare(item: &ItemContainer) -> bool {
+        match *item {
+            ItemContainer::Enum(ref x) => x.tag.is_some() || x.repr.ty.is_some(),
+            _ => true,
+        }
+    }
+
+    /// Whether `write_forward_declaration` already writes the whole item.
+    fn is_defined_by_forward_declaration(item: &ItemContainer) -> bool {
+        match *item {
+            ItemContainer::Typedef(..) | ItemContainer::OpaqueItem(..) => true,
+            ItemContainer::Struct(ref x) => x.is_transparent,
+            _ => false,
+        }
+    }
+
+    fn write_item<F: Write>(&self, out: &mut SourceWriter<F>, item: &ItemContainer) {
+        self.write_before(out, item.deref().path());
+        match *item {
+            ItemContainer::Constant(..) => unreachable!(),
+            ItemContainer::Static(..) => unreachable!(),
+            ItemContainer::Enum(ref x) => x.write(&self.config, out),
+            ItemContainer::Struct(ref x) => x.write(&self.config, out),
+            ItemContainer::Union(ref x) => x.write(&self.config, out),
+            ItemContainer::OpaqueItem(ref x) => x.write(&self.config, out),
+            ItemContainer::Typedef(ref x) => x.write(&self.config, out),
+        }
+        out.new_line();
+        self.write_after(out, item.deref().path());
+    }
+
+    /// Writes the start of the region `name`, with `emit_region_markers`, if
+    /// it has any content.
+    fn write_region_start<F: Write>(&self, out: &mut SourceWriter<F>, name: &str, is_used: bool) {
+        if self.config.emit_region_markers && is_used {
+            out.new_line_if_not_start();
+            write!(out, "{}", self.config.region_start.replace("{name}", name));
+            out.new_line();
+        }
+    }
+
+    /// Writes the end of the region `name`, with `emit_region_markers`, if it
+    /// has any content.
+    fn write_region_end<F: Write>(&self, out: &mut SourceWriter<F>, name: &str, is_used: bool) {
+        if self.config.emit_region_markers && is_used {
+            out.new_line_if_not_start();
+            write!(out, "{}", self.config.region_end.replace("{name}", name));
             out.new_line();
         }
+    }
 
+    fn write_non_primitive_constants<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let is_used = self
+            .constants
+            .iter()
+            .any(|x| !x.uses_only_primitive_types());
+        self.write_region_start(out, "Constants", is_used);
         for constant in &self.constants {
             if !constant.uses_only_primitive_types() {
-                out.new_line_if_not_start();
-                constant.write(&self.config, &mut out, None);
+                out.new_lines_between_items();
+                self.write_before(out, &constant.path);
+                constant.write(&self.config, out, None);
                 out.new_line();
+                self.write_after(out, &constant.path);
             }
         }
+        self.write_region_end(out, "Constants", is_used);
+    }
 
+    fn write_functions<F: Write>(&self, out: &mut SourceWriter<F>) {
         if !self.functions.is_empty() || !self.globals.is_empty() {
-            if self.config.language == Language::C && self.config.cpp_compat {
-                out.new_line_if_not_start();
-                out.write("#ifdef __cplusplus");
-            }
-
             if self.config.language == Language::Cxx {
                 if let Some(ref using_namespaces) = self.config.using_namespaces {
                     for namespace in using_namespaces {
@@ -272,49 +835,468 @@ impl Bindings {
                 }
             }
 
-            if self.config.language == Language::Cxx || self.config.cpp_compat {
-                out.new_line();
-                out.write("extern \"C\" {");
-                out.new_line();
+            for (globals, functions) in self.extern_c_blocks() {
+                self.write_extern_c_block(out, &globals, &functions);
             }
+        }
 
-            if self.config.language == Language::C && self.config.cpp_compat {
-                out.write("#endif // __cplusplus");
-                out.new_line();
+        self.write_unique_ptr_deleters(out);
+        self.write_generic_macros(out);
+    }
+
+    /// The globals and functions of each `extern "C"` block, see
+    /// `Config::extern_c_blocks`.
+    fn extern_c_blocks(&self) -> Vec<(Vec<&Static>, Vec<&Function>)> {
+        if self.config.extern_c_blocks == ExternCBlocks::Single {
+            return vec![(
+                self.globals.iter().collect(),
+                self.functions.iter().collect(),
+            )];
+        }
+        let mut blocks: BTreeMap<Option<&str>, (Vec<&Static>, Vec<&Function>)> = BTreeMap::new();
+        let module = |path: &BindgenPath| self.modules.get(path).map(String::as_str);
+        for global in &self.globals {
+            blocks
+                .entry(module(&global.path))
+                .or_default()
+                .0
+                .push(global);
+        }
+        for function in &self.functions {
+            blocks
+                .entry(module(&function.path))
+                .or_default()
+                .1
+                .push(function);
+        }
+        blocks.into_values().collect()
+    }
+
+    fn write_extern_c_block<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        globals: &[&Static],
+        functions: &[&Function],
+    ) {
+        if self.config.language == Language::C && self.config.cpp_compat {
+            out.new_line_if_not_start();
+            out.write("#ifdef __cplusplus");
+        }
+
+        if self.config.language == Language::Cxx || self.config.cpp_compat {
+            out.new_line();
+            out.write("extern \"C\" {");
+            out.new_line();
+        }
+
+        if self.config.language == Language::C && self.config.cpp_compat {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+
+        self.write_region_start(out, "Globals", !globals.is_empty());
+        for global in globals {
+            out.new_lines_between_items();
+            self.write_before(out, &global.path);
+            global.write(&self.config, out);
+            out.new_line();
+            self.write_after(out, &global.path);
+        }
+        self.write_region_end(out, "Globals", !globals.is_empty());
+
+        self.write_region_start(out, "Functions", !functions.is_empty());
+        for function in functions {
+            out.new_lines_between_items();
+            self.write_before(out, &function.path);
+            function.write(&self.config, out);
+            out.new_line();
+            self.write_after(out, &function.path);
+        }
+        self.write_region_end(out, "Functions", !functions.is_empty());
+
+        if self.config.language == Language::C && self.config.cpp_compat {
+            out.new_line();
+            out.write("#ifdef __cplusplus");
+        }
+
+        if self.config.language == Language::Cxx || self.config.cpp_compat {
+            out.new_line();
+            out.write("} // extern \"C\"");
+            out.new_line();
+        }
+
+        if self.config.language == Language::C && self.config.cpp_compat {
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        }
+    }
+
+    /// The functions to dispatch to with a C11 `_Generic` macro, by the name
+    /// of the macro. Functions are grouped by their `generic` annotation, or,
+    /// with `fn.generic_dispatch`, when several are named `name_T` where `T`
+    /// is the Rust name of the type of their first argument, or of what it
+    /// points to, e.g. `add_i32(i32, i32)` and `add_f32(f32, f32)`.
+    fn generic_macros(&self) -> IndexMap<String, Vec<&Function>> {
+        // Whether each group is named by annotations, and its functions.
+        let mut groups: IndexMap<String, (bool, Vec<&Function>)> = IndexMap::new();
+        if self.config.language != Language::C {
+            return IndexMap::new();
+        }
+        for function in &self.functions {
+            let (name, is_annotated) = match function.annotations.atom("generic") {
+                Some(Some(name)) => (name, true),
+                _ if self.config.function.generic_dispatch => {
+                    let type_name = match Self::first_arg_type_name(function) {
+                        Some(type_name) => type_name,
+                        None => continue,
+                    };
+                    let suffix = format!("_{}", type_name);
+                    let name = function.path.name();
+                    if name.len() <= suffix.len() || !name.ends_with(&suffix) {
+                        continue;
+                    }
+                    (name[..name.len() - suffix.len()].to_owned(), false)
+                }
+                _ => continue,
+            };
+            let group = groups.entry(name).or_insert((false, vec![]));
+            group.0 |= is_annotated;
+            group.1.push(function);
+        }
+        groups
+            .into_iter()
+            .filter(|&(_, (is_annotated, ref functions))| is_annotated || functions.len() > 1)
+            .map(|(name, (_, functions))| (name, functions))
+            .collect()
+    }
+
+    /// The Rust name of the type of the first argument of `function`, or of
+    /// what it points to.
+    fn first_arg_type_name(function: &Function) -> Option<&str> {
+        let mut ty = &function.args.first()?.ty;
+        while let Type::Ptr {
+            ty: ref pointee, ..
+        } = *ty
+        {
+            ty = pointee;
+        }
+        match *ty {
+            Type::Primitive(ref prim) => Some(prim.to_repr_rust()),
+            Type::Path(ref generic) => Some(generic.path().name()),
+            _ => None,
+        }
+    }
+
+    /// Why the `_Generic` macro `name` can't dispatch to `functions`, if it
+    /// can't.
+    fn generic_macro_problem(&self, name: &str, functions: &[&Function]) -> Option<&'static str> {
+        let arity = functions[0].args.len();
+        if arity == 0 || functions.iter().any(|x| x.args.len() != arity) {
+            return Some("the functions don't all have the same number of arguments");
+        }
+        let first_arg_types: Vec<_> = functions
+            .iter()
+            .map(|x| Self::generic_association_type(&x.args[0].ty, None))
+            .collect();
+        if (0..functions.len()).any(|i| first_arg_types[..i].contains(&first_arg_types[i])) {
+            Some("several functions have the same type of first argument")
+        } else if functions.iter().any(|x| x.cfg.is_some()) {
+            Some("some of the functions depend on cfgs")
+        } else if self.functions.iter().any(|x| x.path.name() == name) {
+            Some("it has the name of a function")
+        } else {
+            None
+        }
+    }
+
+    /// `ty` without the distinctions between pointers and references which
+    /// don't exist in C, and with a pointer to `is_const` data if given.
+    fn generic_association_type(ty: &Type, is_const: Option<bool>) -> Type {
+        match *ty {
+            Type::Ptr {
+                ref ty,
+                is_const: was_const,
+                ..
+            } => Type::Ptr {
+                ty: ty.clone(),
+                is_const: is_const.unwrap_or(was_const),
+                is_nullable: true,
+                is_ref: false,
+            },
+            _ => ty.clone(),
+        }
+    }
+
+    /// Writes a C11 `_Generic` macro for each group of `generic_macros`,
+    /// calling the function for the type of the first argument, like
+    /// `#define add(a, b) _Generic((a), int32_t: add_i32, float: add_f32)(a, b)`
+    /// split over several lines.
+    fn write_generic_macros<F: Write>(&self, out: &mut SourceWriter<F>) {
+        let macros = self.generic_macros();
+        let mut written = 0;
+        for (name, functions) in &macros {
+            if let Some(problem) = self.generic_macro_problem(name, functions) {
+                warn!(
+                    "Not writing the _Generic macro {}, since {}.",
+                    name, problem
+                );
+                continue;
+            }
+            // The type of each function's first argument as written in C, and
+            // the function. Functions taking a const pointer are also called
+            // with a mutable one, unless another function takes it.
+            let mut associations: Vec<_> = functions
+                .iter()
+                .map(|&x| (Self::generic_association_type(&x.args[0].ty, None), x))
+                .collect();
+            for &function in functions {
+                let mutable = Self::generic_association_type(&function.args[0].ty, Some(false));
+                if !associations.iter().any(|x| x.0 == mutable) {
+                    associations.push((mutable, function));
+                }
             }
 
-            for global in &self.globals {
-                out.new_line_if_not_start();
-                global.write(&self.config, &mut out);
+            out.new_line_if_not_start();
+            if written == 0 {
+                out.write("#if defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L");
                 out.new_line();
             }
-
-            for function in &self.functions {
-                out.new_line_if_not_start();
-                function.write(&self.config, &mut out);
+            written += 1;
+            let args: Vec<_> = functions[0]
+                .args
+                .iter()
+                .enumerate()
+                .map(|(i, x)| x.name.clone().unwrap_or_else(|| format!("arg{}", i)))
+                .collect();
+            write!(
+                out,
+                "#define {}({}) _Generic(({}),",
+                self.config.define_name(name),
+                args.join(", "),
+                args[0]
+            );
+            for (i, &(ref ty, function)) in associations.iter().enumerate() {
+                out.write(if i > 0 { ", \\" } else { " \\" });
                 out.new_line();
+                out.write("  ");
+                cdecl::write_type(out, ty, &self.config);
+                write!(out, ": {}", function.path.name());
             }
+            write!(out, ")({})", args.join(", "));
+            out.new_line();
+        }
+        if written > 0 {
+            out.write("#endif");
+            out.new_line();
+        }
+    }
 
-            if self.config.language == Language::C && self.config.cpp_compat {
-                out.new_line();
-                out.write("#ifdef __cplusplus");
+    /// The opaque items which get a deleter and a `std::unique_ptr` alias in
+    /// C++, with the function named as their destructor by
+    /// `struct.destructor_name` or by their `destructor` annotation.
+    fn unique_ptr_destructors(&self) -> Vec<(&OpaqueItem, &Function)> {
+        if self.config.language != Language::Cxx {
+            return vec![];
+        }
+        let mut result = vec![];
+        for item in &self.items {
+            let item = match *item {
+                ItemContainer::OpaqueItem(ref x) if Self::is_exported(item) => x,
+                _ => continue,
+            };
+            if !self.config.structure.derive_unique_ptr(&item.annotations)
+                || !item.generic_params.is_empty()
+            {
+                continue;
             }
+            let name = self
+                .config
+                .structure
+                .destructor_name(item.export_name(), &item.annotations);
+            if let Some(function) = self.functions.iter().find(|x| x.path.name() == name) {
+                result.push((item, function));
+            }
+        }
+        result
+    }
 
-            if self.config.language == Language::Cxx || self.config.cpp_compat {
-                out.new_line();
-                out.write("} // extern \"C\"");
-                out.new_line();
+    /// Whether `function` takes a single `*mut` pointer to `item` and returns
+    /// nothing, as a destructor should.
+    fn is_destructor_of(function: &Function, item: &OpaqueItem) -> bool {
+        let takes_item = match function.args[..] {
+            [ref arg] => match arg.ty {
+                Type::Ptr {
+                    ref ty,
+                    is_const: false,
+                    ..
+                } => match **ty {
+                    Type::Path(ref generic) => generic.path() == &item.path,
+                    _ => false,
+                },
+                _ => false,
+            },
+            _ => false,
+        };
+        takes_item && function.ret == Type::Primitive(PrimitiveType::Void)
+    }
+
+    /// Writes the deleters and `std::unique_ptr` aliases of opaque items
+    /// with destructors, after the destructors are declared.
+    fn write_unique_ptr_deleters<F: Write>(&self, out: &mut SourceWriter<F>) {
+        for (item, function) in self.unique_ptr_destructors() {
+            let name = item.export_name();
+            if !Self::is_destructor_of(function, item) {
+                warn!(
+                    "Destructor {} of {} doesn't take a single `*mut {}` and return nothing; \
+                     not writing a std::unique_ptr for it.",
+                    function.path.name(),
+                    name,
+                    name
+                );
+                continue;
             }
+            let item_condition = item.cfg.to_condition(&self.config);
+            let function_condition = function.cfg.to_condition(&self.config);
 
-            if self.config.language == Language::C && self.config.cpp_compat {
-                out.write("#endif // __cplusplus");
-                out.new_line();
+            out.new_line_if_not_start();
+            item_condition.write_before(&self.config, out);
+            function_condition.write_before(&self.config, out);
+            write!(out, "struct {}Deleter", name);
+            out.open_brace();
+            write!(out, "void operator()({} *ptr) const", name);
+            out.open_brace();
+            write!(out, "{}(ptr);", function.path.name());
+            out.close_brace(false);
+            out.close_brace(true);
+            out.new_line();
+            write!(
+                out,
+                "using {}Ptr = std::unique_ptr<{}, {}Deleter>;",
+                name, name, name
+            );
+            function_condition.write_after(&self.config, out);
+            item_condition.write_after(&self.config, out);
+            out.new_line();
+        }
+    }
+
+    /// Writes only forward declarations of the types of these bindings, for
+    /// breaking include cycles: structs, unions, tagged enums and opaque items
+    /// are declared, and in C++, typedefs are repeated. Repeating a typedef is
+    /// only valid from C11, so in C, only the struct and union tags are
+    /// declared. Constants, globals and functions are left out. The include
+    /// guard, if any, gets a `_FWD` suffix.
+    #[allow(unused)]
+    pub fn write_forward_declarations<F: Write>(&self, file: F) {
+        let mut out = SourceWriter::new(file, self);
+
+        let include_guard = self
+            .config
+            .include_guard
+            .as_ref()
+            .map(|x| format!("{}_FWD", x));
+        self.write_headers_with_guard(&mut out, include_guard.as_deref());
+
+        self.open_namespaces(&mut out);
+
+        for item in &self.items {
+            if !Self::is_exported(item) || !Self::can_forward_declare(item) {
+                continue;
             }
+
+            let tag_only = self.config.language == Language::C;
+            if tag_only && !Self::has_tag(item) {
+                continue;
+            }
+
+            out.new_lines_between_items();
+            self.write_forward_declaration(&mut out, item, tag_only);
+            out.new_line();
         }
 
         self.close_namespaces(&mut out);
 
-        if let Some(ref f) = self.config.include_guard {
+        self.write_trailers(&mut out, include_guard.as_deref());
+    }
+
+    /// Whether the forward declaration of `item` declares a struct or union
+    /// tag, rather than a typedef.
+    fn has_tag(item: &ItemContainer) -> bool {
+        match *item {
+            ItemContainer::Typedef(..) => false,
+            ItemContainer::Struct(ref x) => !x.is_transparent,
+            ItemContainer::Enum(ref x) => x.tag.is_some(),
+            _ => true,
+        }
+    }
+
+    /// Writes the forward declaration of `item`, with a typedef of the struct
+    /// or union tag unless `tag_only` or the style has no typedefs.
+    fn write_forward_declaration<F: Write>(
+        &self,
+        out: &mut SourceWriter<F>,
+        item: &ItemContainer,
+        tag_only: bool,
+    ) {
+        let config = &self.config;
+        let (keyword, generic_params, cfg) = match *item {
+            ItemContainer::Constant(..) => unreachable!(),
+            ItemContainer::Static(..) => unreachable!(),
+            ItemContainer::Typedef(ref x) => return x.write(config, out),
+            ItemContainer::Struct(ref x) if x.is_transparent => {
+                return x.as_typedef().write(config, out)
+            }
+            ItemContainer::Struct(ref x) => ("struct", &x.generic_params, &x.cfg),
+            ItemContainer::Union(ref x) => ("union", &x.generic_params, &x.cfg),
+            ItemContainer::OpaqueItem(ref x) => ("struct", &x.generic_params, &x.cfg),
+            ItemContainer::Enum(ref x) if x.tag.is_some() => {
+                let keyword = if x.repr.style == ReprStyle::C {
+                    "struct"
+                } else {
+                    "union"
+                };
+                (keyword, &x.generic_params, &x.cfg)
+            }
+            ItemContainer::Enum(ref x) => {
+                let condition = x.cfg.to_condition(config);
+                condition.write_before(config, out);
+                let prim = x.repr_type_name().unwrap();
+                if config.language == Language::C && config.cpp_compat {
+                    out.write("#ifdef __cplusplus");
+                    out.new_line();
+                    write!(out, "enum {} : {};", x.export_name(), prim);
+                    out.new_line();
+                    out.write("#else");
+                    out.new_line();
+                    write!(out, "typedef {} {};", prim, x.export_name());
+                    out.new_line();
+                    out.write("#endif // __cplusplus");
+                } else if config.language == Language::C {
+                    write!(out, "typedef {} {};", prim, x.export_name());
+                } else if config.enumeration.enum_class(&x.annotations) {
+                    write!(out, "enum class {} : {};", x.export_name(), prim);
+                } else {
+                    write!(out, "enum {} : {};", x.export_name(), prim);
+                }
+                condition.write_after(config, out);
+                return;
+            }
+        };
+
+        let condition = cfg.to_condition(config);
+        condition.write_before(config, out);
+        generic_params.write(config, out);
+        let name = item.deref().export_name();
+        if config.language == Language::C && config.style.generate_typedef() && !tag_only {
+            write!(out, "typedef {} {} {};", keyword, name, name);
+        } else {
+            write!(out, "{} {};", keyword, name);
+        }
+        condition.write_after(config, out);
+    }
+
+    fn write_trailers<F: Write>(&self, out: &mut SourceWriter<F>, include_guard: Option<&str>) {
+        if let Some(f) = include_guard {
             out.new_line_if_not_start();
             if self.config.language == Language::C {
                 write!(out, "#endif /* {} */", f);
@@ -332,6 +1314,20 @@ impl Bindings {
         }
     }
 
+    fn write_before<F: Write>(&self, out: &mut SourceWriter<F>, path: &BindgenPath) {
+        if let Some(text) = self.config.export.before_item(path) {
+            out.write_raw_block(text);
+            out.new_line();
+        }
+    }
+
+    fn write_after<F: Write>(&self, out: &mut SourceWriter<F>, path: &BindgenPath) {
+        if let Some(text) = self.config.export.after_item(path) {
+            out.write_raw_block(text);
+            out.new_line();
+        }
+    }
+
     fn all_namespaces(&self) -> Vec<&str> {
         if self.config.language != Language::Cxx && !self.config.cpp_compat {
             return vec![];
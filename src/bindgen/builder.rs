@@ -306,15 +306,22 @@ impl Builder {
         self
     }
 
-    pub fn generate(self) -> Result<Bindings, Error> {
+    /// Parses the sources into a `Library` of the items they contain, without
+    /// generating bindings for them.
+    pub fn parse(mut self) -> Result<Library, Error> {
         let mut result = Parse::new();
+        let mut crate_name = None;
 
         if self.std_types {
             result.add_std_types();
         }
 
         for x in &self.srcs {
-            result.extend_with(&parser::parse_src(x, &self.config)?);
+            if x.is_dir() {
+                result.extend_with(&parser::parse_src_dir(x, &self.config)?);
+            } else {
+                result.extend_with(&parser::parse_src(x, &self.config)?);
+            }
         }
 
         if let Some((lib_dir, binding_lib_name)) = self.lib.clone() {
@@ -329,12 +336,33 @@ impl Builder {
                 /* existing_metadata = */ None,
             )?;
 
+            crate_name = Some(cargo.binding_crate_name().to_owned());
             result.extend_with(&parser::parse_lib(cargo, &self.config)?);
         } else if let Some(cargo) = self.lib_cargo.clone() {
+            crate_name = Some(cargo.binding_crate_name().to_owned());
             result.extend_with(&parser::parse_lib(cargo, &self.config)?);
         }
 
-        Library::new(
+        self.config.resolve_include_guard(crate_name.as_deref());
+        self.config.resolve_define_prefix();
+        self.config.resolve_type_mappings();
+
+        let exclude = &self.config.export.exclude;
+        let skipped: Vec<_> = result
+            .skipped
+            .into_iter()
+            .filter(|x| !exclude.contains(&x.name))
+            .collect();
+        if self.config.strict {
+            let mut skipped = skipped.clone();
+            if !skipped.is_empty() {
+                skipped.sort_by(|a, b| a.name.cmp(&b.name));
+                skipped.dedup_by(|a, b| a.description == b.description);
+                return Err(Error::SkippedPublicItems(skipped));
+            }
+        }
+
+        Ok(Library::new(
             self.config,
             result.constants,
             result.globals,
@@ -344,14 +372,30 @@ impl Builder {
             result.opaque_items,
             result.typedefs,
             result.functions,
-        )
-        .generate()
+            result.modules,
+            result.source_order,
+            result.crate_documentation,
+            skipped,
+        ))
+    }
+
+    /// Generates the bindings, and checks that they compile if
+    /// `verify_compile` is set.
+    pub fn generate(self) -> Result<Bindings, Error> {
+        let bindings = self.parse()?.generate()?;
+        if bindings.config.verify_compile {
+            bindings.verify_compile()?;
+        }
+        Ok(bindings)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::bindgen::config::{macro_name, ItemType, ModuleIncludePath};
+    use crate::bindgen::ir::Path;
+    use crate::bindgen::library::MergeConflictPolicy;
 
     #[test]
     fn with_style() {
@@ -360,4 +404,393 @@ mod tests {
             Builder::new().with_style(Style::Tag).config.style
         );
     }
+
+    #[test]
+    fn parse_exposes_items() {
+        let library = Builder::new()
+            .with_src("tests/rust/strict.rs")
+            .parse()
+            .unwrap();
+        let functions: Vec<_> = library.functions().map(|x| x.path.name()).collect();
+        assert_eq!(functions, ["point_new"]);
+        let structs: Vec<_> = library.structs().map(|x| x.path.name()).collect();
+        assert_eq!(structs, ["Point"]);
+        assert!(library.opaque_items().any(|x| x.path.name() == "Handle"));
+    }
+
+    #[test]
+    fn dependencies_of() {
+        let library = Builder::new()
+            .with_src("tests/rust/dependency_diamond.rs")
+            .parse()
+            .unwrap();
+        let names = |name: &str| -> Vec<String> {
+            library
+                .dependencies_of(&Path::new(name))
+                .iter()
+                .map(|x| x.name().to_owned())
+                .collect()
+        };
+        assert_eq!(names("Top"), ["Left", "Base", "Right"]);
+        assert_eq!(names("Right"), ["Base"]);
+        assert!(names("Base").is_empty());
+        assert!(names("Missing").is_empty());
+    }
+
+    #[test]
+    fn inline_functions() {
+        let library = Builder::new()
+            .with_src("tests/rust/function_inline.rs")
+            .parse()
+            .unwrap();
+        let mut inline: Vec<_> = library
+            .functions()
+            .filter(|x| x.is_inline())
+            .map(|x| x.path().name())
+            .collect();
+        inline.sort_unstable();
+        assert_eq!(inline, ["always", "hinted"]);
+    }
+
+    #[test]
+    fn default_repr_by_value_is_an_error() {
+        // Without the type mappings of its config, the fixture passes types
+        // without a repr by value.
+        let result = Builder::new()
+            .with_src("tests/rust/by_value_type_mapping.rs")
+            .generate();
+        match result {
+            Err(Error::DefaultReprByValue(uses)) => assert_eq!(
+                uses,
+                [
+                    "argument `h` of `handle_close` has type `Handle`",
+                    "the return value of `handle_open` has type `Handle`",
+                    "argument `bits` of `bits_set` has type `Bits`",
+                ]
+            ),
+            _ => panic!("expected passing Handle by value to fail"),
+        }
+    }
+
+    #[test]
+    fn forward_declarations() {
+        let bindings = Builder::new()
+            .with_language(Language::C)
+            .with_style(Style::Both)
+            .with_no_includes()
+            .with_include_guard("LIB_H")
+            .with_src("tests/rust/functions_first.rs")
+            .generate()
+            .unwrap();
+        let mut out = Vec::new();
+        bindings.write_forward_declarations(&mut out);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "#ifndef LIB_H_FWD\n\
+             #define LIB_H_FWD\n\
+             \n\
+             struct Handle;\n\
+             \n\
+             struct Point;\n\
+             \n\
+             struct Shape;\n\
+             \n\
+             #endif /* LIB_H_FWD */\n"
+        );
+    }
+
+    #[test]
+    fn max_specialization_depth() {
+        // The fixture needs 3 levels.
+        let config = Config {
+            language: Language::C,
+            max_specialization_depth: 2,
+            ..Config::default()
+        };
+        let result = Builder::new()
+            .with_config(config)
+            .with_src("tests/rust/max_specialization_depth.rs")
+            .generate();
+        match result {
+            Err(Error::SpecializationTooDeep(chain)) => {
+                assert_eq!(chain, ["Outer<u8>", "Middle<u8>", "Inner<u8>"]);
+            }
+            _ => panic!("expected the specialization depth to be exceeded"),
+        }
+    }
+
+    #[test]
+    fn to_string_matches_written_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let header = dir.path().join("strict.h");
+        let bindings = Builder::new()
+            .with_config(Config::from_file("tests/rust/strict.toml").unwrap())
+            .with_src("tests/rust/strict.rs")
+            .generate()
+            .unwrap();
+        assert!(bindings.write_to_file(&header));
+        let contents = bindings.to_string();
+        assert_eq!(contents, std::fs::read_to_string(&header).unwrap());
+        assert_eq!(
+            contents,
+            std::fs::read_to_string("tests/expectations/strict.cpp").unwrap()
+        );
+    }
+
+    #[test]
+    fn include_guard_from_crate_name() {
+        let mut config = Config {
+            include_guard: Some("MYORG_{crate}_H".to_owned()),
+            ..Config::default()
+        };
+        config.resolve_include_guard(Some("my-crate.v2"));
+        assert_eq!(config.include_guard.as_deref(), Some("MYORG_MY_CRATE_V2_H"));
+
+        assert_eq!(macro_name("2d--lib..rs"), "_2d_lib_rs");
+        assert_eq!(macro_name(""), "_");
+
+        let bindings = Builder::new()
+            .with_include_guard("{crate}_H")
+            .with_crate("tests/rust/mod_path_include")
+            .generate()
+            .unwrap();
+        let mut out = Vec::new();
+        bindings.write(&mut out);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.starts_with("#ifndef MOD_PATH_INCLUDE_H\n#define MOD_PATH_INCLUDE_H\n"));
+
+        // Without a crate, there's no name to put in the guard.
+        let bindings = Builder::new()
+            .with_include_guard("{crate}_H")
+            .with_src("tests/rust/mod_path_include/src")
+            .generate()
+            .unwrap();
+        let mut out = Vec::new();
+        bindings.write(&mut out);
+        assert!(!String::from_utf8(out).unwrap().contains("#ifndef"));
+    }
+
+    #[test]
+    fn define_prefix_is_sanitized() {
+        let mut config = Config::from_file("tests/rust/define_prefix.toml").unwrap();
+        config.language = Language::C;
+        config.define_prefix = Some("my-lib_".to_owned());
+        let out = Builder::new()
+            .with_config(config)
+            .with_src("tests/rust/define_prefix.rs")
+            .generate()
+            .unwrap()
+            .to_string();
+        let expected = std::fs::read_to_string("tests/expectations/define_prefix.c").unwrap();
+        assert_eq!(out, expected.replace("MYLIB_", "my_lib_"));
+    }
+
+    #[test]
+    fn verify_compile_reports_diagnostics() {
+        let config = Config {
+            language: Language::C,
+            compiler: Some("gcc".to_owned()),
+            compiler_flags: vec!["-Wall".to_owned(), "-Werror".to_owned()],
+            ..Config::default()
+        };
+        let bindings = Builder::new()
+            .with_config(config.clone())
+            .with_src("tests/rust/region_markers.rs")
+            .generate()
+            .unwrap();
+        bindings.verify_compile().unwrap();
+
+        let bindings = Builder::new()
+            .with_config(config)
+            .with_trailer("int broken = ;")
+            .with_src("tests/rust/region_markers.rs")
+            .generate()
+            .unwrap();
+        match bindings.verify_compile() {
+            Err(Error::CompileFailed {
+                compiler,
+                diagnostics,
+            }) => {
+                assert_eq!(compiler, "gcc");
+                assert!(diagnostics.contains("int broken = ;"), "{}", diagnostics);
+            }
+            result => panic!("expected the bindings not to compile: {:?}", result),
+        }
+
+        // Every header of split modules is compiled when generating.
+        let mut config = Config::from_file("tests/rust/split_by_module.toml").unwrap();
+        config.verify_compile = true;
+        config.compiler = Some("gcc".to_owned());
+        config.module_include_prefix = Some("split/".to_owned());
+        let result = Builder::new()
+            .with_config(config.clone())
+            .with_src("tests/rust/split_by_module")
+            .generate();
+        assert!(result.is_ok(), "{:?}", result.err());
+
+        config.trailer = Some("int broken = ;".to_owned());
+        let result = Builder::new()
+            .with_config(config)
+            .with_src("tests/rust/split_by_module")
+            .generate();
+        match result {
+            Err(Error::CompileFailed { diagnostics, .. }) => {
+                assert!(diagnostics.contains("int broken = ;"), "{}", diagnostics);
+            }
+            result => panic!("expected the bindings not to compile: {:?}", result.err()),
+        }
+    }
+
+    #[test]
+    fn module_include_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let header = dir.path().join("bindings.h");
+        let generate = |path, prefix: Option<&str>| {
+            let config = Config {
+                language: Language::C,
+                split_by_module: true,
+                module_include_path: path,
+                module_include_prefix: prefix.map(String::from),
+                ..Config::default()
+            };
+            let bindings = Builder::new()
+                .with_config(config)
+                .with_src("tests/rust/split_by_module")
+                .generate()
+                .unwrap();
+            bindings.write_to_file(&header);
+            let contents = std::fs::read_to_string(&header).unwrap();
+            contents
+                .lines()
+                .filter(|x| x.starts_with("#include \""))
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+
+        // The `split_by_module` fixture covers file names without a prefix.
+        assert_eq!(
+            generate(ModuleIncludePath::FileName, Some("mylib/")),
+            [
+                "#include \"mylib/bindings_geometry.h\"",
+                "#include \"mylib/bindings_limits.h\"",
+                "#include \"mylib/bindings_render.h\"",
+            ]
+        );
+        let absolute = |name| format!("#include \"{}\"", dir.path().join(name).display());
+        assert_eq!(
+            generate(ModuleIncludePath::Absolute, Some("mylib/")),
+            [
+                absolute("bindings_geometry.h"),
+                absolute("bindings_limits.h"),
+                absolute("bindings_render.h"),
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_libraries() {
+        let parse = |name: &str| {
+            Builder::new()
+                .with_language(Language::C)
+                .with_src(format!("tests/rust/{}.rs", name))
+                .parse()
+                .unwrap()
+        };
+        let first = parse("merge_first");
+        let second = parse("merge_second");
+        let conflicting = parse("merge_conflicting");
+
+        let mut library = first.clone();
+        let conflicts = library.merge(second, MergeConflictPolicy::Error).unwrap();
+        assert!(conflicts.is_empty());
+        let names: Vec<_> = library.functions().map(|x| x.path().name()).collect();
+        assert_eq!(names, ["first", "second"]);
+        assert_eq!(library.structs().count(), 2);
+
+        match library
+            .clone()
+            .merge(conflicting.clone(), MergeConflictPolicy::Error)
+        {
+            Err(Error::MergeConflicts(names)) => assert_eq!(names, ["Point", "first"]),
+            _ => panic!("expected the merge to conflict"),
+        }
+
+        let conflicts = library
+            .merge(conflicting, MergeConflictPolicy::LastWins)
+            .unwrap();
+        let conflicts: Vec<_> = conflicts.iter().map(|x| x.name()).collect();
+        assert_eq!(conflicts, ["Point", "first"]);
+        let structs: Vec<_> = library.structs().map(|x| x.path.name()).collect();
+        assert_eq!(structs, ["Size"]);
+        let enums: Vec<_> = library.enums().map(|x| x.path.name()).collect();
+        assert_eq!(enums, ["Point"]);
+
+        let bindings = library.generate().unwrap();
+        let mut out = Vec::new();
+        bindings.write(&mut out);
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("void first(Point p);"));
+        assert!(out.contains("void second(Size s);"));
+    }
+
+    #[test]
+    fn coverage_counts_skipped_items() {
+        let library = Builder::new()
+            .with_src("tests/rust/strict.rs")
+            .parse()
+            .unwrap();
+        let kinds: Vec<_> = library
+            .skipped_items()
+            .iter()
+            .map(|x| (x.name.as_str(), x.kind, x.made_opaque))
+            .collect();
+        assert_eq!(
+            kinds,
+            [
+                ("Handle", ItemType::Structs, true),
+                ("handle_visit", ItemType::Functions, false),
+            ]
+        );
+
+        let coverage = library.coverage();
+        let count = |kind| coverage.iter().find(|x| x.kind == kind).unwrap().clone();
+        let structs = count(ItemType::Structs);
+        assert_eq!(
+            (structs.taken, structs.skipped, structs.made_opaque),
+            (1, 0, 1)
+        );
+        let functions = count(ItemType::Functions);
+        assert_eq!(
+            (functions.taken, functions.skipped, functions.made_opaque),
+            (1, 1, 0)
+        );
+
+        // Excluded items aren't counted.
+        let library = Builder::new()
+            .exclude_item("Handle")
+            .with_src("tests/rust/strict.rs")
+            .parse()
+            .unwrap();
+        let names: Vec<_> = library.skipped_items().iter().map(|x| &x.name).collect();
+        assert_eq!(names, ["handle_visit"]);
+    }
+
+    #[test]
+    fn strict_reports_skipped_items() {
+        let config = Config {
+            strict: true,
+            ..Config::default()
+        };
+        let result = Builder::new()
+            .with_config(config)
+            .with_src("tests/rust/strict.rs")
+            .generate();
+        match result {
+            Err(Error::SkippedPublicItems(items)) => {
+                let names: Vec<_> = items.iter().map(|x| x.name.as_str()).collect();
+                assert_eq!(names, ["Handle", "handle_visit"]);
+            }
+            _ => panic!("expected skipped public items to be reported"),
+        }
+    }
 }
@@ -18,6 +18,7 @@ enum CDeclarator {
         is_const: bool,
         is_nullable: bool,
         is_ref: bool,
+        is_restrict: bool,
     },
     Array(String),
     Func(Vec<(Option<String>, CDecl)>, bool),
@@ -57,11 +58,22 @@ impl CDecl {
         cdecl
     }
 
-    fn from_func_arg(t: &Type, array_length: Option<&str>) -> CDecl {
+    fn from_func_arg(t: &Type, array_length: Option<&str>, is_restrict: bool) -> CDecl {
         let mut cdecl = CDecl::new();
         let length = match array_length {
             Some(l) => l,
-            None => return CDecl::from_type(t),
+            None => {
+                match *t {
+                    Type::Ptr {
+                        ref ty,
+                        is_nullable,
+                        is_const,
+                        is_ref,
+                    } => cdecl.build_ptr(ty, is_const, is_nullable, is_ref, is_restrict, false),
+                    _ => cdecl.build_type(t, false),
+                }
+                return cdecl;
+            }
         };
         let (ty, is_const) = match t {
             Type::Ptr { ty, is_const, .. } => (ty, is_const),
@@ -75,20 +87,28 @@ impl CDecl {
         cdecl
     }
 
-    fn from_func(f: &Function, layout_vertical: bool) -> CDecl {
+    fn from_func(f: &Function, layout_vertical: bool, config: &Config) -> CDecl {
         let mut cdecl = CDecl::new();
-        cdecl.build_func(f, layout_vertical);
+        cdecl.build_func(f, layout_vertical, config);
         cdecl
     }
 
-    fn build_func(&mut self, f: &Function, layout_vertical: bool) {
+    fn build_func(&mut self, f: &Function, layout_vertical: bool, config: &Config) {
+        // `restrict` isn't a C++ keyword, so it can't be used in headers which
+        // may be included from C++.
+        let use_restrict =
+            config.function.use_restrict && config.language == Language::C && !config.cpp_compat;
         let args = f
             .args
             .iter()
             .map(|arg| {
                 (
-                    arg.name.clone(),
-                    CDecl::from_func_arg(&arg.ty, arg.array_length.as_deref()),
+                    arg.name.clone().filter(|_| config.function.arg_names),
+                    CDecl::from_func_arg(
+                        &arg.ty,
+                        arg.array_length.as_deref(),
+                        use_restrict && arg.is_mut_ref,
+                    ),
                 )
             })
             .collect();
@@ -100,15 +120,7 @@ impl CDecl {
     fn build_type(&mut self, t: &Type, is_const: bool) {
         match t {
             Type::Path(ref generic) => {
-                if is_const {
-                    assert!(
-                        self.type_qualifers.is_empty(),
-                        "error generating cdecl for {:?}",
-                        t
-                    );
-                    self.type_qualifers = "const".to_owned();
-                }
-
+                self.build_const_qualifier(t, is_const);
                 assert!(
                     self.type_name.is_empty(),
                     "error generating cdecl for {:?}",
@@ -124,15 +136,7 @@ impl CDecl {
                 self.type_ctype = generic.ctype().cloned();
             }
             Type::Primitive(ref p) => {
-                if is_const {
-                    assert!(
-                        self.type_qualifers.is_empty(),
-                        "error generating cdecl for {:?}",
-                        t
-                    );
-                    self.type_qualifers = "const".to_owned();
-                }
-
+                self.build_const_qualifier(t, is_const);
                 assert!(
                     self.type_name.is_empty(),
                     "error generating cdecl for {:?}",
@@ -145,14 +149,7 @@ impl CDecl {
                 is_nullable,
                 is_const: ptr_is_const,
                 is_ref,
-            } => {
-                self.declarators.push(CDeclarator::Ptr {
-                    is_const,
-                    is_nullable: *is_nullable,
-                    is_ref: *is_ref,
-                });
-                self.build_type(ty, *ptr_is_const);
-            }
+            } => self.build_ptr(ty, *ptr_is_const, *is_nullable, *is_ref, false, is_const),
             Type::Array(ref t, ref constant) => {
                 let len = constant.as_str().to_owned();
                 self.declarators.push(CDeclarator::Array(len));
@@ -164,9 +161,10 @@ impl CDecl {
                     .map(|(ref name, ref ty)| (name.clone(), CDecl::from_type(ty)))
                     .collect();
                 self.declarators.push(CDeclarator::Ptr {
-                    is_const: false,
+                    is_const,
                     is_nullable: true,
                     is_ref: false,
+                    is_restrict: false,
                 });
                 self.declarators.push(CDeclarator::Func(args, false));
                 self.build_type(ret, false);
@@ -174,6 +172,49 @@ impl CDecl {
         }
     }
 
+    /// Builds a pointer to `ty`, which is `const` if `pointee_is_const`, so
+    /// that `*const T` is written as `const T*` and `*mut T` as `T*`. This is
+    /// shared by struct fields, globals, and function arguments and return
+    /// types, so they all agree on where `const` goes.
+    fn build_ptr(
+        &mut self,
+        ty: &Type,
+        pointee_is_const: bool,
+        is_nullable: bool,
+        is_ref: bool,
+        is_restrict: bool,
+        is_const: bool,
+    ) {
+        self.declarators.push(CDeclarator::Ptr {
+            is_const,
+            is_nullable,
+            is_ref,
+            is_restrict,
+        });
+        self.build_type(ty, pointee_is_const);
+    }
+
+    /// Makes the innermost type `volatile`, before any `const`, so that
+    /// `*const T` is written as `volatile const T*`.
+    fn add_volatile_qualifier(&mut self) {
+        if self.type_qualifers.is_empty() {
+            self.type_qualifers = "volatile".to_owned();
+        } else {
+            self.type_qualifers.insert_str(0, "volatile ");
+        }
+    }
+
+    fn build_const_qualifier(&mut self, t: &Type, is_const: bool) {
+        if is_const {
+            assert!(
+                self.type_qualifers.is_empty(),
+                "error generating cdecl for {:?}",
+                t
+            );
+            self.type_qualifers = "const".to_owned();
+        }
+    }
+
     fn write<F: Write>(&self, out: &mut SourceWriter<F>, ident: Option<&str>, config: &Config) {
         // Write the type-specifier and type-qualifier first
         if !self.type_qualifers.is_empty() {
@@ -209,11 +250,15 @@ impl CDecl {
                     is_const,
                     is_nullable,
                     is_ref,
+                    is_restrict,
                 } => {
                     out.write(if is_ref { "&" } else { "*" });
                     if is_const {
                         out.write("const ");
                     }
+                    if is_restrict {
+                        out.write("restrict ");
+                    }
                     if !is_nullable && !is_ref {
                         if let Some(attr) = &config.pointer.non_null_attribute {
                             write!(out, "{} ", attr);
@@ -307,13 +352,40 @@ pub fn write_func<F: Write>(
     layout_vertical: bool,
     config: &Config,
 ) {
-    CDecl::from_func(f, layout_vertical).write(out, Some(f.path().name()), config);
+    CDecl::from_func(f, layout_vertical, config).write(out, Some(f.path().name()), config);
 }
 
 pub fn write_field<F: Write>(out: &mut SourceWriter<F>, t: &Type, ident: &str, config: &Config) {
     CDecl::from_type(t).write(out, Some(ident), config);
 }
 
+/// Writes a field which can't be modified. For pointers, it's the pointer
+/// which is `const`, as in `const T *const name`, rather than just what it
+/// points to.
+pub fn write_const_field<F: Write>(
+    out: &mut SourceWriter<F>,
+    t: &Type,
+    ident: &str,
+    config: &Config,
+) {
+    let mut cdecl = CDecl::new();
+    cdecl.build_type(t, true);
+    cdecl.write(out, Some(ident), config);
+}
+
+/// Writes a field whose innermost type, which is the pointed-to type for
+/// pointers, is `volatile`.
+pub fn write_volatile_field<F: Write>(
+    out: &mut SourceWriter<F>,
+    t: &Type,
+    ident: &str,
+    config: &Config,
+) {
+    let mut cdecl = CDecl::from_type(t);
+    cdecl.add_volatile_qualifier();
+    cdecl.write(out, Some(ident), config);
+}
+
 pub fn write_type<F: Write>(out: &mut SourceWriter<F>, t: &Type, config: &Config) {
     CDecl::from_type(t).write(out, None, config);
 }
@@ -2,7 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
 use std::default::Default;
 use std::str::FromStr;
 use std::{fmt, fs, path::Path as StdPath};
@@ -13,7 +14,9 @@ use serde::de::{Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
 use crate::bindgen::ir::annotation::AnnotationSet;
 use crate::bindgen::ir::path::Path;
 use crate::bindgen::ir::repr::ReprAlign;
+use crate::bindgen::rename::IdentifierType;
 pub use crate::bindgen::rename::RenameRule;
+use crate::bindgen::utilities::SynAttributeHelpers;
 
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
 
@@ -178,6 +181,48 @@ impl FromStr for DocumentationStyle {
 
 deserialize_enum_str!(DocumentationStyle);
 
+/// Whether the documentation of functions is written as is, or with Doxygen tags.
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum DocumentationFormat {
+    Plain,
+    Doxygen,
+}
+
+impl FromStr for DocumentationFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DocumentationFormat, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "plain" => Ok(DocumentationFormat::Plain),
+            "doxygen" => Ok(DocumentationFormat::Doxygen),
+            _ => Err(format!("Unrecognized documentation format: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(DocumentationFormat);
+
+/// Where the documentation of struct fields and enum variants should be placed.
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub enum DocumentationPlacement {
+    Leading,
+    Trailing,
+}
+
+impl FromStr for DocumentationPlacement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<DocumentationPlacement, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "leading" => Ok(DocumentationPlacement::Leading),
+            "trailing" => Ok(DocumentationPlacement::Trailing),
+            _ => Err(format!("Unrecognized documentation placement: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(DocumentationPlacement);
+
 /// A style of Style to use when generating structs and enums.
 #[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Style {
@@ -220,8 +265,56 @@ impl FromStr for Style {
 
 deserialize_enum_str!(Style);
 
+/// Whether the typedef of a C enum also names the enum.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EnumTypedefStyle {
+    /// `typedef enum Name { ... } Name;`
+    Named,
+    /// `typedef enum { ... } Name;`
+    Anonymous,
+}
+
+impl FromStr for EnumTypedefStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::EnumTypedefStyle::*;
+        Ok(match &*s.to_lowercase() {
+            "named" => Named,
+            "anonymous" => Anonymous,
+            _ => return Err(format!("Unrecognized enum typedef style: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(EnumTypedefStyle);
+
+/// How the variants of enums written with `as_defines` are written.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum EnumDefinesStyle {
+    /// `#define Name_A 0`
+    Define,
+    /// `static const uint8_t Name_A = 0;`
+    StaticConst,
+}
+
+impl FromStr for EnumDefinesStyle {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::EnumDefinesStyle::*;
+        Ok(match &*s.to_lowercase() {
+            "define" => Define,
+            "static_const" | "staticconst" => StaticConst,
+            _ => return Err(format!("Unrecognized enum defines style: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(EnumDefinesStyle);
+
 /// Different item types that we can generate and filter.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum ItemType {
     Constants,
     Globals,
@@ -276,6 +369,187 @@ impl FromStr for SortKey {
 
 deserialize_enum_str!(SortKey);
 
+/// How the headers written with `split_by_module` include each other.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ModuleIncludePath {
+    /// Just the file name of the header, after `module_include_prefix`.
+    FileName,
+    /// The absolute path the header is written to.
+    Absolute,
+}
+
+impl FromStr for ModuleIncludePath {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::ModuleIncludePath::*;
+        Ok(match &*s.to_lowercase() {
+            "filename" | "file_name" | "file-name" => FileName,
+            "absolute" => Absolute,
+            _ => return Err(format!("Unrecognized module include path: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(ModuleIncludePath);
+
+/// How globals and functions are wrapped in `extern "C"` blocks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExternCBlocks {
+    /// A single block with all of them.
+    Single,
+    /// A block for each top-level module.
+    PerModule,
+}
+
+impl FromStr for ExternCBlocks {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::ExternCBlocks::*;
+        Ok(match &*s.to_lowercase() {
+            "single" => Single,
+            "permodule" | "per_module" | "per-module" => PerModule,
+            _ => return Err(format!("Unrecognized extern \"C\" blocks: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(ExternCBlocks);
+
+/// Where the autogen warning is written.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AutogenWarningPlacement {
+    /// Only at the top of the file, the default.
+    Top,
+    /// At the top and at the bottom of the file.
+    TopAndBottom,
+    /// At the top, between types and functions, and at the bottom.
+    Everywhere,
+}
+
+impl FromStr for AutogenWarningPlacement {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::AutogenWarningPlacement::*;
+        Ok(match &*s.to_lowercase() {
+            "top" => Top,
+            "topandbottom" | "top_and_bottom" | "top-and-bottom" => TopAndBottom,
+            "everywhere" => Everywhere,
+            _ => return Err(format!("Unrecognized autogen warning placement: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(AutogenWarningPlacement);
+
+/// Whether types or functions are written first.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeclarationOrder {
+    /// Type definitions, then functions and globals.
+    TypesFirst,
+    /// Forward declarations of types, then functions and globals, then type
+    /// definitions.
+    FunctionsFirst,
+}
+
+impl FromStr for DeclarationOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::DeclarationOrder::*;
+        Ok(match &*s.to_lowercase() {
+            "typesfirst" | "types_first" | "types-first" => TypesFirst,
+            "functionsfirst" | "functions_first" | "functions-first" => FunctionsFirst,
+            _ => return Err(format!("Unrecognized declaration order: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(DeclarationOrder);
+
+/// How much the command line tool reports about the items it parses.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Verbosity {
+    /// Only skipped items, warnings and errors.
+    Quiet,
+    /// Also every item which is taken.
+    Normal,
+    /// Everything, including where the items are.
+    Verbose,
+}
+
+impl Verbosity {
+    pub fn level_filter(self) -> log::LevelFilter {
+        match self {
+            Verbosity::Quiet => log::LevelFilter::Warn,
+            Verbosity::Normal => log::LevelFilter::Info,
+            Verbosity::Verbose => log::LevelFilter::Trace,
+        }
+    }
+}
+
+impl Default for Verbosity {
+    fn default() -> Self {
+        Verbosity::Normal
+    }
+}
+
+impl FromStr for Verbosity {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::Verbosity::*;
+        Ok(match &*s.to_lowercase() {
+            "quiet" => Quiet,
+            "normal" => Normal,
+            "verbose" => Verbose,
+            _ => return Err(format!("Unrecognized verbosity: '{}'.", s)),
+        })
+    }
+}
+
+deserialize_enum_str!(Verbosity);
+
+/// How to handle `#[cfg(target_pointer_width = "...")]` predicates which have
+/// no `[defines]` entry.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TargetPointerWidth {
+    /// Test the maximum value of `uintptr_t` with the preprocessor.
+    Preprocessor,
+    /// Only generate the items for the given width in bits.
+    Bits(u32),
+}
+
+impl TargetPointerWidth {
+    /// The maximum value of `uintptr_t` for a pointer width in bits.
+    pub(crate) fn uintptr_max(bits: &str) -> Option<&'static str> {
+        Some(match bits {
+            "16" => "0xFFFF",
+            "32" => "0xFFFFFFFF",
+            "64" => "0xFFFFFFFFFFFFFFFF",
+            _ => return None,
+        })
+    }
+}
+
+impl FromStr for TargetPointerWidth {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match &*s.to_lowercase() {
+            "preprocessor" => Ok(TargetPointerWidth::Preprocessor),
+            bits if TargetPointerWidth::uintptr_max(bits).is_some() => {
+                Ok(TargetPointerWidth::Bits(bits.parse().unwrap()))
+            }
+            _ => Err(format!("Unrecognized target pointer width: '{}'.", s)),
+        }
+    }
+}
+
+deserialize_enum_str!(TargetPointerWidth);
+
 /// Settings to apply when exporting items.
 #[derive(Debug, Clone, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -293,14 +567,30 @@ pub struct ExportConfig {
     pub pre_body: HashMap<String, String>,
     /// Table of raw strings to append to the body of items.
     pub body: HashMap<String, String>,
+    /// Table of raw strings to write right before the declaration of items.
+    pub before: HashMap<String, String>,
+    /// Table of raw strings to write right after the declaration of items.
+    pub after: HashMap<String, String>,
     /// A prefix to add before the name of every item
     pub prefix: Option<String>,
     /// Types of items to generate.
     pub item_types: Vec<ItemType>,
     /// Whether renaming overrides or extends prefixing.
     pub renaming_overrides_prefixing: bool,
+    /// The rename rule to apply to the names of types which aren't in `rename`.
+    pub rename_types: RenameRule,
     /// Mangling configuration.
     pub mangle: MangleConfig,
+    /// The order to write types in, or `None` to sort untagged enums and
+    /// opaque items by name. Unlike `fn.sort_by`, this doesn't fall back to
+    /// the top-level `sort_by`.
+    pub sort_by: Option<SortKey>,
+    /// Whether to write typedefs, then enums, then structs and unions, then
+    /// opaque items, each in the order of `sort_by`.
+    pub group_by_kind: bool,
+    /// Whether to also write types after the types they use behind pointers,
+    /// unless they're in a cycle, so that fewer types are forward-declared.
+    pub minimize_forward_declarations: bool,
 }
 
 /// Mangling-specific configuration.
@@ -316,6 +606,16 @@ pub struct MangleConfig {
 }
 
 impl ExportConfig {
+    /// Whether `exclude` has the module or item at `path`, which are the names
+    /// of the modules from the root of the crate and, for items, the name of
+    /// the item. Bare item names are matched after parsing instead.
+    pub(crate) fn excludes_path(&self, path: &[String]) -> bool {
+        self.exclude.iter().any(|x| {
+            let x = x.strip_prefix("crate::").unwrap_or(x);
+            x.split("::").eq(path.iter().map(String::as_str))
+        })
+    }
+
     pub(crate) fn should_generate(&self, item_type: ItemType) -> bool {
         self.item_types.is_empty() || self.item_types.contains(&item_type)
     }
@@ -328,6 +628,14 @@ impl ExportConfig {
         self.body.get(path.name()).map(|s| s.trim_matches('\n'))
     }
 
+    pub(crate) fn before_item(&self, path: &Path) -> Option<&str> {
+        self.before.get(path.name()).map(|s| s.trim_matches('\n'))
+    }
+
+    pub(crate) fn after_item(&self, path: &Path) -> Option<&str> {
+        self.after.get(path.name()).map(|s| s.trim_matches('\n'))
+    }
+
     pub(crate) fn rename(&self, item_name: &mut String) {
         if let Some(name) = self.rename.get(item_name) {
             *item_name = name.clone();
@@ -339,6 +647,18 @@ impl ExportConfig {
             item_name.insert_str(0, &prefix);
         }
     }
+
+    /// Like `rename`, but also applies `rename_types` to names which aren't
+    /// renamed explicitly.
+    pub(crate) fn rename_type(&self, item_name: &mut String) {
+        if !self.rename.contains_key(item_name) {
+            let renamed = self.rename_types.apply(item_name, IdentifierType::Type);
+            if let Cow::Owned(renamed) = renamed {
+                *item_name = renamed;
+            }
+        }
+        self.rename(item_name);
+    }
 }
 
 /// Settings to apply to generated types with layout modifiers.
@@ -386,6 +706,17 @@ pub struct FunctionConfig {
     pub sort_by: Option<SortKey>,
     /// Optional text to output after functions which return `!`.
     pub no_return: Option<String>,
+    /// Whether to mark pointer arguments coming from `&mut` references as
+    /// `restrict`. Only applicable when language="C"
+    pub use_restrict: bool,
+    /// The name of the out-param of the wrappers named by the `result-wrapper`
+    /// annotation
+    pub result_wrapper_arg: String,
+    /// Whether to group functions named after the type of their first
+    /// argument into a C11 `_Generic` macro
+    pub generic_dispatch: bool,
+    /// Whether to write the names of the arguments in function declarations
+    pub arg_names: bool,
 }
 
 impl Default for FunctionConfig {
@@ -399,6 +730,10 @@ impl Default for FunctionConfig {
             swift_name_macro: None,
             sort_by: None,
             no_return: None,
+            use_restrict: false,
+            result_wrapper_arg: "out".to_owned(),
+            generic_dispatch: false,
+            arg_names: true,
         }
     }
 }
@@ -420,7 +755,7 @@ impl FunctionConfig {
 }
 
 /// Settings to apply to generated structs.
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[serde(deny_unknown_fields)]
 #[serde(default)]
@@ -449,9 +784,68 @@ pub struct StructConfig {
     pub associated_constants_in_body: bool,
     /// The way to annotate this struct as #[must_use].
     pub must_use: Option<String>,
+    /// Whether to emit a typedef for opaque structs in C, even if the style
+    /// doesn't generate typedefs.
+    pub opaque_typedef: bool,
+    /// Whether to write `#[repr(C)]` structs with a single field of a primitive
+    /// type as a typedef of that type.
+    pub typedef_primitive_newtypes: bool,
+    /// Whether to write a deleter struct and a `std::unique_ptr` alias for
+    /// opaque items with a destructor function, in C++
+    pub derive_unique_ptr: bool,
+    /// The name of the destructor function of opaque items, where `{struct}`
+    /// and `{struct_snake_case}` are replaced by the name of the item. Defaults
+    /// to `{struct_snake_case}_free`.
+    pub destructor_name: Option<String>,
+    /// Whether to write a last field that is a zero-length array as such,
+    /// rather than as a flexible array member, in C.
+    pub keep_zero_length_arrays: bool,
+}
+
+impl Default for StructConfig {
+    fn default() -> StructConfig {
+        StructConfig {
+            rename_fields: RenameRule::None,
+            derive_constructor: false,
+            derive_eq: false,
+            derive_neq: false,
+            derive_lt: false,
+            derive_lte: false,
+            derive_gt: false,
+            derive_gte: false,
+            derive_ostream: false,
+            associated_constants_in_body: false,
+            must_use: None,
+            opaque_typedef: false,
+            typedef_primitive_newtypes: false,
+            derive_unique_ptr: false,
+            destructor_name: None,
+            keep_zero_length_arrays: true,
+        }
+    }
 }
 
 impl StructConfig {
+    pub(crate) fn derive_unique_ptr(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("derive-unique-ptr") {
+            return x;
+        }
+        self.derive_unique_ptr
+    }
+    /// The name of the destructor function of the opaque item `name`.
+    pub(crate) fn destructor_name(&self, name: &str, annotations: &AnnotationSet) -> String {
+        if let Some(Some(x)) = annotations.atom("destructor") {
+            return x;
+        }
+        self.destructor_name
+            .as_deref()
+            .unwrap_or("{struct_snake_case}_free")
+            .replace(
+                "{struct_snake_case}",
+                &RenameRule::SnakeCase.apply(name, IdentifierType::Type),
+            )
+            .replace("{struct}", name)
+    }
     pub(crate) fn derive_constructor(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-constructor") {
             return x;
@@ -542,9 +936,40 @@ pub struct EnumConfig {
     /// Declare the enum as an enum class.
     /// Only relevant when targeting C++.
     pub enum_class: bool,
+    /// Whether C headers with `cpp_compat` declare fieldless enums with an
+    /// integer repr as an `enum class` when compiled as C++.
+    pub cpp_compat_enum_class: bool,
     /// Whether to generate empty, private default-constructors for tagged
     /// enums.
     pub private_default_tagged_enum_constructor: bool,
+    /// Whether to write the variants of enums with an integer repr as
+    /// `#define`s, along with a typedef of the integer type, in C.
+    pub as_defines: bool,
+    /// Whether the variants of enums written with `as_defines` are `#define`s,
+    /// or typed `static const` constants, which C++ also supports.
+    pub as_defines_style: EnumDefinesStyle,
+    /// Whether to write fieldless enums with a single variant like with
+    /// `as_defines`, even with `#[repr(C)]`.
+    pub single_variant_as_define: bool,
+    /// Whether enums written with a typedef in C also name the enum, or `None`
+    /// to name it only if `style` generates tags.
+    pub typedef_style: Option<EnumTypedefStyle>,
+    /// Whether to write a comma after the last variant of enums.
+    pub trailing_comma: bool,
+    /// Whether to write a `#define` with the number of variants after enums.
+    pub add_count: bool,
+    /// The name of the `#define` written by `add_count`, where `{enum}` is
+    /// replaced by the name of the enum.
+    pub count_name: String,
+    /// Whether to generate `static inline` functions in C to test the variant
+    /// of tagged enums and to access their payloads.
+    pub tagged_enum_helpers: bool,
+    /// The name of the functions testing the variant of tagged enums, where
+    /// `{enum}` and `{variant}` are replaced by their names.
+    pub tagged_enum_is_name: String,
+    /// The name of the functions accessing the payload of the variants of
+    /// tagged enums, where `{enum}` and `{variant}` are replaced by their names.
+    pub tagged_enum_as_name: String,
 }
 
 impl Default for EnumConfig {
@@ -563,7 +988,18 @@ impl Default for EnumConfig {
             derive_tagged_enum_copy_assignment: false,
             derive_ostream: false,
             enum_class: true,
+            cpp_compat_enum_class: false,
             private_default_tagged_enum_constructor: false,
+            as_defines: false,
+            as_defines_style: EnumDefinesStyle::Define,
+            single_variant_as_define: false,
+            typedef_style: None,
+            trailing_comma: false,
+            add_count: false,
+            count_name: "{enum}_COUNT".to_owned(),
+            tagged_enum_helpers: false,
+            tagged_enum_is_name: "{enum}_is_{variant}".to_owned(),
+            tagged_enum_as_name: "{enum}_as_{variant}".to_owned(),
         }
     }
 }
@@ -575,12 +1011,24 @@ impl EnumConfig {
         }
         self.add_sentinel
     }
+    pub(crate) fn add_count(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("add-count") {
+            return x;
+        }
+        self.add_count
+    }
     pub(crate) fn derive_helper_methods(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-helper-methods") {
             return x;
         }
         self.derive_helper_methods
     }
+    pub(crate) fn tagged_enum_helpers(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("tagged-enum-helpers") {
+            return x;
+        }
+        self.tagged_enum_helpers
+    }
     pub(crate) fn derive_const_casts(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("derive-const-casts") {
             return x;
@@ -617,6 +1065,12 @@ impl EnumConfig {
         }
         self.derive_ostream
     }
+    pub(crate) fn as_defines(&self, annotations: &AnnotationSet) -> bool {
+        if let Some(x) = annotations.bool("as-defines") {
+            return x;
+        }
+        self.as_defines
+    }
     pub(crate) fn enum_class(&self, annotations: &AnnotationSet) -> bool {
         if let Some(x) = annotations.bool("enum-class") {
             return x;
@@ -646,6 +1100,9 @@ pub struct ConstantConfig {
     pub allow_constexpr: bool,
     /// Sort key for constants
     pub sort_by: Option<SortKey>,
+    /// Whether array lengths that name an integer constant should be replaced
+    /// by the constant's value.
+    pub resolve_array_lengths: bool,
 }
 
 impl Default for ConstantConfig {
@@ -654,6 +1111,7 @@ impl Default for ConstantConfig {
             allow_static_const: true,
             allow_constexpr: false,
             sort_by: None,
+            resolve_array_lengths: false,
         }
     }
 }
@@ -762,9 +1220,25 @@ pub struct ParseConfig {
     /// List of crate names which generate consts, statics, and fns. By default
     /// no dependent crates generate them.
     pub extra_bindings: Vec<String>,
+    /// An optional whitelist of glob patterns of the files to parse when the
+    /// input is a directory of source files
+    pub include_files: Option<Vec<String>>,
+    /// Glob patterns of the files to not parse when the input is a directory of
+    /// source files
+    pub exclude_files: Vec<String>,
+    /// An optional path of an attribute, like `ffi::skip`, marking items that
+    /// should never be exported, regardless of their repr or `#[no_mangle]`
+    pub skip_attribute: Option<String>,
 }
 
 impl ParseConfig {
+    pub(crate) fn should_skip_item<T: SynAttributeHelpers + ?Sized>(&self, item: &T) -> bool {
+        match self.skip_attribute {
+            Some(ref path) => item.has_attr_path(path),
+            None => false,
+        }
+    }
+
     pub(crate) fn should_generate_top_level_item(
         &self,
         crate_name: &str,
@@ -797,6 +1271,8 @@ pub struct PtrConfig {
 pub struct Config {
     /// Optional text to output at the beginning of the file
     pub header: Option<String>,
+    /// An optional header to include before any other include
+    pub base_header: Option<String>,
     /// A list of additional includes to put at the beginning of the generated header
     pub includes: Vec<String>,
     /// A list of additional system includes to put at the beginning of the generated header
@@ -805,17 +1281,33 @@ pub struct Config {
     pub after_includes: Option<String>,
     /// Optional text to output at the end of the file
     pub trailer: Option<String>,
-    /// Optional name to use for an include guard
+    /// Optional name to use for an include guard, where `{crate}` is replaced
+    /// by the name of the binding crate
     pub include_guard: Option<String>,
     /// Add a `#pragma once` guard
     pub pragma_once: bool,
+    /// Write the items of each top-level module of the binding crate to their
+    /// own header, next to the main one
+    pub split_by_module: bool,
+    /// How the headers written with `split_by_module` include each other
+    pub module_include_path: ModuleIncludePath,
+    /// A prefix for the file names of the headers written with
+    /// `split_by_module` when they include each other
+    pub module_include_prefix: Option<String>,
+    /// The file name of a header to write next to the main one, including all
+    /// the headers written with `split_by_module`
+    pub umbrella_header: Option<String>,
+    /// Write a JSON manifest of the items in the header next to it
+    pub manifest: bool,
     /// Generates no includes at all. Overrides all other include options
     ///
     /// This option is useful when using cbindgen with tools such as python's cffi which
     /// doesn't understand include directives
     pub no_includes: bool,
-    /// Optional text to output at major sections to deter manual editing
+    /// Optional text to output to deter manual editing
     pub autogen_warning: Option<String>,
+    /// Where to write the autogen warning
+    pub autogen_warning_placement: AutogenWarningPlacement,
     /// Include a comment with the version of cbindgen used to generate the file
     pub include_version: bool,
     /// An optional name for the root namespace. Only applicable when language="C++"
@@ -830,16 +1322,32 @@ pub struct Config {
     pub line_length: usize,
     /// The amount of spaces in a tab
     pub tab_width: usize,
+    /// The number of blank lines between declarations
+    pub blank_lines_between_items: usize,
     /// The type of line endings to generate
     pub line_endings: LineEndingStyle,
     /// The language to output bindings for
     pub language: Language,
     /// Include preprocessor defines in C bindings to ensure C++ compatibility
     pub cpp_compat: bool,
+    /// Whether globals and functions are wrapped in a single `extern "C"`
+    /// block, or in one for each top-level module
+    pub extern_c_blocks: ExternCBlocks,
     /// The style to declare structs, enums and unions in for C
     pub style: Style,
     /// Default sort key for functions and constants.
     pub sort_by: SortKey,
+    /// Whether types or functions are written first
+    pub declaration_order: DeclarationOrder,
+    /// A prefix for the names of the `#define`s written for constants, enum
+    /// variants and `_Generic` macros, to keep them apart from other macros
+    pub define_prefix: Option<String>,
+    /// Fail instead of skipping public items, or making them opaque, when they
+    /// can't be exported
+    pub strict: bool,
+    /// How much the command line tool reports, if not given on the command
+    /// line
+    pub verbosity: Verbosity,
     /// The configuration options for parsing
     pub parse: ParseConfig,
     /// The configuration options for exporting
@@ -862,10 +1370,50 @@ pub struct Config {
     pub constant: ConstantConfig,
     /// Preprocessor defines to use when generating #ifdef's for #[cfg]
     pub defines: HashMap<String, String>,
+    /// The C types to write for the Rust types with the given names, which
+    /// are then not looked up in the crates
+    pub type_mappings: BTreeMap<String, String>,
+    /// Whether cfgs which aren't in `defines` are tested with a define that
+    /// must be set when compiling the bindings, instead of being ignored
+    pub error_on_missing_defines: bool,
+    /// How to handle `target_pointer_width` cfgs which aren't in `defines`
+    pub target_pointer_width: Option<TargetPointerWidth>,
+    /// The `target_os` to generate the bindings for, leaving out the items
+    /// whose cfg can't hold on it
+    pub target_os: Option<String>,
+    /// The `target_arch` to generate the bindings for, like `target_os`
+    pub target_arch: Option<String>,
+    /// How deeply specializations of generic types may nest before giving up
+    /// with an error, or 0 for no limit
+    pub max_specialization_depth: usize,
+    /// Whether to write static assertions of the size and alignment of structs
+    pub layout_assertions: bool,
+    /// Whether to check that the generated header compiles with `compiler`
+    pub verify_compile: bool,
+    /// The compiler command used by `verify_compile`, defaulting to `$CC` or
+    /// `$CXX`
+    pub compiler: Option<String>,
+    /// Extra arguments to pass to `compiler`
+    pub compiler_flags: Vec<String>,
     /// Include doc comments from rust as documentation
     pub documentation: bool,
+    /// Write the `//!` documentation of the binding crate at the top of the
+    /// file, after the header
+    pub crate_documentation: bool,
     /// How documentation comments should be styled.
     pub documentation_style: DocumentationStyle,
+    /// Whether to add Doxygen tags for the arguments and return values of
+    /// functions to their documentation.
+    pub documentation_format: DocumentationFormat,
+    /// Where the documentation of fields and variants should be placed.
+    pub member_documentation_placement: DocumentationPlacement,
+    /// Whether to wrap the constants, types, globals and functions in region
+    /// markers, for editors to fold them
+    pub emit_region_markers: bool,
+    /// The line starting a region, where `{name}` is replaced by its name
+    pub region_start: String,
+    /// The line ending a region, where `{name}` is replaced by its name
+    pub region_end: String,
     /// Configuration options for pointers
     #[serde(rename = "ptr")]
     pub pointer: PtrConfig,
@@ -875,13 +1423,20 @@ impl Default for Config {
     fn default() -> Config {
         Config {
             header: None,
+            base_header: None,
             includes: Vec::new(),
             sys_includes: Vec::new(),
             after_includes: None,
             trailer: None,
             include_guard: None,
             pragma_once: false,
+            split_by_module: false,
+            module_include_path: ModuleIncludePath::FileName,
+            module_include_prefix: None,
+            umbrella_header: None,
+            manifest: false,
             autogen_warning: None,
+            autogen_warning_placement: AutogenWarningPlacement::Top,
             include_version: false,
             no_includes: false,
             namespace: None,
@@ -890,11 +1445,17 @@ impl Default for Config {
             braces: Braces::SameLine,
             line_length: 100,
             tab_width: 2,
+            blank_lines_between_items: 1,
             line_endings: LineEndingStyle::default(),
             language: Language::Cxx,
             cpp_compat: false,
+            extern_c_blocks: ExternCBlocks::Single,
             style: Style::Type,
             sort_by: SortKey::None,
+            declaration_order: DeclarationOrder::TypesFirst,
+            define_prefix: None,
+            strict: false,
+            verbosity: Verbosity::default(),
             macro_expansion: Default::default(),
             parse: ParseConfig::default(),
             export: ExportConfig::default(),
@@ -904,8 +1465,24 @@ impl Default for Config {
             enumeration: EnumConfig::default(),
             constant: ConstantConfig::default(),
             defines: HashMap::new(),
+            type_mappings: BTreeMap::new(),
+            error_on_missing_defines: false,
+            target_pointer_width: None,
+            target_os: None,
+            target_arch: None,
+            max_specialization_depth: 64,
+            layout_assertions: false,
+            verify_compile: false,
+            compiler: None,
+            compiler_flags: Vec::new(),
             documentation: true,
+            crate_documentation: false,
             documentation_style: DocumentationStyle::Auto,
+            documentation_format: DocumentationFormat::Plain,
+            member_documentation_placement: DocumentationPlacement::Leading,
+            emit_region_markers: false,
+            region_start: "// region: {name}".to_owned(),
+            region_end: "// endregion".to_owned(),
             pointer: PtrConfig::default(),
         }
     }
@@ -926,6 +1503,84 @@ impl Config {
         }
     }
 
+    /// Replaces `{crate}` in the include guard by `crate_name`, made into a
+    /// valid macro name. The include guard is dropped if it needs a crate name
+    /// but there's none, as when parsing source files without a crate.
+    pub(crate) fn resolve_include_guard(&mut self, crate_name: Option<&str>) {
+        let include_guard = match self.include_guard {
+            Some(ref include_guard) if include_guard.contains("{crate}") => include_guard,
+            _ => return,
+        };
+        self.include_guard = match crate_name {
+            Some(crate_name) => Some(macro_name(
+                &include_guard.replace("{crate}", &crate_name.to_uppercase()),
+            )),
+            None => {
+                warn!(
+                    "Include guard `{}` needs a crate name, but there's none; not writing it.",
+                    include_guard
+                );
+                None
+            }
+        };
+    }
+
+    /// Makes `define_prefix` a valid start of a macro name.
+    pub(crate) fn resolve_define_prefix(&mut self) {
+        let define_prefix = match self.define_prefix {
+            Some(ref define_prefix) => define_prefix,
+            None => return,
+        };
+        let resolved = macro_name(define_prefix);
+        if resolved != *define_prefix {
+            warn!(
+                "Define prefix `{}` isn't a valid macro name; using `{}` instead.",
+                define_prefix, resolved
+            );
+            self.define_prefix = Some(resolved);
+        }
+    }
+
+    /// Drops the `type_mappings` to pointer, array or function types, which
+    /// can't be written in place of a type name, e.g. in `const T *`.
+    pub(crate) fn resolve_type_mappings(&mut self) {
+        self.type_mappings.retain(|rust_type, c_type| {
+            let is_type_name = !c_type.contains(&['*', '[', '('][..]);
+            if !is_type_name {
+                warn!(
+                    "Type mapping `{}` = `{}` isn't a single C type name; ignoring it. \
+                     Map the Rust type which is pointed to instead.",
+                    rust_type, c_type
+                );
+            }
+            is_type_name
+        });
+    }
+
+    /// The name of a `#define` written for `name`, with `define_prefix`.
+    pub(crate) fn define_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+        match self.define_prefix {
+            Some(ref prefix) => Cow::Owned(format!("{}{}", prefix, name)),
+            None => Cow::Borrowed(name),
+        }
+    }
+
+    /// Whether constants are written as `#define`s, so that the names which
+    /// refer to them need `define_prefix`.
+    pub(crate) fn constants_are_defines(&self) -> bool {
+        self.language == Language::C
+            || !self.constant.allow_static_const && !self.constant.allow_constexpr
+    }
+
+    /// The attribute written before functions and globals with the
+    /// `maybe-unused` annotation.
+    pub(crate) fn maybe_unused_attribute(&self) -> &'static str {
+        match self.language {
+            Language::Cxx => "[[maybe_unused]]",
+            Language::C => "__attribute__((unused))",
+        }
+    }
+
     pub fn from_root_or_default<P: AsRef<StdPath>>(root: P) -> Config {
         let c = root.as_ref().join("cbindgen.toml");
 
@@ -936,3 +1591,21 @@ impl Config {
         }
     }
 }
+
+/// Makes `name` a valid C preprocessor identifier, by replacing the characters
+/// which can't be in one, like `-` or `.`, with underscores. Runs of
+/// underscores are collapsed, since identifiers containing `__` are reserved,
+/// and an underscore is prepended to names starting with a digit.
+pub(crate) fn macro_name(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 1);
+    for c in name.chars() {
+        let c = if c.is_ascii_alphanumeric() { c } else { '_' };
+        if c != '_' || !result.ends_with('_') {
+            result.push(c);
+        }
+    }
+    if result.is_empty() || result.starts_with(|c: char| c.is_ascii_digit()) {
+        result.insert(0, '_');
+    }
+    result
+}
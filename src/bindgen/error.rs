@@ -8,6 +8,7 @@ use std::fmt;
 pub use crate::bindgen::cargo::cargo_expand::Error as CargoExpandError;
 pub use crate::bindgen::cargo::cargo_metadata::Error as CargoMetadataError;
 pub use crate::bindgen::cargo::cargo_toml::Error as CargoTomlError;
+pub use crate::bindgen::parser::SkippedItem;
 pub use syn::parse::Error as ParseError;
 
 #[derive(Debug)]
@@ -24,6 +25,14 @@ pub enum Error {
         crate_name: String,
         src_path: String,
     },
+    SkippedPublicItems(Vec<SkippedItem>),
+    DefaultReprByValue(Vec<String>),
+    SpecializationTooDeep(Vec<String>),
+    MergeConflicts(Vec<String>),
+    CompileFailed {
+        compiler: String,
+        diagnostics: String,
+    },
 }
 
 impl fmt::Display for Error {
@@ -70,6 +79,55 @@ impl fmt::Display for Error {
                 "Parsing crate `{}`: cannot open file `{}`.",
                 crate_name, src_path
             ),
+            Error::SkippedPublicItems(ref items) => {
+                writeln!(
+                    f,
+                    "Strict mode: {} public item(s) can't be exported:",
+                    items.len()
+                )?;
+                for item in items {
+                    writeln!(f, "  {}", item.description)?;
+                }
+                writeln!(f, "To accept them, add them to the `[export]` section:")?;
+                let names: Vec<_> = items.iter().map(|x| format!("{:?}", x.name)).collect();
+                write!(f, "exclude = [{}]", names.join(", "))
+            }
+            Error::DefaultReprByValue(ref uses) => {
+                write!(
+                    f,
+                    "Types without a #[repr] attribute have no stable layout and can't be \
+                     passed by value, pass them by pointer instead:"
+                )?;
+                for usage in uses {
+                    write!(f, "\n  {}", usage)?;
+                }
+                Ok(())
+            }
+            Error::MergeConflicts(ref names) => write!(
+                f,
+                "Can't merge libraries which both define {}",
+                names.join(", ")
+            ),
+            Error::CompileFailed {
+                ref compiler,
+                ref diagnostics,
+            } => write!(
+                f,
+                "The bindings don't compile with `{}`:\n{}",
+                compiler, diagnostics
+            ),
+            Error::SpecializationTooDeep(ref chain) => {
+                write!(
+                    f,
+                    "Specializing generic types nests more than {} levels deep, see \
+                     `max_specialization_depth`:",
+                    chain.len() - 1
+                )?;
+                for (i, ty) in chain.iter().enumerate() {
+                    write!(f, "\n  {}{}", if i == 0 { "" } else { "-> " }, ty)?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -82,6 +140,11 @@ impl error::Error for Error {
             Error::CargoExpand(_, ref error) => Some(error),
             Error::ParseSyntaxError { ref error, .. } => Some(error),
             Error::ParseCannotOpenFile { .. } => None,
+            Error::SkippedPublicItems(..) => None,
+            Error::DefaultReprByValue(..) => None,
+            Error::SpecializationTooDeep(..) => None,
+            Error::MergeConflicts(..) => None,
+            Error::CompileFailed { .. } => None,
         }
     }
 }
@@ -6,7 +6,7 @@ use std::fmt;
 use std::io::Write;
 
 use crate::bindgen::cargo::cargo_metadata::Dependency;
-use crate::bindgen::config::Config;
+use crate::bindgen::config::{Config, TargetPointerWidth};
 use crate::bindgen::writer::SourceWriter;
 
 #[derive(PartialEq, Eq)]
@@ -42,7 +42,7 @@ impl<'a> DefineKey<'a> {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Cfg {
     Boolean(String),
     Named(String, String),
@@ -185,6 +185,61 @@ impl Cfg {
         })
     }
 
+    /// Resolves the predicates of the cfg which are known on the configured
+    /// target: `target_pointer_width` if it's set to a width in bits, and
+    /// `target_os` and `target_arch` if they're set.
+    pub fn resolve_target(&self, config: &Config) -> ResolvedCfg {
+        match *self {
+            Cfg::Named(ref name, ref value) => match target_value(name, value, config) {
+                Some(target_value) => ResolvedCfg::Known(*value == target_value),
+                None => ResolvedCfg::Unknown(self.clone()),
+            },
+            Cfg::Boolean(..) => ResolvedCfg::Unknown(self.clone()),
+            Cfg::Any(ref children) => {
+                let mut unknown = vec![];
+                for child in children {
+                    match child.resolve_target(config) {
+                        ResolvedCfg::Known(true) => return ResolvedCfg::Known(true),
+                        ResolvedCfg::Known(false) => {}
+                        ResolvedCfg::Unknown(cfg) => unknown.push(cfg),
+                    }
+                }
+                match unknown.len() {
+                    0 => ResolvedCfg::Known(false),
+                    1 => ResolvedCfg::Unknown(unknown.pop().unwrap()),
+                    _ => ResolvedCfg::Unknown(Cfg::Any(unknown)),
+                }
+            }
+            Cfg::All(ref children) => {
+                let mut unknown = vec![];
+                for child in children {
+                    match child.resolve_target(config) {
+                        ResolvedCfg::Known(false) => return ResolvedCfg::Known(false),
+                        ResolvedCfg::Known(true) => {}
+                        ResolvedCfg::Unknown(cfg) => unknown.push(cfg),
+                    }
+                }
+                match unknown.len() {
+                    0 => ResolvedCfg::Known(true),
+                    1 => ResolvedCfg::Unknown(unknown.pop().unwrap()),
+                    _ => ResolvedCfg::Unknown(Cfg::All(unknown)),
+                }
+            }
+            Cfg::Not(ref child) => match child.resolve_target(config) {
+                ResolvedCfg::Known(value) => ResolvedCfg::Known(!value),
+                ResolvedCfg::Unknown(cfg) => ResolvedCfg::Unknown(Cfg::Not(Box::new(cfg))),
+            },
+        }
+    }
+
+    /// Whether an item with this cfg is generated for the configured target.
+    pub fn is_generated(cfg: Option<&Cfg>, config: &Config) -> bool {
+        match cfg {
+            Some(cfg) => cfg.resolve_target(config) != ResolvedCfg::Known(false),
+            None => true,
+        }
+    }
+
     fn load_list<'a, I: Iterator<Item = &'a syn::NestedMeta>>(attrs: I) -> Option<Vec<Cfg>> {
         let mut configs = Vec::new();
 
@@ -212,6 +267,40 @@ impl<'a> ToCondition for Option<Cfg> {
 
 impl<'a> ToCondition for Cfg {
     fn to_condition(&self, config: &Config) -> Option<Condition> {
+        match self.resolve_target(config) {
+            ResolvedCfg::Known(..) => None,
+            ResolvedCfg::Unknown(cfg) => cfg.condition(config),
+        }
+    }
+}
+
+impl Cfg {
+    /// The condition of a predicate which has no `[defines]` entry.
+    fn missing_define(&self, config: &Config) -> Option<Condition> {
+        if !config.error_on_missing_defines {
+            warn!(
+                "Missing `[defines]` entry for `{}` in cbindgen config.",
+                self,
+            );
+            return None;
+        }
+        let define = self
+            .to_string()
+            .chars()
+            .filter(|c| *c != '"' && *c != ' ')
+            .map(|c| match c {
+                'a'..='z' => c.to_ascii_uppercase(),
+                'A'..='Z' | '0'..='9' => c,
+                _ => '_',
+            })
+            .collect::<String>();
+        Some(Condition::Missing {
+            define: format!("CFG_{}", define),
+            cfg: self.to_string(),
+        })
+    }
+
+    fn condition(&self, config: &Config) -> Option<Condition> {
         match *self {
             Cfg::Boolean(ref cfg_name) => {
                 let define = config
@@ -221,11 +310,7 @@ impl<'a> ToCondition for Cfg {
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
                 } else {
-                    warn!(
-                        "Missing `[defines]` entry for `{}` in cbindgen config.",
-                        self,
-                    );
-                    None
+                    self.missing_define(config)
                 }
             }
             Cfg::Named(ref cfg_name, ref cfg_value) => {
@@ -234,18 +319,16 @@ impl<'a> ToCondition for Cfg {
                 });
                 if let Some((_, define)) = define {
                     Some(Condition::Define(define.to_owned()))
+                } else if let Some(max) = pointer_width_max(cfg_name, cfg_value, config) {
+                    Some(Condition::Expression(format!("UINTPTR_MAX == {}", max)))
                 } else {
-                    warn!(
-                        "Missing `[defines]` entry for `{}` in cbindgen config.",
-                        self,
-                    );
-                    None
+                    self.missing_define(config)
                 }
             }
             Cfg::Any(ref children) => {
                 let conditions: Vec<_> = children
                     .iter()
-                    .filter_map(|x| x.to_condition(config))
+                    .filter_map(|x| x.condition(config))
                     .collect();
                 match conditions.len() {
                     0 => None,
@@ -256,7 +339,7 @@ impl<'a> ToCondition for Cfg {
             Cfg::All(ref children) => {
                 let cfgs: Vec<_> = children
                     .iter()
-                    .filter_map(|x| x.to_condition(config))
+                    .filter_map(|x| x.condition(config))
                     .collect();
                 match cfgs.len() {
                     0 => None,
@@ -265,15 +348,67 @@ impl<'a> ToCondition for Cfg {
                 }
             }
             Cfg::Not(ref child) => child
-                .to_condition(config)
+                .condition(config)
                 .map(|cfg| Condition::Not(Box::new(cfg))),
         }
     }
 }
 
+/// The value of the cfg named `name` on the configured target, if it's known,
+/// to check `name = value` against.
+fn target_value(name: &str, value: &str, config: &Config) -> Option<String> {
+    match name {
+        "target_pointer_width" => match config.target_pointer_width {
+            // Entries in [defines] take precedence over `target_pointer_width`.
+            Some(TargetPointerWidth::Bits(bits)) if !has_define(name, value, config) => {
+                Some(bits.to_string())
+            }
+            _ => None,
+        },
+        "target_os" => config.target_os.clone(),
+        "target_arch" => config.target_arch.clone(),
+        _ => None,
+    }
+}
+
+/// Whether [defines] has an entry for `name = value`.
+fn has_define(name: &str, value: &str, config: &Config) -> bool {
+    config
+        .defines
+        .keys()
+        .any(|key| DefineKey::Named(name, value) == DefineKey::load(key))
+}
+
+/// The maximum value of `uintptr_t` to test a `target_pointer_width` cfg with,
+/// if the preprocessor should test it.
+fn pointer_width_max(name: &str, value: &str, config: &Config) -> Option<&'static str> {
+    if name != "target_pointer_width"
+        || config.target_pointer_width != Some(TargetPointerWidth::Preprocessor)
+    {
+        return None;
+    }
+    TargetPointerWidth::uintptr_max(value)
+}
+
+/// A cfg with some of its predicates resolved.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResolvedCfg {
+    /// Whether the cfg holds is known.
+    Known(bool),
+    /// The part of the cfg that is left to check.
+    Unknown(Cfg),
+}
+
 #[derive(Debug, Clone)]
 pub enum Condition {
     Define(String),
+    Expression(String),
+    /// A predicate without a `[defines]` entry, tested with a define that the
+    /// user of the bindings has to set to 0 or 1.
+    Missing {
+        define: String,
+        cfg: String,
+    },
     Any(Vec<Condition>),
     All(Vec<Condition>),
     Not(Box<Condition>),
@@ -287,6 +422,12 @@ impl Condition {
                 write!(out, "{}", define);
                 out.write(")");
             }
+            Condition::Expression(ref expression) => {
+                write!(out, "({})", expression);
+            }
+            Condition::Missing { ref define, .. } => {
+                write!(out, "{}", define);
+            }
             Condition::Any(ref conditions) => {
                 out.write("(");
                 for (i, condition) in conditions.iter().enumerate() {
@@ -320,11 +461,56 @@ pub trait ConditionWrite {
     fn write_after<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>);
 }
 
+impl Condition {
+    fn collect_missing<'a>(&'a self, out: &mut Vec<(&'a str, &'a str)>) {
+        match *self {
+            Condition::Define(..) | Condition::Expression(..) => {}
+            Condition::Missing {
+                ref define,
+                ref cfg,
+            } => {
+                if !out.iter().any(|(x, _)| x == define) {
+                    out.push((define, cfg));
+                }
+            }
+            Condition::Any(ref conditions) | Condition::All(ref conditions) => {
+                for condition in conditions {
+                    condition.collect_missing(out);
+                }
+            }
+            Condition::Not(ref condition) => condition.collect_missing(out),
+        }
+    }
+}
+
 impl ConditionWrite for Option<Condition> {
     fn write_before<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         if let Some(ref cfg) = *self {
             out.push_set_spaces(0);
+            let mut missing = vec![];
+            cfg.collect_missing(&mut missing);
             out.write("#if ");
+            if !missing.is_empty() {
+                // Fail loudly when the cfgs that cbindgen can't resolve aren't
+                // decided by the user of the bindings.
+                for (i, (define, _)) in missing.iter().enumerate() {
+                    if i != 0 {
+                        out.write(" || ");
+                    }
+                    write!(out, "!defined({})", define);
+                }
+                out.new_line();
+                for (define, cfg) in &missing {
+                    write!(
+                        out,
+                        "#error \"Define {} to 0 or 1, for `cfg({})`\"",
+                        define,
+                        cfg.replace('"', "\\\"")
+                    );
+                    out.new_line();
+                }
+                out.write("#elif ");
+            }
             cfg.write(config, out);
             out.pop_set_spaces();
             out.new_line();
@@ -12,8 +12,8 @@ use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Struct, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, ItemMap,
+    Path, Struct, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::writer::{Source, SourceWriter};
@@ -111,6 +111,16 @@ impl Literal {
             }
         }
     }
+
+    /// Returns the value of this literal if it is a plain unsigned integer,
+    /// following paths to other constants.
+    fn integer_value(&self, constants: &ItemMap<Constant>) -> Option<String> {
+        match *self {
+            Literal::Expr(ref value) if value.parse::<u64>().is_ok() => Some(value.clone()),
+            Literal::Path(ref name) => Constant::integer_value_of(name, constants),
+            _ => None,
+        }
+    }
 }
 
 impl Literal {
@@ -121,13 +131,16 @@ impl Literal {
                 fields,
                 ..
             } => {
-                config.export.rename(export_name);
+                config.export.rename_type(export_name);
                 for lit in fields.values_mut() {
                     lit.rename_for_config(config);
                 }
             }
             Literal::Path(ref mut name) => {
                 config.export.rename(name);
+                if config.constants_are_defines() {
+                    *name = config.define_name(name).into_owned();
+                }
             }
             Literal::PostfixUnaryOp { ref mut value, .. } => {
                 value.rename_for_config(config);
@@ -420,6 +433,19 @@ impl Constant {
     pub fn uses_only_primitive_types(&self) -> bool {
         self.value.uses_only_primitive_types() && self.ty.is_primitive_or_ptr_primitive()
     }
+
+    /// Looks up the constant named `name` and returns its value if it has a
+    /// single definition which evaluates to a plain unsigned integer.
+    pub fn integer_value_of(name: &str, constants: &ItemMap<Constant>) -> Option<String> {
+        let mut values = Vec::new();
+        constants.for_items(&Path::new(name), |constant| {
+            values.push(constant.value.integer_value(constants));
+        });
+        if values.len() != 1 {
+            return None;
+        }
+        values.pop().unwrap()
+    }
 }
 
 impl Item for Constant {
@@ -454,6 +480,9 @@ impl Item for Constant {
     fn rename_for_config(&mut self, config: &Config) {
         if self.associated_to.is_none() {
             config.export.rename(&mut self.export_name);
+            if self.is_written_as_define(config) {
+                self.export_name = config.define_name(&self.export_name).into_owned();
+            }
         }
         self.value.rename_for_config(config);
         self.ty.rename_for_config(config, &GenericParams::default()); // FIXME: should probably propagate something here
@@ -465,6 +494,17 @@ impl Item for Constant {
 }
 
 impl Constant {
+    /// Whether the constant is written as a `#define` rather than as a C++
+    /// constant.
+    fn is_written_as_define(&self, config: &Config) -> bool {
+        let allow_constexpr = if let Type::Primitive(..) = self.ty {
+            config.constant.allow_constexpr
+        } else {
+            false
+        };
+        !(config.constant.allow_static_const || allow_constexpr) || config.language != Language::Cxx
+    }
+
     pub fn write_declaration<F: Write>(
         &self,
         config: &Config,
@@ -526,7 +566,7 @@ impl Constant {
                 Some(s) => Cow::Borrowed(s.export_name()),
                 None => {
                     let mut name = self.associated_to.as_ref().unwrap().name().to_owned();
-                    config.export.rename(&mut name);
+                    config.export.rename_type(&mut name);
                     Cow::Owned(name)
                 }
             };
@@ -573,7 +613,11 @@ impl Constant {
             value.write(config, out);
             write!(out, ";");
         } else {
-            write!(out, "#define {} ", name);
+            if self.associated_to.is_some() {
+                write!(out, "#define {} ", config.define_name(&name));
+            } else {
+                write!(out, "#define {} ", name);
+            }
             value.write(config, out);
         }
         condition.write_after(config, out);
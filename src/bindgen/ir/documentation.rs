@@ -2,9 +2,10 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::HashSet;
 use std::io::Write;
 
-use crate::bindgen::config::{Config, DocumentationStyle, Language};
+use crate::bindgen::config::{Config, DocumentationPlacement, DocumentationStyle, Language};
 use crate::bindgen::utilities::SynAttributeHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
@@ -24,6 +25,13 @@ impl Documentation {
         Documentation { doc_comment: doc }
     }
 
+    /// Loads the inner `//!` documentation of a module.
+    pub fn load_inner(attrs: &[syn::Attribute]) -> Self {
+        Documentation {
+            doc_comment: attrs.get_inner_comment_lines(),
+        }
+    }
+
     pub fn simple(line: &str) -> Self {
         Documentation {
             doc_comment: vec![line.to_owned()],
@@ -35,6 +43,161 @@ impl Documentation {
             doc_comment: Vec::new(),
         }
     }
+
+    /// This documentation with Doxygen tags for the arguments named `params`
+    /// and, if `has_return`, the return value of a function. The items of an
+    /// `# Arguments` list which name an argument become `@param` tags, and the
+    /// first line of a `# Returns` section a `@return` tag. Bare tags are
+    /// added for the rest.
+    pub fn with_doxygen_tags(&self, params: &[&str], has_return: bool) -> Documentation {
+        let mut doc_comment = Vec::new();
+        let mut documented_params = HashSet::new();
+        let mut has_return_tag = false;
+        let mut in_returns = false;
+        for line in &self.doc_comment {
+            let trimmed = line.trim();
+            match trimmed {
+                "# Arguments" | "# Parameters" => continue,
+                "# Returns" => {
+                    in_returns = true;
+                    continue;
+                }
+                _ => {}
+            }
+            if in_returns && !trimmed.is_empty() {
+                in_returns = false;
+                has_return_tag = true;
+                doc_comment.push(format!(" @return {}", trimmed));
+                continue;
+            }
+            if let Some((name, text)) = parse_argument_item(trimmed) {
+                if params.contains(&name) {
+                    documented_params.insert(name.to_owned());
+                    doc_comment.push(format!(" @param {} {}", name, text).trim_end().to_owned());
+                    continue;
+                }
+            }
+            if is_tag(trimmed, "param") {
+                let rest = &trimmed["@param".len()..];
+                // Skips a direction, like `@param[in] name`.
+                let rest = if rest.starts_with('[') {
+                    rest.find(']').map_or("", |i| &rest[i + 1..])
+                } else {
+                    rest
+                };
+                if let Some(name) = rest.split_whitespace().next() {
+                    documented_params.insert(name.to_owned());
+                }
+            }
+            if is_tag(trimmed, "return") {
+                has_return_tag = true;
+            }
+            doc_comment.push(line.clone());
+        }
+
+        // Removing the section headings can leave several blank lines in a row.
+        doc_comment.dedup_by(|a, b| a.trim().is_empty() && b.trim().is_empty());
+        while let Some(last) = doc_comment.last() {
+            if !last.trim().is_empty() {
+                break;
+            }
+            doc_comment.pop();
+        }
+
+        let missing_params: Vec<String> = params
+            .iter()
+            .filter(|x| !documented_params.contains(**x))
+            .map(|x| format!(" @param {}", x))
+            .collect();
+        // Bare `@param` tags go after the other ones, or before `@return`.
+        let position = match doc_comment.iter().rposition(|x| is_tag(x, "param")) {
+            Some(i) => Some(i + 1),
+            None => doc_comment.iter().position(|x| is_tag(x, "return")),
+        };
+        match position {
+            Some(i) => {
+                doc_comment.splice(i..i, missing_params);
+            }
+            None => push_tags(&mut doc_comment, missing_params),
+        }
+        if has_return && !has_return_tag {
+            push_tags(&mut doc_comment, vec![" @return".to_owned()]);
+        }
+        Documentation { doc_comment }
+    }
+
+    /// Whether the documentation of a field or variant should be written at
+    /// the end of its line. Only single line comments can be trailing.
+    fn is_trailing(&self, config: &Config) -> bool {
+        config.member_documentation_placement == DocumentationPlacement::Trailing
+            && self.doc_comment.len() == 1
+    }
+
+    /// Writes the documentation of a field or variant which comes before it.
+    pub fn write_leading<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if !self.is_trailing(config) {
+            self.write(config, out);
+        }
+    }
+
+    /// Writes the documentation of a field or variant which comes after it,
+    /// on the same line.
+    pub fn write_trailing<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if !config.documentation || !self.is_trailing(config) {
+            return;
+        }
+
+        let line = &self.doc_comment[0];
+        match style(config) {
+            DocumentationStyle::C => write!(out, " /*{} */", line),
+            DocumentationStyle::Doxy => write!(out, " /**<{} */", line),
+            DocumentationStyle::C99 => write!(out, " //{}", line),
+            DocumentationStyle::Cxx => write!(out, " ///<{}", line),
+            DocumentationStyle::Auto => unreachable!(), // Auto case should always be covered
+        }
+    }
+}
+
+/// Whether `line` starts with the Doxygen tag `name`, as `@name` or `\\name`.
+fn is_tag(line: &str, name: &str) -> bool {
+    let line = line.trim_start();
+    (line.starts_with('@') || line.starts_with('\\')) && line[1..].starts_with(name)
+}
+
+/// Appends `tags` to `doc_comment`, after a blank line unless it ends with
+/// other tags.
+fn push_tags(doc_comment: &mut Vec<String>, tags: Vec<String>) {
+    if tags.is_empty() {
+        return;
+    }
+    if let Some(last) = doc_comment.last() {
+        if !is_tag(last, "param") && !is_tag(last, "return") {
+            doc_comment.push(String::new());
+        }
+    }
+    doc_comment.extend(tags);
+}
+
+/// The name and text of a Markdown list item documenting an argument, like
+/// "* `name` - text" or "- `name`: text".
+fn parse_argument_item(line: &str) -> Option<(&str, &str)> {
+    if !line.starts_with("* `") && !line.starts_with("- `") {
+        return None;
+    }
+    let item = &line[3..];
+    let end = item.find('`')?;
+    let text =
+        item[end + 1..].trim_start_matches(|c: char| c == '-' || c == ':' || c.is_whitespace());
+    Some((&item[..end], text))
+}
+
+fn style(config: &Config) -> DocumentationStyle {
+    match config.documentation_style {
+        DocumentationStyle::Auto if config.language == Language::C => DocumentationStyle::Doxy,
+        DocumentationStyle::Auto if config.language == Language::Cxx => DocumentationStyle::Cxx,
+        DocumentationStyle::Auto => DocumentationStyle::C, // Fallback if `Language` gets extended.
+        other => other,
+    }
 }
 
 impl Source for Documentation {
@@ -43,12 +206,7 @@ impl Source for Documentation {
             return;
         }
 
-        let style = match config.documentation_style {
-            DocumentationStyle::Auto if config.language == Language::C => DocumentationStyle::Doxy,
-            DocumentationStyle::Auto if config.language == Language::Cxx => DocumentationStyle::Cxx,
-            DocumentationStyle::Auto => DocumentationStyle::C, // Fallback if `Language` gets extended.
-            other => other,
-        };
+        let style = style(config);
 
         // Following these documents for style conventions:
         // https://en.wikibooks.org/wiki/C++_Programming/Code/Style_Conventions/Comments
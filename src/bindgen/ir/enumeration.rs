@@ -2,14 +2,17 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
+use std::collections::HashMap;
+use std::convert::TryFrom;
 use std::io::Write;
 
-use crate::bindgen::config::{Config, Language};
+use crate::bindgen::config::{Config, EnumDefinesStyle, EnumTypedefStyle, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, AnnotationValue, Cfg, ConditionWrite, Documentation, GenericParams, GenericPath,
-    Item, ItemContainer, Path, Repr, ReprStyle, ReprType, Struct, ToCondition, Type,
+    AnnotationSet, AnnotationValue, Cfg, ConditionWrite, Constant, Documentation, GenericParams,
+    GenericPath, Item, ItemContainer, ItemMap, Literal, Path, Repr, ReprStyle, ReprType, Struct,
+    ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -70,6 +73,9 @@ pub struct EnumVariant {
     pub name: String,
     pub export_name: String,
     pub discriminant: Option<i64>,
+    /// A discriminant that refers to constants or other variants, which is
+    /// evaluated into `discriminant` by `Enum::resolve_discriminants`.
+    pub discriminant_expr: Option<Literal>,
     pub body: VariantBody,
     pub cfg: Option<Cfg>,
     pub documentation: Documentation,
@@ -86,13 +92,192 @@ fn value_from_expr(val: &syn::Expr) -> Option<i64> {
             match unary.op {
                 syn::UnOp::Deref(..) => None,
                 syn::UnOp::Neg(..) => v.checked_mul(-1),
-                syn::UnOp::Not(..) => v.checked_neg(),
+                // The complement depends on the repr, see `complement`.
+                syn::UnOp::Not(..) => None,
             }
         }
         _ => None,
     }
 }
 
+/// The prefix of the names of the variants a discriminant refers to, which
+/// keeps them apart from the constants it refers to.
+const VARIANT_PREFIX: &str = "Self::";
+
+/// Loads a discriminant that isn't a plain integer. Only integers, names of
+/// constants, paths to variants of the same enum (`Self::A` or `MyEnum::A`),
+/// parentheses, casts, `-`, `!` and the arithmetic and bitwise binary
+/// operators are supported.
+fn discriminant_expr_from_syn(expr: &syn::Expr, self_path: &Path) -> Result<Literal, String> {
+    match *expr {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(ref lit),
+            ..
+        }) => Ok(Literal::Expr(lit.base10_digits().to_owned())),
+        syn::Expr::Path(syn::ExprPath { ref path, .. }) => {
+            let segments: Vec<_> = path.segments.iter().map(|x| x.ident.to_string()).collect();
+            match segments[..] {
+                [ref name] => Ok(Literal::Path(name.clone())),
+                [ref ty, ref name] if ty == "Self" || ty == self_path.name() => {
+                    Ok(Literal::Path(format!("{}{}", VARIANT_PREFIX, name)))
+                }
+                _ => Err(format!(
+                    "Unsupported path `{}` in discriminant.",
+                    segments.join("::")
+                )),
+            }
+        }
+        // Casts between integer types don't change the value of in-range
+        // discriminants, so they're ignored.
+        syn::Expr::Paren(syn::ExprParen { ref expr, .. })
+        | syn::Expr::Cast(syn::ExprCast { ref expr, .. }) => {
+            discriminant_expr_from_syn(expr, self_path)
+        }
+        syn::Expr::Unary(syn::ExprUnary {
+            ref op, ref expr, ..
+        }) => {
+            let op = match *op {
+                syn::UnOp::Neg(..) => "-",
+                syn::UnOp::Not(..) => "~",
+                syn::UnOp::Deref(..) => {
+                    return Err("Unsupported dereference in discriminant.".to_owned())
+                }
+            };
+            Ok(Literal::PostfixUnaryOp {
+                op,
+                value: Box::new(discriminant_expr_from_syn(expr, self_path)?),
+            })
+        }
+        syn::Expr::Binary(syn::ExprBinary {
+            ref left,
+            ref op,
+            ref right,
+            ..
+        }) => {
+            let op = match *op {
+                syn::BinOp::Add(..) => "+",
+                syn::BinOp::Sub(..) => "-",
+                syn::BinOp::Mul(..) => "*",
+                syn::BinOp::Div(..) => "/",
+                syn::BinOp::Rem(..) => "%",
+                syn::BinOp::BitXor(..) => "^",
+                syn::BinOp::BitAnd(..) => "&",
+                syn::BinOp::BitOr(..) => "|",
+                syn::BinOp::Shl(..) => "<<",
+                syn::BinOp::Shr(..) => ">>",
+                _ => return Err(format!("Unsupported operator {:?} in discriminant.", op)),
+            };
+            Ok(Literal::BinOp {
+                left: Box::new(discriminant_expr_from_syn(left, self_path)?),
+                op,
+                right: Box::new(discriminant_expr_from_syn(right, self_path)?),
+            })
+        }
+        _ => Err(format!("Unsupported discriminant {:?}.", expr)),
+    }
+}
+
+/// Evaluates a discriminant loaded by `discriminant_expr_from_syn`, looking up
+/// the variants it refers to in `variants`, and the constants in `constants`.
+/// `!` is evaluated in the integer type of `repr`.
+fn evaluate_discriminant(
+    expr: &Literal,
+    variants: &HashMap<&str, i64>,
+    constants: &ItemMap<Constant>,
+    repr: Option<ReprType>,
+    depth: usize,
+) -> Result<i64, String> {
+    // Constants can't be cyclic in valid Rust, but don't hang on invalid code.
+    if depth > 64 {
+        return Err("Discriminant is too deeply nested.".to_owned());
+    }
+    let overflow = || "Discriminant overflows.".to_owned();
+    match *expr {
+        Literal::Expr(ref value) => value
+            .parse::<i64>()
+            .map_err(|_| format!("Discriminant value {} isn't an integer.", value)),
+        Literal::Path(ref name) if name.starts_with(VARIANT_PREFIX) => {
+            let name = &name[VARIANT_PREFIX.len()..];
+            variants
+                .get(name)
+                .cloned()
+                .ok_or_else(|| format!("Unknown earlier variant {} in discriminant.", name))
+        }
+        Literal::Path(ref name) => {
+            // Constants can't refer to the variants of the enum.
+            let no_variants = HashMap::new();
+            let mut values = Vec::new();
+            constants.for_items(&Path::new(name.clone()), |constant| {
+                values.push(evaluate_discriminant(
+                    &constant.value,
+                    &no_variants,
+                    constants,
+                    repr,
+                    depth + 1,
+                ));
+            });
+            match values.len() {
+                0 => Err(format!("Unknown constant {} in discriminant.", name)),
+                1 => values.pop().unwrap(),
+                _ => Err(format!(
+                    "Constant {} in discriminant depends on cfgs.",
+                    name
+                )),
+            }
+        }
+        Literal::Cast { ref value, .. } => {
+            evaluate_discriminant(value, variants, constants, repr, depth + 1)
+        }
+        Literal::PostfixUnaryOp { op, ref value } => {
+            let value = evaluate_discriminant(value, variants, constants, repr, depth + 1)?;
+            match op {
+                "-" => value.checked_neg().ok_or_else(overflow),
+                "~" => complement(value, repr).ok_or_else(overflow),
+                _ => Err(format!("Unsupported operator {} in discriminant.", op)),
+            }
+        }
+        Literal::BinOp {
+            ref left,
+            op,
+            ref right,
+        } => {
+            let left = evaluate_discriminant(left, variants, constants, repr, depth + 1)?;
+            let right = evaluate_discriminant(right, variants, constants, repr, depth + 1)?;
+            let value = match op {
+                "+" => left.checked_add(right),
+                "-" => left.checked_sub(right),
+                "*" => left.checked_mul(right),
+                "/" => left.checked_div(right),
+                "%" => left.checked_rem(right),
+                "^" => Some(left ^ right),
+                "&" => Some(left & right),
+                "|" => Some(left | right),
+                "<<" => u32::try_from(right).ok().and_then(|x| left.checked_shl(x)),
+                ">>" => u32::try_from(right).ok().and_then(|x| left.checked_shr(x)),
+                _ => return Err(format!("Unsupported operator {} in discriminant.", op)),
+            };
+            value.ok_or_else(overflow)
+        }
+        Literal::Struct { .. } => Err("Discriminant isn't an integer.".to_owned()),
+    }
+}
+
+/// The bitwise complement of `value` in the integer type of `repr`, like Rust
+/// computes `!value` in a discriminant. Enums without an integer repr have
+/// `isize` discriminants. `None` if it doesn't fit in an `i64`.
+fn complement(value: i64, repr: Option<ReprType>) -> Option<i64> {
+    match repr {
+        Some(ReprType::U8) => Some(i64::from(!(value as u8))),
+        Some(ReprType::U16) => Some(i64::from(!(value as u16))),
+        Some(ReprType::U32) => Some(i64::from(!(value as u32))),
+        Some(ReprType::U64) | Some(ReprType::USize) => i64::try_from(!(value as u64)).ok(),
+        Some(ReprType::I8) => Some(i64::from(!(value as i8))),
+        Some(ReprType::I16) => Some(i64::from(!(value as i16))),
+        Some(ReprType::I32) => Some(i64::from(!(value as i32))),
+        Some(ReprType::I64) | Some(ReprType::ISize) | None => Some(!value),
+    }
+}
+
 impl EnumVariant {
     fn load(
         is_tagged: bool,
@@ -102,12 +287,12 @@ impl EnumVariant {
         self_path: &Path,
         enum_annotations: &AnnotationSet,
     ) -> Result<Self, String> {
-        let discriminant = match variant.discriminant {
+        let (discriminant, discriminant_expr) = match variant.discriminant {
             Some((_, ref expr)) => match value_from_expr(expr) {
-                Some(v) => Some(v),
-                None => return Err(format!("Unsupported discriminant {:?}.", expr)),
+                Some(v) => (Some(v), None),
+                None => (None, Some(discriminant_expr_from_syn(expr, self_path)?)),
             },
-            None => None,
+            None => (None, None),
         };
 
         fn parse_fields(
@@ -195,13 +380,15 @@ impl EnumVariant {
             }
         };
 
-        Ok(EnumVariant::new(
+        let mut result = EnumVariant::new(
             variant.ident.to_string(),
             discriminant,
             body,
             variant_cfg,
             Documentation::load(&variant.attrs),
-        ))
+        );
+        result.discriminant_expr = discriminant_expr;
+        Ok(result)
     }
 
     pub fn new(
@@ -216,6 +403,7 @@ impl EnumVariant {
             name,
             export_name,
             discriminant,
+            discriminant_expr: None,
             body,
             cfg,
             documentation,
@@ -240,13 +428,15 @@ impl EnumVariant {
         mappings: &[(&Path, &Type)],
         config: &Config,
     ) -> Self {
-        Self::new(
+        let mut specialized = Self::new(
             mangle::mangle_name(&self.name, generic_values, &config.export.mangle),
             self.discriminant,
             self.body.specialize(generic_values, mappings, config),
             self.cfg.clone(),
             self.documentation.clone(),
-        )
+        );
+        specialized.discriminant_expr = self.discriminant_expr.clone();
+        specialized
     }
 
     fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
@@ -260,18 +450,29 @@ impl EnumVariant {
             body.mangle_paths(monomorphs);
         }
     }
+
+    fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        if let VariantBody::Body { ref mut body, .. } = self.body {
+            body.resolve_array_lengths(constants);
+        }
+    }
 }
 
-impl Source for EnumVariant {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+impl EnumVariant {
+    /// Writes the variant in the body of the enum, with a comma after it
+    /// unless it's the last one and `enum.trailing_comma` is false.
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>, is_last: bool) {
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
-        self.documentation.write(config, out);
+        self.documentation.write_leading(config, out);
         write!(out, "{}", self.export_name);
         if let Some(discriminant) = self.discriminant {
             write!(out, " = {}", discriminant);
         }
-        out.write(",");
+        if !is_last || config.enumeration.trailing_comma {
+            out.write(",");
+        }
+        self.documentation.write_trailing(config, out);
         condition.write_after(config, out);
     }
 }
@@ -317,6 +518,12 @@ impl Enum {
         }
     }
 
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        for variant in &mut self.variants {
+            variant.resolve_array_lengths(constants);
+        }
+    }
+
     pub fn load(
         item: &syn::ItemEnum,
         mod_cfg: Option<&Cfg>,
@@ -332,7 +539,7 @@ impl Enum {
         }
 
         let path = Path::new(item.ident.to_string());
-        let generic_params = GenericParams::new(&item.generics);
+        let generic_params = GenericParams::load(&item.generics)?;
 
         let mut variants = Vec::new();
         let mut is_tagged = false;
@@ -460,7 +667,7 @@ impl Item for Enum {
     }
 
     fn rename_for_config(&mut self, config: &Config) {
-        config.export.rename(&mut self.export_name);
+        config.export.rename_type(&mut self.export_name);
 
         if config.language == Language::C && self.tag.is_some() {
             // it makes sense to always prefix Tag with type name in C
@@ -593,9 +800,10 @@ impl Item for Enum {
     }
 }
 
-impl Source for Enum {
-    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        let size = self.repr.ty.map(|ty| match ty {
+impl Enum {
+    /// The C name of the integer type of the repr of this enum, if any.
+    pub fn repr_type_name(&self) -> Option<&'static str> {
+        self.repr.ty.map(|ty| match ty {
             ReprType::USize => "uintptr_t",
             ReprType::U64 => "uint64_t",
             ReprType::U32 => "uint32_t",
@@ -606,13 +814,243 @@ impl Source for Enum {
             ReprType::I32 => "int32_t",
             ReprType::I16 => "int16_t",
             ReprType::I8 => "int8_t",
-        });
+        })
+    }
+
+    /// Writes the `static inline` functions which test the variant of a tagged
+    /// enum in C, and give access to its payload if it's the right variant.
+    fn write_c_helpers<F: Write>(
+        &self,
+        separate_tag: bool,
+        config: &Config,
+        out: &mut SourceWriter<F>,
+    ) {
+        let helper_name = |template: &str, variant: &EnumVariant| {
+            template
+                .replace("{enum}", self.export_name())
+                .replace("{variant}", &variant.export_name)
+        };
+        let type_name = |keyword: &str, name: &str| {
+            if config.style.generate_typedef() {
+                name.to_owned()
+            } else {
+                format!("{} {}", keyword, name)
+            }
+        };
+        let this = config
+            .function
+            .rename_args
+            .apply("self", IdentifierType::FunctionArg);
+        let self_type = type_name(
+            if separate_tag { "struct" } else { "union" },
+            &self.export_name,
+        );
+
+        for variant in &self.variants {
+            out.new_line();
+            out.new_line();
+
+            let condition = variant.cfg.to_condition(config);
+            condition.write_before(config, out);
+
+            let is_name = helper_name(&config.enumeration.tagged_enum_is_name, variant);
+            write!(
+                out,
+                "static inline bool {}(const {} *{})",
+                is_name, self_type, this
+            );
+            out.open_brace();
+            write!(out, "return {}->tag == {};", this, variant.export_name);
+            out.close_brace(false);
+
+            if let VariantBody::Body { ref name, ref body } = variant.body {
+                out.new_line();
+                out.new_line();
+                write!(
+                    out,
+                    "static inline const {} *{}(const {} *{})",
+                    type_name("struct", body.export_name()),
+                    helper_name(&config.enumeration.tagged_enum_as_name, variant),
+                    self_type,
+                    this
+                );
+                out.open_brace();
+                write!(
+                    out,
+                    "return {}->tag == {} ? &{}->{} : NULL;",
+                    this, variant.export_name, this, name
+                );
+                out.close_brace(false);
+            }
+
+            condition.write_after(config, out);
+        }
+    }
+
+    /// Whether this enum is written as a typedef and defines rather than as
+    /// an `enum`, see `EnumConfig::as_defines` and
+    /// `EnumConfig::single_variant_as_define`. Only `static const` values are
+    /// written in C++.
+    pub fn is_written_as_defines(&self, config: &Config) -> bool {
+        let is_single_variant =
+            config.enumeration.single_variant_as_define && self.variants.len() == 1;
+        (config.language == Language::C
+            || config.enumeration.as_defines_style == EnumDefinesStyle::StaticConst)
+            && (config.enumeration.as_defines(&self.annotations) && self.repr.ty.is_some()
+                || is_single_variant)
+            && self.tag.is_none()
+            && self.variants.iter().all(|x| x.cfg.is_none())
+    }
+
+    /// Whether the enum is declared as an `enum class` for C++ compilers in a C
+    /// header with `cpp_compat`.
+    fn is_cpp_compat_enum_class(&self, config: &Config) -> bool {
+        config.cpp_compat
+            && config.enumeration.cpp_compat_enum_class
+            && config.enumeration.enum_class(&self.annotations)
+            && self.tag.is_none()
+            && self.repr.ty.is_some()
+    }
+
+    /// Writes a fieldless enum as a typedef of its integer type and a define
+    /// or constant for each of its variants.
+    fn write_as_defines<F: Write>(&self, prim: &str, config: &Config, out: &mut SourceWriter<F>) {
+        if config.language == Language::C {
+            write!(out, "typedef {} {};", prim, self.export_name());
+        } else {
+            write!(out, "using {} = {};", self.export_name(), prim);
+        }
+
+        for (variant, value) in self.variants.iter().zip(self.discriminant_values()) {
+            out.new_line();
+            variant.documentation.write(config, out);
+            if config.enumeration.as_defines_style == EnumDefinesStyle::StaticConst {
+                write!(
+                    out,
+                    "static const {} {} = {};",
+                    self.export_name(),
+                    variant.export_name,
+                    value
+                );
+            } else if value < 0 {
+                let name = config.define_name(&variant.export_name);
+                write!(out, "#define {} ({})", name, value);
+            } else {
+                let name = config.define_name(&variant.export_name);
+                write!(out, "#define {} {}", name, value);
+            }
+        }
+    }
+
+    /// Evaluates the discriminants that refer to constants or other variants
+    /// into plain integers.
+    pub fn resolve_discriminants(&mut self, constants: &ItemMap<Constant>) -> Result<(), String> {
+        let mut values = HashMap::new();
+        let mut value = 0;
+        for variant in &mut self.variants {
+            if let Some(ref expr) = variant.discriminant_expr {
+                variant.discriminant = Some(evaluate_discriminant(
+                    expr,
+                    &values,
+                    constants,
+                    self.repr.ty,
+                    0,
+                )?);
+                variant.discriminant_expr = None;
+            }
+            if let Some(discriminant) = variant.discriminant {
+                value = discriminant;
+            }
+            values.insert(variant.name.as_str(), value);
+            value = value.wrapping_add(1);
+        }
+        Ok(())
+    }
+
+    /// The values of the variants, computed like C does: implicit
+    /// discriminants are one more than the previous one.
+    fn discriminant_values(&self) -> Vec<i64> {
+        let mut value = 0;
+        self.variants
+            .iter()
+            .map(|variant| {
+                if let Some(discriminant) = variant.discriminant {
+                    value = discriminant;
+                }
+                let current = value;
+                value = value.wrapping_add(1);
+                current
+            })
+            .collect()
+    }
+
+    /// Whether each variant has the same value as an earlier variant without
+    /// cfgs, which C allows. Switches over the enum can only have a case for
+    /// the first of these.
+    fn aliased_variants(&self) -> Vec<bool> {
+        let values = self.discriminant_values();
+        (0..values.len())
+            .map(|i| (0..i).any(|j| values[j] == values[i] && self.variants[j].cfg.is_none()))
+            .collect()
+    }
+
+    /// Writes a define with the number of variants, if they can be iterated
+    /// from 0 to it.
+    fn write_count<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.variants.iter().any(|x| x.cfg.is_some()) {
+            warn!(
+                "Not writing the variant count of {}, since it depends on cfgs.",
+                self.export_name()
+            );
+            return;
+        }
+        let values = self.discriminant_values();
+        if values
+            .iter()
+            .enumerate()
+            .any(|(i, value)| *value != i as i64)
+        {
+            warn!(
+                "Not writing the variant count of {}, since its discriminants aren't \
+                 contiguous from 0.",
+                self.export_name()
+            );
+            return;
+        }
+
+        out.new_line();
+        out.new_line();
+        let name = config
+            .enumeration
+            .count_name
+            .replace("{enum}", self.export_name());
+        let name = config.define_name(&name);
+        write!(out, "#define {} {}", name, values.len());
+    }
+}
+
+impl Source for Enum {
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        let size = self.repr_type_name();
 
         let condition = self.cfg.to_condition(config);
         condition.write_before(config, out);
 
         self.documentation.write(config, out);
 
+        let add_count = config.enumeration.add_count(&self.annotations);
+
+        if self.is_written_as_defines(config) {
+            // `#[repr(C)]` enums have the size of C enums, which is that of
+            // `int` for small values.
+            self.write_as_defines(size.unwrap_or("int"), config, out);
+            if add_count {
+                self.write_count(config, out);
+            }
+            condition.write_after(config, out);
+            return;
+        }
+
         let is_tagged = self.tag.is_some();
         let separate_tag = self.repr.style == ReprStyle::C;
 
@@ -646,14 +1084,33 @@ impl Source for Enum {
         };
 
         // Emit the actual enum
-        if config.language == Language::C {
-            if size.is_none() && config.style.generate_typedef() {
+        if config.language == Language::C && self.is_cpp_compat_enum_class(config) {
+            let prim = size.unwrap();
+            out.write("#ifdef __cplusplus");
+            out.new_line();
+            write!(out, "enum class {} : {}", enum_name, prim);
+            out.new_line();
+            out.write("#else");
+            out.new_line();
+            write!(out, "enum {}", enum_name);
+            out.new_line();
+            out.write("#endif // __cplusplus");
+            out.new_line();
+        } else if config.language == Language::C {
+            let is_typedef = size.is_none() && config.style.generate_typedef();
+            if is_typedef {
                 out.write("typedef ");
             }
 
             out.write("enum");
 
-            if size.is_some() || config.style.generate_tag() {
+            let is_named = !is_typedef
+                || match config.enumeration.typedef_style {
+                    Some(EnumTypedefStyle::Named) => true,
+                    Some(EnumTypedefStyle::Anonymous) => false,
+                    None => config.style.generate_tag(),
+                };
+            if is_named {
                 write!(out, " {}", enum_name);
             }
 
@@ -691,7 +1148,7 @@ impl Source for Enum {
             if i != 0 {
                 out.new_line()
             }
-            variant.write(config, out);
+            variant.write(config, out, i == self.variants.len() - 1);
         }
 
         if config.language == Language::C && size.is_none() && config.style.generate_typedef() {
@@ -701,7 +1158,9 @@ impl Source for Enum {
             out.close_brace(true);
         }
 
-        if config.language == Language::C {
+        // A forward declaration of an untagged enum already has the typedef.
+        let is_forward_declared = !is_tagged && out.bindings().is_forward_declared(&self.path);
+        if config.language == Language::C && !is_forward_declared {
             if let Some(prim) = size {
                 if config.cpp_compat {
                     out.new_line_if_not_start();
@@ -772,17 +1231,26 @@ impl Source for Enum {
             }
             write!(out, "switch ({})", instance);
             out.open_brace();
-            let vec: Vec<_> = self
+            let aliased = self.aliased_variants();
+            let cases = self
                 .variants
                 .iter()
-                .map(|x| {
-                    format!(
-                        "case {}::{}: {} << \"{}\"; break;",
-                        enum_name, x.export_name, stream, x.export_name
-                    )
-                })
-                .collect();
-            out.write_vertical_source_list(&vec[..], ListType::Join(""));
+                .zip(aliased)
+                .filter(|&(_, is_alias)| !is_alias)
+                .map(|(x, _)| x);
+            for (i, variant) in cases.enumerate() {
+                if i != 0 {
+                    out.new_line();
+                }
+                let condition = variant.cfg.to_condition(config);
+                condition.write_before(config, out);
+                write!(
+                    out,
+                    "case {}::{}: {} << \"{}\"; break;",
+                    enum_name, variant.export_name, stream, variant.export_name
+                );
+                condition.write_after(config, out);
+            }
             out.close_brace(false);
             out.new_line();
 
@@ -866,15 +1334,20 @@ impl Source for Enum {
             out.new_line();
             out.new_line();
 
-            // Emit the actual union
+            // Emit the actual union, without a typedef if its forward
+            // declaration has it
+            let is_forward_declared = out.bindings().is_forward_declared(&self.path);
+            let is_typedef = config.language == Language::C
+                && config.style.generate_typedef()
+                && !is_forward_declared;
             if config.language == Language::C {
-                if config.style.generate_typedef() {
+                if is_typedef {
                     out.write("typedef ");
                 }
 
                 out.write(if separate_tag { "struct" } else { "union" });
 
-                if config.style.generate_tag() {
+                if config.style.generate_tag() || is_forward_declared {
                     write!(out, " {}", self.export_name());
                 }
 
@@ -1330,13 +1803,24 @@ impl Source for Enum {
                 out.write_raw_block(body);
             }
 
-            if config.language == Language::C && config.style.generate_typedef() {
+            if is_typedef {
                 out.close_brace(false);
                 write!(out, " {};", self.export_name);
             } else {
                 out.close_brace(true);
             }
+
+            if config.language == Language::C
+                && config.enumeration.tagged_enum_helpers(&self.annotations)
+            {
+                self.write_c_helpers(separate_tag, config, out);
+            }
+        }
+
+        if add_count {
+            self.write_count(config, out);
         }
+
         condition.write_after(config, out);
     }
 }
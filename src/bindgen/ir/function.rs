@@ -6,18 +6,18 @@ use std::collections::HashMap;
 use std::io::Write;
 
 use crate::bindgen::cdecl;
-use crate::bindgen::config::{Config, Layout};
+use crate::bindgen::config::{Config, DocumentationFormat, Layout};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericPath, Path, PrimitiveType,
-    ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, GenericPath, ItemMap, Path,
+    PrimitiveType, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::reserved;
-use crate::bindgen::utilities::IterHelpers;
+use crate::bindgen::utilities::{IterHelpers, SynAttributeHelpers};
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Debug, Clone)]
@@ -25,11 +25,17 @@ pub struct FunctionArgument {
     pub name: Option<String>,
     pub ty: Type,
     pub array_length: Option<String>,
+    /// Whether the argument is a `&mut` reference, which can't alias any other
+    /// argument.
+    pub is_mut_ref: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct Function {
     pub path: Path,
+    /// The name of the Rust function, which isn't the name of `path` with
+    /// `#[export_name]` or a `result-wrapper`.
+    pub rust_name: String,
     /// Path to the self-type of the function
     /// If the function is a method, this will contain the path of the type in the impl block
     pub self_type_path: Option<Path>,
@@ -40,6 +46,11 @@ pub struct Function {
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
     pub never_return: bool,
+    /// Whether the Rust function is `#[inline]`, see `Function::is_inline`.
+    inline: bool,
+    /// Whether some arguments have no name in Rust, and are named after their
+    /// position instead.
+    has_unnamed_args: bool,
 }
 
 impl Function {
@@ -50,16 +61,61 @@ impl Function {
         extern_decl: bool,
         attrs: &[syn::Attribute],
         mod_cfg: Option<&Cfg>,
+        config: &Config,
     ) -> Result<Function, String> {
         let mut args = sig.inputs.iter().try_skip_map(|x| x.as_argument())?;
+        let has_unnamed_args = args.iter().any(|x| x.name.is_none());
+        name_unnamed_args(&mut args);
+
+        let annotations = AnnotationSet::load(attrs)?;
+        // The path of a function with a `result-wrapper` is the wrapper's.
+        let is_result_wrapper = !extern_decl && annotations.atom("result-wrapper").is_some();
 
         let mut never_return = false;
         let mut ret = match sig.output {
+            syn::ReturnType::Default if is_result_wrapper => {
+                return Err("Only functions returning a `Result` can have a wrapper.".to_owned());
+            }
             syn::ReturnType::Default => Type::Primitive(PrimitiveType::Void),
             syn::ReturnType::Type(_, ref ty) => {
+                let result = if is_result_wrapper {
+                    match load_result(ty)? {
+                        Some(result) => Some(result),
+                        None => {
+                            return Err(
+                                "Only functions returning a `Result<T, E>` can have a wrapper."
+                                    .to_owned(),
+                            )
+                        }
+                    }
+                } else {
+                    None
+                };
                 if let syn::Type::Never(_) = ty.as_ref() {
                     never_return = true;
                     Type::Primitive(PrimitiveType::Void)
+                } else if let Some((ok, err)) = result {
+                    if let Some(ok) = ok {
+                        let name = &config.function.result_wrapper_arg;
+                        if args.iter().any(|x| x.name.as_ref() == Some(name)) {
+                            return Err(format!(
+                                "Result can't be lowered, an argument is already named `{}`.",
+                                name
+                            ));
+                        }
+                        args.push(FunctionArgument {
+                            name: Some(name.clone()),
+                            ty: Type::Ptr {
+                                ty: Box::new(ok),
+                                is_const: false,
+                                is_nullable: false,
+                                is_ref: false,
+                            },
+                            array_length: None,
+                            is_mut_ref: false,
+                        });
+                    }
+                    err
                 } else {
                     Type::load(ty)?.unwrap_or_else(|| Type::Primitive(PrimitiveType::Void))
                 }
@@ -75,14 +131,17 @@ impl Function {
 
         Ok(Function {
             path,
+            rust_name: sig.ident.to_string(),
             self_type_path: self_type_path.cloned(),
             ret,
             args,
             extern_decl,
             cfg: Cfg::append(mod_cfg, Cfg::load(attrs)),
-            annotations: AnnotationSet::load(attrs)?,
+            annotations,
             documentation: Documentation::load(attrs),
             never_return,
+            inline: attrs.is_inline(),
+            has_unnamed_args,
         })
     }
 
@@ -106,6 +165,11 @@ impl Function {
             .trim_start_matches(type_name)
             .trim_start_matches('_');
 
+        // The Swift name only has labels for the arguments named in Rust.
+        if self.has_unnamed_args {
+            return None;
+        }
+
         let item_args = {
             let mut items = Vec::with_capacity(self.args.len());
             for arg in self.args.iter() {
@@ -120,6 +184,13 @@ impl Function {
         &self.path
     }
 
+    /// Whether the Rust function is `#[inline]` or `#[inline(always)]`. This
+    /// doesn't change its declaration, but backends which can write the body
+    /// of the function could write an inline wrapper for it.
+    pub fn is_inline(&self) -> bool {
+        self.inline
+    }
+
     pub fn simplify_standard_types(&mut self, config: &Config) {
         self.ret.simplify_standard_types(config);
         for arg in &mut self.args {
@@ -127,6 +198,13 @@ impl Function {
         }
     }
 
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        self.ret.resolve_array_lengths(constants);
+        for arg in &mut self.args {
+            arg.ty.resolve_array_lengths(constants);
+        }
+    }
+
     pub fn add_dependencies(&self, library: &Library, out: &mut Dependencies) {
         self.ret.add_dependencies(library, out);
         for arg in &self.args {
@@ -178,6 +256,7 @@ impl Function {
                         name,
                         ty: arg.ty,
                         array_length: None,
+                        is_mut_ref: arg.is_mut_ref,
                     }
                 })
                 .collect()
@@ -226,6 +305,26 @@ impl Function {
     }
 }
 
+impl Function {
+    fn is_maybe_unused(&self) -> bool {
+        self.annotations.bool("maybe-unused").unwrap_or(false)
+    }
+
+    fn write_documentation<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        match config.documentation_format {
+            DocumentationFormat::Plain => self.documentation.write(config, out),
+            DocumentationFormat::Doxygen => {
+                let params: Vec<_> = self.args.iter().filter_map(|x| x.name.as_deref()).collect();
+                let has_return =
+                    !self.never_return && self.ret != Type::Primitive(PrimitiveType::Void);
+                self.documentation
+                    .with_doxygen_tags(&params, has_return)
+                    .write(config, out);
+            }
+        }
+    }
+}
+
 impl Source for Function {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         fn write_1<W: Write>(func: &Function, config: &Config, out: &mut SourceWriter<W>) {
@@ -235,8 +334,11 @@ impl Source for Function {
             let condition = func.cfg.to_condition(config);
             condition.write_before(config, out);
 
-            func.documentation.write(config, out);
+            func.write_documentation(config, out);
 
+            if func.is_maybe_unused() {
+                write!(out, "{} ", config.maybe_unused_attribute());
+            }
             if func.extern_decl {
                 out.write("extern ");
             } else {
@@ -282,8 +384,12 @@ impl Source for Function {
 
             condition.write_before(config, out);
 
-            func.documentation.write(config, out);
+            func.write_documentation(config, out);
 
+            if func.is_maybe_unused() {
+                out.write(config.maybe_unused_attribute());
+                out.new_line();
+            }
             if func.extern_decl {
                 out.write("extern ");
             } else {
@@ -335,6 +441,66 @@ impl Source for Function {
     }
 }
 
+/// Splits a `Result<T, E>` return type into its `Ok` and `Err` types for
+/// `cbindgen:result-wrapper`, the former being `None` for `()`. Returns `None`
+/// for other types. Whether the types can cross the FFI boundary is checked
+/// once the whole crate is loaded, by `Library::remove_unsafe_result_wrappers`.
+fn load_result(ty: &syn::Type) -> Result<Option<(Option<Type>, Type)>, String> {
+    let segment = match *ty {
+        syn::Type::Path(syn::TypePath {
+            qself: None,
+            ref path,
+        }) => path.segments.last().unwrap(),
+        _ => return Ok(None),
+    };
+    if segment.ident != "Result" {
+        return Ok(None);
+    }
+    let args: Vec<_> = match segment.arguments {
+        syn::PathArguments::AngleBracketed(ref arguments) => arguments
+            .args
+            .iter()
+            .filter_map(|x| match *x {
+                syn::GenericArgument::Type(ref x) => Some(x),
+                _ => None,
+            })
+            .collect(),
+        _ => return Ok(None),
+    };
+    // Aliases like `io::Result<T>` don't tell us the error type.
+    if args.len() != 2 {
+        return Ok(None);
+    }
+
+    let ok = Type::load(args[0])?;
+    let err = match Type::load(args[1])? {
+        Some(err) => err,
+        None => return Err("Result with a `()` error type can't be lowered.".to_owned()),
+    };
+    if let Some(Type::Array(..)) = ok {
+        return Err("Result with an array `Ok` type can't be lowered.".to_owned());
+    }
+    if let Type::Array(..) = err {
+        return Err("Result with an array error type can't be lowered.".to_owned());
+    }
+    Ok(Some((ok, err)))
+}
+
+/// Names the arguments without a name, like `_`, after their position, e.g.
+/// `arg0`, so that the C declaration can refer to them.
+fn name_unnamed_args(args: &mut [FunctionArgument]) {
+    for i in 0..args.len() {
+        if args[i].name.is_some() {
+            continue;
+        }
+        let mut name = format!("arg{}", i);
+        while args.iter().any(|x| x.name.as_ref() == Some(&name)) {
+            name.push('_');
+        }
+        args[i].name = Some(name);
+    }
+}
+
 trait SynFnArgHelpers {
     fn as_argument(&self) -> Result<Option<FunctionArgument>, String>;
 }
@@ -360,15 +526,14 @@ impl SynFnArgHelpers for syn::FnArg {
             syn::FnArg::Typed(syn::PatType {
                 ref pat, ref ty, ..
             }) => {
+                // `_` and destructuring patterns are named by `name_unnamed_args`.
                 let name = match **pat {
-                    syn::Pat::Wild(..) => None,
                     syn::Pat::Ident(syn::PatIdent { ref ident, .. }) => Some(ident.to_string()),
-                    _ => {
-                        return Err(format!(
-                            "Parameter has an unsupported argument name: {:?}",
-                            pat
-                        ))
-                    }
+                    _ => None,
+                };
+                let is_mut_ref = match **ty {
+                    syn::Type::Reference(ref reference) => reference.mutability.is_some(),
+                    _ => false,
                 };
                 let ty = match Type::load(ty)? {
                     Some(x) => x,
@@ -381,12 +546,14 @@ impl SynFnArgHelpers for syn::FnArg {
                     name,
                     ty,
                     array_length: None,
+                    is_mut_ref,
                 }))
             }
             syn::FnArg::Receiver(ref receiver) => Ok(Some(FunctionArgument {
                 name: Some("self".to_string()),
                 ty: gen_self_type(receiver),
                 array_length: None,
+                is_mut_ref: receiver.reference.is_some() && receiver.mutability.is_some(),
             })),
         }
     }
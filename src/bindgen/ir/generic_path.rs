@@ -1,6 +1,7 @@
 use std::io::Write;
 use std::ops::Deref;
 
+use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::{DeclarationType, DeclarationTypeResolver};
 use crate::bindgen::ir::{Path, Type};
@@ -8,22 +9,43 @@ use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{Source, SourceWriter};
 
 #[derive(Default, Debug, Clone)]
-pub struct GenericParams(pub Vec<Path>);
+pub struct GenericParams {
+    params: Vec<Path>,
+    /// The type of each const parameter, or `None` for type parameters.
+    const_types: Vec<Option<Type>>,
+}
 
 impl GenericParams {
-    pub fn new(generics: &syn::Generics) -> Self {
-        GenericParams(
-            generics
-                .params
-                .iter()
-                .filter_map(|x| match *x {
-                    syn::GenericParam::Type(syn::TypeParam { ref ident, .. }) => {
-                        Some(Path::new(ident.to_string()))
-                    }
-                    _ => None,
-                })
-                .collect(),
-        )
+    pub fn load(generics: &syn::Generics) -> Result<Self, String> {
+        let mut result = GenericParams::default();
+        for param in &generics.params {
+            match *param {
+                syn::GenericParam::Type(syn::TypeParam { ref ident, .. }) => {
+                    result.params.push(Path::new(ident.to_string()));
+                    result.const_types.push(None);
+                }
+                syn::GenericParam::Const(syn::ConstParam {
+                    ref ident, ref ty, ..
+                }) => {
+                    let ty = Type::load(ty)?.ok_or_else(|| {
+                        format!("Const parameter {} has a zero sized type.", ident)
+                    })?;
+                    result.params.push(Path::new(ident.to_string()));
+                    result.const_types.push(Some(ty));
+                }
+                syn::GenericParam::Lifetime(_) => {}
+            }
+        }
+        Ok(result)
+    }
+
+    /// Type parameters with the given names.
+    pub fn with_type_params(params: Vec<Path>) -> Self {
+        let const_types = vec![None; params.len()];
+        GenericParams {
+            params,
+            const_types,
+        }
     }
 
     fn write_internal<F: Write>(
@@ -32,15 +54,25 @@ impl GenericParams {
         out: &mut SourceWriter<F>,
         with_default: bool,
     ) {
-        if !self.0.is_empty() && config.language == Language::Cxx {
+        if !self.params.is_empty() && config.language == Language::Cxx {
             out.write("template<");
-            for (i, item) in self.0.iter().enumerate() {
+            for (i, (item, const_type)) in self.params.iter().zip(&self.const_types).enumerate() {
                 if i != 0 {
                     out.write(", ");
                 }
-                write!(out, "typename {}", item);
-                if with_default {
-                    write!(out, " = void");
+                match *const_type {
+                    Some(ref ty) => {
+                        cdecl::write_field(out, ty, item.name(), config);
+                        if with_default {
+                            write!(out, " = 0");
+                        }
+                    }
+                    None => {
+                        write!(out, "typename {}", item);
+                        if with_default {
+                            write!(out, " = void");
+                        }
+                    }
                 }
             }
             out.write(">");
@@ -57,7 +89,7 @@ impl Deref for GenericParams {
     type Target = [Path];
 
     fn deref(&self) -> &[Path] {
-        &self.0
+        &self.params
     }
 }
 
@@ -119,12 +151,29 @@ impl GenericPath {
         &self.export_name
     }
 
+    /// Whether this is the value of a const generic argument, like `16` in
+    /// `Buf<16>`, rather than a type.
+    pub fn is_const_value(&self) -> bool {
+        self.name().starts_with(|c: char| c.is_ascii_digit())
+    }
+
     pub fn rename_for_config(&mut self, config: &Config, generic_params: &GenericParams) {
+        if self.is_const_value() {
+            return;
+        }
+        if !generic_params.contains(&self.path) {
+            if let Some(c_type) = config.type_mappings.get(self.path.name()) {
+                // The C type is written as is, without the generic arguments.
+                self.export_name = c_type.clone();
+                self.generics.clear();
+                return;
+            }
+        }
         for generic in &mut self.generics {
             generic.rename_for_config(config, generic_params);
         }
         if !generic_params.contains(&self.path) {
-            config.export.rename(&mut self.export_name);
+            config.export.rename_type(&mut self.export_name);
         }
     }
 
@@ -154,6 +203,14 @@ impl GenericPath {
             }) => args.iter().try_skip_map(|x| match *x {
                 syn::GenericArgument::Type(ref x) => Type::load(x),
                 syn::GenericArgument::Lifetime(_) => Ok(None),
+                // Const arguments are kept as paths named after their value.
+                syn::GenericArgument::Const(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(ref value),
+                    ..
+                })) => Ok(Some(Type::Path(GenericPath::new(
+                    Path::new(value.base10_digits()),
+                    vec![],
+                )))),
                 _ => Err(format!("can't handle generic argument {:?}", x)),
             })?,
             syn::PathArguments::Parenthesized(_) => {
@@ -8,7 +8,9 @@ use crate::bindgen::cdecl;
 use crate::bindgen::config::Config;
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
-use crate::bindgen::ir::{AnnotationSet, Cfg, Documentation, Item, ItemContainer, Path, Type};
+use crate::bindgen::ir::{
+    AnnotationSet, Cfg, Constant, Documentation, Item, ItemContainer, ItemMap, Path, Type,
+};
 use crate::bindgen::library::Library;
 use crate::bindgen::writer::{Source, SourceWriter};
 
@@ -16,6 +18,9 @@ use crate::bindgen::writer::{Source, SourceWriter};
 pub struct Static {
     pub path: Path,
     pub export_name: String,
+    /// The name of the Rust static, which isn't the name of `path` with
+    /// `#[export_name]`.
+    pub rust_name: String,
     pub ty: Type,
     pub mutable: bool,
     pub cfg: Option<Cfg>,
@@ -24,21 +29,27 @@ pub struct Static {
 }
 
 impl Static {
-    pub fn load(item: &syn::ItemStatic, mod_cfg: Option<&Cfg>) -> Result<Static, String> {
+    pub fn load(
+        path: Path,
+        item: &syn::ItemStatic,
+        mod_cfg: Option<&Cfg>,
+    ) -> Result<Static, String> {
         let ty = Type::load(&item.ty)?;
 
         if ty.is_none() {
             return Err("Cannot have a zero sized static definition.".to_owned());
         }
 
-        Ok(Static::new(
-            Path::new(item.ident.to_string()),
+        let mut global = Static::new(
+            path,
             ty.unwrap(),
             item.mutability.is_some(),
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
             AnnotationSet::load(&item.attrs)?,
             Documentation::load(&item.attrs),
-        ))
+        );
+        global.rust_name = item.ident.to_string();
+        Ok(global)
     }
 
     pub fn new(
@@ -51,6 +62,7 @@ impl Static {
     ) -> Self {
         let export_name = path.name().to_owned();
         Self {
+            rust_name: export_name.clone(),
             path,
             export_name,
             ty,
@@ -64,6 +76,10 @@ impl Static {
     pub fn simplify_standard_types(&mut self, config: &Config) {
         self.ty.simplify_standard_types(config);
     }
+
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        self.ty.resolve_array_lengths(constants);
+    }
 }
 
 impl Item for Static {
@@ -106,12 +122,15 @@ impl Item for Static {
 
 impl Source for Static {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.annotations.bool("maybe-unused").unwrap_or(false) {
+            write!(out, "{} ", config.maybe_unused_attribute());
+        }
         out.write("extern ");
-        if let Type::Ptr { is_const: true, .. } = self.ty {
-        } else if !self.mutable {
-            out.write("const ");
+        if self.mutable {
+            cdecl::write_field(out, &self.ty, &self.export_name, config);
+        } else {
+            cdecl::write_const_field(out, &self.ty, &self.export_name, config);
         }
-        cdecl::write_field(out, &self.ty, &self.export_name, config);
         out.write(";");
     }
 }
@@ -87,6 +87,34 @@ impl<T: Item> Default for ItemMap<T> {
 }
 
 impl<T: Item + Clone> ItemMap<T> {
+    pub(crate) fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Returns the paths of the items, in insertion order.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.data.keys()
+    }
+
+    /// Returns the items, including every `cfg`-dependent variant of them.
+    #[allow(unused)]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.data.values().flat_map(|container| match *container {
+            ItemValue::Cfg(ref items) => items.iter(),
+            ItemValue::Single(ref item) => std::slice::from_ref(item).iter(),
+        })
+    }
+
+    pub fn contains(&self, path: &Path) -> bool {
+        self.data.contains_key(path)
+    }
+
+    /// Removes the items with the given path, including every `cfg`-dependent
+    /// variant of them.
+    pub fn remove(&mut self, path: &Path) {
+        self.data.shift_remove(path);
+    }
+
     pub fn rebuild(&mut self) {
         let old = mem::take(self);
         old.for_all_items(|x| {
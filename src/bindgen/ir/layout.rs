@@ -0,0 +1,199 @@
+/* This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
+
+//! Estimates the size and alignment of types from their definitions, for
+//! `layout_assertions`. This assumes the usual C ABIs, and gives up on any type
+//! whose layout isn't the same across them.
+
+use crate::bindgen::ir::{
+    ArrayLength, Constant, ItemContainer, ItemMap, PrimitiveType, ReprAlign, ReprType, Struct, Type,
+};
+use crate::bindgen::library::Library;
+
+/// The size and alignment of a type, in bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub size: u64,
+    pub align: u64,
+}
+
+impl Layout {
+    fn scalar(size: u64) -> Self {
+        Layout { size, align: size }
+    }
+}
+
+/// Types nested deeper than this are given up on.
+const MAX_DEPTH: usize = 64;
+
+pub struct LayoutEstimator<'a> {
+    library: &'a Library,
+    constants: &'a ItemMap<Constant>,
+    /// The size of pointers, or `None` if it isn't known.
+    pointer_size: Option<u64>,
+}
+
+impl<'a> LayoutEstimator<'a> {
+    pub fn new(
+        library: &'a Library,
+        constants: &'a ItemMap<Constant>,
+        pointer_size: Option<u64>,
+    ) -> Self {
+        LayoutEstimator {
+            library,
+            constants,
+            pointer_size,
+        }
+    }
+
+    /// The layout of `st`, if it's the same on every target with the pointer
+    /// size of this estimator.
+    pub fn struct_layout(&self, st: &Struct) -> Option<Layout> {
+        self.struct_layout_at_depth(st, 0)
+    }
+
+    fn struct_layout_at_depth(&self, st: &Struct, depth: usize) -> Option<Layout> {
+        // Empty structs are a GNU extension in C with a different size than
+        // in C++, and bit-fields are laid out differently by MSVC.
+        if st.is_generic() || st.fields.is_empty() || !st.bitfields.is_empty() {
+            return None;
+        }
+        if st.is_transparent {
+            return self.type_layout(&st.fields[0].1, depth + 1);
+        }
+
+        let packed = st.alignment == Some(ReprAlign::Packed);
+        let mut size = 0;
+        let mut align = 1;
+        for (i, field) in st.fields.iter().enumerate() {
+            let field_layout = if st.has_flexible_array && i == st.fields.len() - 1 {
+                match field.1 {
+                    Type::Array(ref ty, _) => Layout {
+                        size: 0,
+                        ..self.type_layout(ty, depth + 1)?
+                    },
+                    _ => return None,
+                }
+            } else {
+                self.type_layout(&field.1, depth + 1)?
+            };
+            let field_align = if packed { 1 } else { field_layout.align };
+            size = round_up(size, field_align) + field_layout.size;
+            align = align.max(field_align);
+        }
+        size += st.padding.unwrap_or(0);
+        if let Some(ReprAlign::Align(n)) = st.alignment {
+            align = align.max(n);
+        }
+        Some(Layout {
+            size: round_up(size, align),
+            align,
+        })
+    }
+
+    fn type_layout(&self, ty: &Type, depth: usize) -> Option<Layout> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        match *ty {
+            Type::Primitive(ref prim) => self.primitive_layout(prim),
+            Type::Ptr { .. } | Type::FuncPtr(..) => self.pointer_size.map(Layout::scalar),
+            Type::Array(ref ty, ref len) => {
+                let len = match *len {
+                    ArrayLength::Value(ref len) => len.parse::<u64>().ok()?,
+                    ArrayLength::Name(ref name) => {
+                        Constant::integer_value_of(name, self.constants)?
+                            .parse::<u64>()
+                            .ok()?
+                    }
+                };
+                let element = self.type_layout(ty, depth + 1)?;
+                Some(Layout {
+                    size: element.size.checked_mul(len)?,
+                    align: element.align,
+                })
+            }
+            Type::Path(ref generic) => {
+                if !generic.generics().is_empty() {
+                    return None;
+                }
+                let items = self.library.get_items(generic.path())?;
+                // Items which depend on cfgs may have several layouts.
+                match items[..] {
+                    [ref item] => self.item_layout(item, depth),
+                    _ => None,
+                }
+            }
+        }
+    }
+
+    fn item_layout(&self, item: &ItemContainer, depth: usize) -> Option<Layout> {
+        match *item {
+            ItemContainer::Struct(ref st) => self.struct_layout_at_depth(st, depth + 1),
+            ItemContainer::Union(ref union) => {
+                if union.is_generic() || union.fields.is_empty() {
+                    return None;
+                }
+                let mut size = 0;
+                let mut align = 1;
+                for field in &union.fields {
+                    let field_layout = self.type_layout(&field.1, depth + 1)?;
+                    size = size.max(field_layout.size);
+                    align = align.max(field_layout.align);
+                }
+                match union.alignment {
+                    Some(ReprAlign::Packed) => align = 1,
+                    Some(ReprAlign::Align(n)) => align = align.max(n),
+                    None => {}
+                }
+                Some(Layout {
+                    size: round_up(size, align),
+                    align,
+                })
+            }
+            // The size of C enums without an explicit repr, and the layout of
+            // tagged enums, aren't estimated.
+            ItemContainer::Enum(ref en) if en.tag.is_none() => match en.repr.ty? {
+                ReprType::U8 | ReprType::I8 => Some(Layout::scalar(1)),
+                ReprType::U16 | ReprType::I16 => Some(Layout::scalar(2)),
+                ReprType::U32 | ReprType::I32 => Some(Layout::scalar(4)),
+                ReprType::U64 | ReprType::I64 => self.eight_byte_scalar(),
+                ReprType::USize | ReprType::ISize => self.pointer_size.map(Layout::scalar),
+            },
+            ItemContainer::Typedef(ref typedef) if typedef.generic_params.is_empty() => {
+                self.type_layout(&typedef.aliased, depth + 1)
+            }
+            _ => None,
+        }
+    }
+
+    fn primitive_layout(&self, prim: &PrimitiveType) -> Option<Layout> {
+        use self::PrimitiveType::*;
+        match *prim {
+            Bool | Char | SChar | UChar | UInt8 | Int8 => Some(Layout::scalar(1)),
+            Short | UShort | UInt16 | Int16 => Some(Layout::scalar(2)),
+            Char32 | Int | UInt | UInt32 | Int32 | Float => Some(Layout::scalar(4)),
+            LongLong | ULongLong | UInt64 | Int64 | Double => self.eight_byte_scalar(),
+            USize | ISize | SizeT | SSizeT | PtrDiffT => self.pointer_size.map(Layout::scalar),
+            // `long` is 4 bytes on 64-bit Windows, but 8 elsewhere.
+            Long | ULong | Void | VaList => None,
+        }
+    }
+
+    /// 8-byte integers and doubles are only 4-byte aligned in structs on some
+    /// 32-bit targets, like x86.
+    fn eight_byte_scalar(&self) -> Option<Layout> {
+        match self.pointer_size {
+            Some(8) => Some(Layout::scalar(8)),
+            _ => None,
+        }
+    }
+}
+
+fn round_up(size: u64, align: u64) -> u64 {
+    match size % align {
+        0 => size,
+        rem => size + (align - rem),
+    }
+}
@@ -11,6 +11,7 @@ pub mod function;
 pub mod generic_path;
 pub mod global;
 pub mod item;
+pub mod layout;
 pub mod opaque;
 pub mod path;
 pub mod repr;
@@ -28,6 +29,7 @@ pub use self::function::*;
 pub use self::generic_path::*;
 pub use self::global::*;
 pub use self::item::*;
+pub use self::layout::*;
 pub use self::opaque::*;
 pub use self::path::*;
 pub use self::repr::*;
@@ -9,7 +9,7 @@ use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
     AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    ToCondition, Type,
+    Repr, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -24,6 +24,9 @@ pub struct OpaqueItem {
     pub cfg: Option<Cfg>,
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
+    /// Whether this is a Rust type without any `#[repr]`, which has no layout
+    /// guarantees and so can't be passed by value.
+    pub is_default_repr: bool,
 }
 
 impl OpaqueItem {
@@ -33,13 +36,15 @@ impl OpaqueItem {
         attrs: &[syn::Attribute],
         mod_cfg: Option<&Cfg>,
     ) -> Result<OpaqueItem, String> {
-        Ok(Self::new(
+        let mut item = Self::new(
             path,
-            GenericParams::new(generics),
+            GenericParams::load(generics)?,
             Cfg::append(mod_cfg, Cfg::load(attrs)),
             AnnotationSet::load(attrs).unwrap_or_else(|_| AnnotationSet::new()),
             Documentation::load(attrs),
-        ))
+        );
+        item.is_default_repr = Repr::load(attrs) == Ok(Repr::default());
+        Ok(item)
     }
 
     pub fn new(
@@ -57,6 +62,7 @@ impl OpaqueItem {
             cfg,
             annotations,
             documentation,
+            is_default_repr: false,
         }
     }
 }
@@ -91,7 +97,7 @@ impl Item for OpaqueItem {
     }
 
     fn rename_for_config(&mut self, config: &Config) {
-        config.export.rename(&mut self.export_name);
+        config.export.rename_type(&mut self.export_name);
     }
 
     fn add_dependencies(&self, _: &Library, _: &mut Dependencies) {}
@@ -125,13 +131,14 @@ impl Item for OpaqueItem {
             &library.get_config().export.mangle,
         );
 
-        let monomorph = OpaqueItem::new(
+        let mut monomorph = OpaqueItem::new(
             mangled_path,
             GenericParams::default(),
             self.cfg.clone(),
             self.annotations.clone(),
             self.documentation.clone(),
         );
+        monomorph.is_default_repr = self.is_default_repr;
 
         out.insert_opaque(self, monomorph, generic_values.to_owned());
     }
@@ -146,7 +153,10 @@ impl Source for OpaqueItem {
 
         self.generic_params.write_with_default(config, out);
 
-        if config.style.generate_typedef() && config.language == Language::C {
+        // The typedef also declares the struct tag, so a separate forward
+        // declaration isn't needed (and references using `struct` still work).
+        let typedef = config.style.generate_typedef() || config.structure.opaque_typedef;
+        if typedef && config.language == Language::C {
             write!(
                 out,
                 "typedef struct {} {};",
@@ -96,11 +96,11 @@ impl Repr {
                 ("i64", None) => ReprType::I64,
                 ("isize", None) => ReprType::ISize,
                 ("C", None) => {
-                    repr.style = ReprStyle::C;
+                    repr.set_style(ReprStyle::C);
                     continue;
                 }
                 ("transparent", None) => {
-                    repr.style = ReprStyle::Transparent;
+                    repr.set_style(ReprStyle::Transparent);
                     continue;
                 }
                 ("packed", args) => {
@@ -111,15 +111,7 @@ impl Repr {
                             "Not-yet-implemented #[repr(packed(...))] encountered.".to_string()
                         );
                     }
-                    let align = ReprAlign::Packed;
-                    // Only permit a single alignment-setting repr.
-                    if let Some(old_align) = repr.align {
-                        return Err(format!(
-                            "Conflicting #[repr(align(...))] type hints {:?} and {:?}.",
-                            old_align, align
-                        ));
-                    }
-                    repr.align = Some(align);
+                    repr.set_align(ReprAlign::Packed);
                     continue;
                 }
                 ("align", Some(args)) => {
@@ -141,15 +133,7 @@ impl Repr {
                     if !align.is_power_of_two() || align == 0 {
                         return Err(format!("Invalid alignment to #[repr(align({}))].", align));
                     }
-                    // Only permit a single alignment-setting repr.
-                    if let Some(old_align) = repr.align {
-                        return Err(format!(
-                            "Conflicting #[repr(align(...))] type hints {:?} and {:?}.",
-                            old_align,
-                            ReprAlign::Align(align)
-                        ));
-                    }
-                    repr.align = Some(ReprAlign::Align(align));
+                    repr.set_align(ReprAlign::Align(align));
                     continue;
                 }
                 (path, args) => match args {
@@ -163,14 +147,118 @@ impl Repr {
                     }
                 },
             };
-            if let Some(old_ty) = repr.ty {
-                return Err(format!(
-                    "Conflicting #[repr(...)] type hints {:?} and {:?}.",
-                    old_ty, new_ty
-                ));
+            match repr.ty {
+                Some(old_ty) if old_ty != new_ty => {
+                    return Err(format!(
+                        "Conflicting #[repr(...)] type hints {:?} and {:?}.",
+                        old_ty, new_ty
+                    ));
+                }
+                _ => repr.ty = Some(new_ty),
             }
-            repr.ty = Some(new_ty);
         }
         Ok(repr)
     }
+
+    // Repeating a hint is harmless, but of conflicting styles, the first wins.
+    fn set_style(&mut self, style: ReprStyle) {
+        if self.style == ReprStyle::Rust {
+            self.style = style;
+        } else if self.style != style {
+            warn!(
+                "Conflicting #[repr(...)] styles {:?} and {:?}; keeping {:?}.",
+                self.style, style, self.style
+            );
+        }
+    }
+
+    // Likewise, of conflicting alignment-setting reprs, the first wins.
+    fn set_align(&mut self, align: ReprAlign) {
+        match self.align {
+            None => self.align = Some(align),
+            Some(old_align) if old_align != align => warn!(
+                "Conflicting #[repr(align(...))] type hints {:?} and {:?}; keeping {:?}.",
+                old_align, align, old_align
+            ),
+            Some(_) => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(item: &str) -> Result<Repr, String> {
+        let item: syn::ItemEnum = syn::parse_str(item).unwrap();
+        Repr::load(&item.attrs)
+    }
+
+    #[test]
+    fn combined_repr() {
+        assert_eq!(
+            load("#[repr(C, u8)] enum E { A }"),
+            Ok(Repr {
+                style: ReprStyle::C,
+                ty: Some(ReprType::U8),
+                align: None,
+            })
+        );
+        assert_eq!(
+            load("#[repr(i32, C)] enum E { A }"),
+            Ok(Repr {
+                style: ReprStyle::C,
+                ty: Some(ReprType::I32),
+                align: None,
+            })
+        );
+    }
+
+    #[test]
+    fn split_combined_repr() {
+        assert_eq!(
+            load("#[repr(C)] #[repr(u16)] enum E { A }"),
+            load("#[repr(C, u16)] enum E { A }")
+        );
+    }
+
+    #[test]
+    fn conflicting_repr_types() {
+        assert!(load("#[repr(C, u8, u16)] enum E { A }").is_err());
+        assert!(load("#[repr(C, u8)] #[repr(u16)] enum E { A }").is_err());
+    }
+
+    #[test]
+    fn conflicting_repr_styles_and_alignments() {
+        let style = |item| load(item).unwrap().style;
+        let align = |item| load(item).unwrap().align;
+        assert_eq!(
+            style("#[repr(C)] #[repr(transparent)] enum E { A }"),
+            ReprStyle::C
+        );
+        assert_eq!(
+            style("#[repr(transparent, C)] enum E { A }"),
+            ReprStyle::Transparent
+        );
+        assert_eq!(
+            align("#[repr(C, packed, align(8))] enum E { A }"),
+            Some(ReprAlign::Packed)
+        );
+        assert_eq!(
+            align("#[repr(C, align(4))] #[repr(align(8))] enum E { A }"),
+            Some(ReprAlign::Align(4))
+        );
+    }
+
+    #[test]
+    fn redundant_repr() {
+        assert_eq!(
+            load("#[repr(C, u8)] #[repr(C, u8)] enum E { A }"),
+            load("#[repr(C, u8)] enum E { A }")
+        );
+        assert_eq!(
+            load("#[repr(C, align(8))] #[repr(align(8))] enum E { A }"),
+            load("#[repr(C, align(8))] enum E { A }")
+        );
+    }
 }
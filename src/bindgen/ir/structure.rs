@@ -4,19 +4,20 @@
 
 use std::io::Write;
 
-use crate::bindgen::config::{Config, Language, LayoutConfig};
+use crate::bindgen::cdecl;
+use crate::bindgen::config::{Config, Language, TargetPointerWidth};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, GenericParams, Item,
-    ItemContainer, Path, Repr, ReprAlign, ReprStyle, ToCondition, Type, Typedef,
+    AnnotationSet, ArrayLength, Cfg, ConditionWrite, Constant, Documentation, GenericParams, Item,
+    ItemContainer, ItemMap, Layout, Path, PrimitiveType, Repr, ReprAlign, ReprStyle, ToCondition,
+    Type, Typedef,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::rename::{IdentifierType, RenameRule};
 use crate::bindgen::reserved;
-use crate::bindgen::utilities::IterHelpers;
 use crate::bindgen::writer::{ListType, Source, SourceWriter};
 
 #[derive(Debug, Clone)]
@@ -24,6 +25,8 @@ pub struct Struct {
     pub path: Path,
     pub export_name: String,
     pub generic_params: GenericParams,
+    /// The fields in declaration order, which the layout depends on, so they
+    /// must never be reordered.
     pub fields: Vec<(String, Type, Documentation)>,
     /// Whether there's a tag field on the body of this struct. When this is
     /// true, is_enum_variant_body is also guaranteed to be true.
@@ -37,6 +40,25 @@ pub struct Struct {
     pub annotations: AnnotationSet,
     pub documentation: Documentation,
     pub associated_constants: Vec<Constant>,
+    /// The widths of the fields that are bit-fields, from their
+    /// `cbindgen:bitfield` annotation, or empty if there are none.
+    pub bitfields: Vec<Option<u32>>,
+    /// Whether each field is `volatile`, from its `cbindgen:volatile`
+    /// annotation, or empty if none are.
+    pub volatile_fields: Vec<bool>,
+    /// Whether each field is written as the body of its union type, as an
+    /// anonymous union member, from its `cbindgen:anonymous-union` annotation,
+    /// or empty if none are.
+    pub anonymous_unions: Vec<bool>,
+    /// Whether the last field is a flexible array member, written as
+    /// `T name[]`.
+    pub has_flexible_array: bool,
+    /// The size of the `uint8_t _pad[N]` field written after the others, from
+    /// the `cbindgen:padding` annotation.
+    pub padding: Option<u64>,
+    /// The estimated layouts of this struct, with the pointer width in bits
+    /// they are for if they depend on it, which `layout_assertions` checks.
+    pub layouts: Vec<(Option<u32>, Layout)>,
 }
 
 impl Struct {
@@ -50,14 +72,22 @@ impl Struct {
     }
 
     pub fn load(
-        layout_config: &LayoutConfig,
+        config: &Config,
         item: &syn::ItemStruct,
         mod_cfg: Option<&Cfg>,
     ) -> Result<Self, String> {
+        let annotations = AnnotationSet::load(&item.attrs)?;
         let repr = Repr::load(&item.attrs)?;
         let is_transparent = match repr.style {
             ReprStyle::C => false,
             ReprStyle::Transparent => true,
+            ReprStyle::Rust if annotations.bool("assume-repr-c").unwrap_or(false) => {
+                warn!(
+                    "Treating {} as #[repr(C)] because of its `assume-repr-c` annotation.",
+                    item.ident
+                );
+                false
+            }
             _ => {
                 return Err("Struct is not marked #[repr(C)] or #[repr(transparent)].".to_owned());
             }
@@ -67,16 +97,27 @@ impl Struct {
 
         // Ensure we can safely represent the struct given the configuration.
         if let Some(align) = repr.align {
-            layout_config.ensure_safe_to_represent(&align)?;
+            config.layout.ensure_safe_to_represent(&align)?;
         }
 
-        let (fields, tuple_struct) = match item.fields {
+        let mut bitfields = Vec::new();
+        let mut volatile_fields = Vec::new();
+        let mut anonymous_unions = Vec::new();
+        let mut flexible_arrays = Vec::new();
+        let (mut fields, tuple_struct) = match item.fields {
             syn::Fields::Unit => (Vec::new(), false),
             syn::Fields::Named(ref fields) => {
-                let out = fields
-                    .named
-                    .iter()
-                    .try_skip_map(|x| x.as_ident_and_type(&path))?;
+                let mut out = Vec::new();
+                for field in fields.named.iter() {
+                    if let Some(x) = field.as_ident_and_type(&path)? {
+                        bitfields.push(load_bitfield(field, &x.0, &x.1)?);
+                        volatile_fields.push(load_volatile(field, &x.0, &x.1)?);
+                        anonymous_unions.push(load_anonymous_union(field, &x.0, &x.1)?);
+                        flexible_arrays
+                            .push(AnnotationSet::load(&field.attrs)?.bool("flexible-array"));
+                        out.push(x);
+                    }
+                }
                 (out, false)
             }
             syn::Fields::Unnamed(ref fields) => {
@@ -85,7 +126,13 @@ impl Struct {
                 for field in fields.unnamed.iter() {
                     if let Some(mut x) = Type::load(&field.ty)? {
                         x.replace_self_with(&path);
-                        out.push((format!("{}", current), x, Documentation::load(&field.attrs)));
+                        let name = format!("{}", current);
+                        bitfields.push(load_bitfield(field, &name, &x)?);
+                        volatile_fields.push(load_volatile(field, &name, &x)?);
+                        anonymous_unions.push(load_anonymous_union(field, &name, &x)?);
+                        flexible_arrays
+                            .push(AnnotationSet::load(&field.attrs)?.bool("flexible-array"));
+                        out.push((name, x, Documentation::load(&field.attrs)));
                         current += 1;
                     }
                 }
@@ -93,12 +140,36 @@ impl Struct {
             }
         };
 
+        let has_flexible_array = load_flexible_array(&mut fields, &flexible_arrays, config)?;
+        let padding = load_padding(&annotations)?;
+        if padding.is_some() && (is_transparent || has_flexible_array) {
+            return Err(
+                "Padding can't be added to transparent structs or after a flexible array member."
+                    .to_owned(),
+            );
+        }
+
+        if is_transparent && volatile_fields.iter().any(|x| *x) {
+            return Err("Fields of transparent structs can't be volatile.".to_owned());
+        }
+        if is_transparent && anonymous_unions.iter().any(|x| *x) {
+            return Err("Fields of transparent structs can't be anonymous unions.".to_owned());
+        }
+        for (i, field) in fields.iter().enumerate() {
+            if anonymous_unions[i] && (bitfields[i].is_some() || volatile_fields[i]) {
+                return Err(format!(
+                    "Anonymous union {} can't be a bit-field or volatile.",
+                    field.0
+                ));
+            }
+        }
+
         let is_tagged = false;
         let is_enum_variant_body = false;
 
-        Ok(Struct::new(
+        let mut result = Struct::new(
             path,
-            GenericParams::new(&item.generics),
+            GenericParams::load(&item.generics)?,
             fields,
             is_tagged,
             is_enum_variant_body,
@@ -106,9 +177,36 @@ impl Struct {
             is_transparent,
             tuple_struct,
             Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
-            AnnotationSet::load(&item.attrs)?,
+            annotations,
             Documentation::load(&item.attrs),
-        ))
+        );
+        if bitfields.iter().any(Option::is_some) {
+            result.bitfields = bitfields;
+        }
+        if volatile_fields.iter().any(|x| *x) {
+            result.volatile_fields = volatile_fields;
+        }
+        if anonymous_unions.iter().any(|x| *x) {
+            result.anonymous_unions = anonymous_unions;
+        }
+        result.has_flexible_array = has_flexible_array;
+        result.padding = padding;
+        Ok(result)
+    }
+
+    /// The bit width of the field at `index`, if it's a bit-field.
+    fn bitfield(&self, index: usize) -> Option<u32> {
+        self.bitfields.get(index).cloned().flatten()
+    }
+
+    /// Whether the field at `index` is `volatile`.
+    fn is_volatile(&self, index: usize) -> bool {
+        self.volatile_fields.get(index).cloned().unwrap_or(false)
+    }
+
+    /// Whether the field at `index` is written as an anonymous union.
+    fn is_anonymous_union(&self, index: usize) -> bool {
+        self.anonymous_unions.get(index).cloned().unwrap_or(false)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -140,6 +238,43 @@ impl Struct {
             annotations,
             documentation,
             associated_constants: vec![],
+            bitfields: vec![],
+            volatile_fields: vec![],
+            anonymous_unions: vec![],
+            has_flexible_array: false,
+            padding: None,
+            layouts: vec![],
+        }
+    }
+
+    /// Whether this is a plain struct wrapping a single primitive, like
+    /// `struct Meters(f64);`.
+    pub fn is_primitive_newtype(&self) -> bool {
+        match self.fields[..] {
+            [(_, Type::Primitive(..), _)] => {
+                !self.is_generic()
+                    && !self.is_tagged
+                    && !self.is_enum_variant_body
+                    && self.alignment.is_none()
+                    && self.bitfields.is_empty()
+                    && self.volatile_fields.is_empty()
+                    && self.anonymous_unions.is_empty()
+                    && self.padding.is_none()
+            }
+            _ => false,
+        }
+    }
+
+    /// The typedef that a transparent struct is written as.
+    pub fn as_typedef(&self) -> Typedef {
+        Typedef {
+            path: self.path.clone(),
+            export_name: self.export_name.to_owned(),
+            generic_params: self.generic_params.clone(),
+            aliased: self.fields[0].1.clone(),
+            cfg: self.cfg.clone(),
+            annotations: self.annotations.clone(),
+            documentation: self.documentation.clone(),
         }
     }
 
@@ -149,6 +284,12 @@ impl Struct {
         }
     }
 
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        for &mut (_, ref mut ty, _) in &mut self.fields {
+            ty.resolve_array_lengths(constants);
+        }
+    }
+
     pub fn is_generic(&self) -> bool {
         self.generic_params.len() > 0
     }
@@ -178,7 +319,7 @@ impl Struct {
         config: &Config,
     ) -> Self {
         let mangled_path = mangle::mangle_path(&self.path, generic_values, &config.export.mangle);
-        Struct::new(
+        let mut specialized = Struct::new(
             mangled_path,
             GenericParams::default(),
             self.fields
@@ -193,7 +334,13 @@ impl Struct {
             self.cfg.clone(),
             self.annotations.clone(),
             self.documentation.clone(),
-        )
+        );
+        specialized.bitfields = self.bitfields.clone();
+        specialized.volatile_fields = self.volatile_fields.clone();
+        specialized.anonymous_unions = self.anonymous_unions.clone();
+        specialized.has_flexible_array = self.has_flexible_array;
+        specialized.padding = self.padding;
+        specialized
     }
 
     fn emit_bitflags_binop<F: Write>(
@@ -276,7 +423,7 @@ impl Item for Struct {
     fn rename_for_config(&mut self, config: &Config) {
         // Rename the name of the struct
         if !self.is_tagged || config.language == Language::C {
-            config.export.rename(&mut self.export_name);
+            config.export.rename_type(&mut self.export_name);
         }
 
         // Rename the types used in fields
@@ -393,16 +540,7 @@ impl Item for Struct {
 impl Source for Struct {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
         if self.is_transparent {
-            let typedef = Typedef {
-                path: self.path.clone(),
-                export_name: self.export_name.to_owned(),
-                generic_params: self.generic_params.clone(),
-                aliased: self.fields[0].1.clone(),
-                cfg: self.cfg.clone(),
-                annotations: self.annotations.clone(),
-                documentation: self.documentation.clone(),
-            };
-            typedef.write(config, out);
+            self.as_typedef().write(config, out);
             for constant in &self.associated_constants {
                 out.new_line();
                 constant.write(config, out, Some(self));
@@ -426,7 +564,13 @@ impl Source for Struct {
         //   typedef struct {
         // C with Both as style:
         //   typedef struct Name {
-        if config.language == Language::C && config.style.generate_typedef() {
+        // C with a forward declaration, which has the typedef:
+        //   struct Name {
+        let is_forward_declared = out.bindings().is_forward_declared(&self.path);
+        let is_typedef = config.language == Language::C
+            && config.style.generate_typedef()
+            && !is_forward_declared;
+        if is_typedef {
             out.write("typedef ");
         }
 
@@ -453,7 +597,7 @@ impl Source for Struct {
             }
         }
 
-        if config.language == Language::Cxx || config.style.generate_tag() {
+        if config.language == Language::Cxx || config.style.generate_tag() || is_forward_declared {
             write!(out, " {}", self.export_name());
         }
 
@@ -465,7 +609,23 @@ impl Source for Struct {
             out.new_line();
         }
 
-        if config.documentation {
+        if !self.bitfields.is_empty()
+            || !self.volatile_fields.is_empty()
+            || !self.anonymous_unions.is_empty()
+        {
+            let fields: Vec<_> = self
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(i, field)| QualifiedField {
+                    field,
+                    width: self.bitfield(i),
+                    is_volatile: self.is_volatile(i),
+                    is_anonymous_union: self.is_anonymous_union(i),
+                })
+                .collect();
+            out.write_vertical_source_list(&fields[..], ListType::Cap(";"));
+        } else if config.documentation {
             out.write_vertical_source_list(&self.fields, ListType::Cap(";"));
         } else {
             let vec: Vec<_> = self
@@ -476,10 +636,20 @@ impl Source for Struct {
             out.write_vertical_source_list(&vec[..], ListType::Cap(";"));
         }
 
+        if let Some(padding) = self.padding {
+            if !self.fields.is_empty() {
+                out.new_line();
+            }
+            write!(out, "uint8_t _pad[{}];", padding);
+        }
+
         if config.language == Language::Cxx {
             let mut wrote_start_newline = false;
 
-            if config.structure.derive_constructor(&self.annotations) && !self.fields.is_empty() {
+            if config.structure.derive_constructor(&self.annotations)
+                && !self.fields.is_empty()
+                && !self.has_flexible_array
+            {
                 if !wrote_start_newline {
                     wrote_start_newline = true;
                     out.new_line();
@@ -679,7 +849,7 @@ impl Source for Struct {
             }
         }
 
-        if config.language == Language::C && config.style.generate_typedef() {
+        if is_typedef {
             out.close_brace(false);
             write!(out, " {};", self.export_name());
         } else {
@@ -691,10 +861,270 @@ impl Source for Struct {
             constant.write(config, out, Some(self));
         }
 
+        if config.layout_assertions {
+            self.write_layout_assertions(config, out);
+        }
+
         condition.write_after(config, out);
     }
 }
 
+impl Struct {
+    /// Writes static assertions that the compiler lays this struct out as
+    /// expected.
+    fn write_layout_assertions<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if self.layouts.is_empty() {
+            return;
+        }
+        let (static_assert, alignof) = match config.language {
+            Language::Cxx => ("static_assert", "alignof"),
+            Language::C => ("_Static_assert", "_Alignof"),
+        };
+        let name = if config.language == Language::C && !config.style.generate_typedef() {
+            format!("struct {}", self.export_name())
+        } else {
+            self.export_name().to_owned()
+        };
+
+        out.new_line();
+        if config.language == Language::C && config.cpp_compat {
+            out.new_line();
+            out.write("#ifndef __cplusplus");
+        }
+        for &(bits, layout) in &self.layouts {
+            out.new_line();
+            if let Some(bits) = bits {
+                let uintptr_max = TargetPointerWidth::uintptr_max(&bits.to_string()).unwrap();
+                write!(out, "#if (UINTPTR_MAX == {})", uintptr_max);
+                out.new_line();
+            }
+            write!(
+                out,
+                "{}(sizeof({}) == {}, \"unexpected size of {}\");",
+                static_assert,
+                name,
+                layout.size,
+                self.export_name()
+            );
+            out.new_line();
+            write!(
+                out,
+                "{}({}({}) == {}, \"unexpected alignment of {}\");",
+                static_assert,
+                alignof,
+                name,
+                layout.align,
+                self.export_name()
+            );
+            if bits.is_some() {
+                out.new_line();
+                out.write("#endif");
+            }
+        }
+        if config.language == Language::C && config.cpp_compat {
+            out.new_line();
+            out.write("#endif // __cplusplus");
+        }
+    }
+}
+
+/// Turns the last field into a flexible array member if it's marked with
+/// `cbindgen:flexible-array`, or if it's a zero-length array which isn't marked
+/// otherwise and `struct.keep_zero_length_arrays` isn't set. Marked fields must
+/// be zero-length arrays and come last. In C, the length of the array is
+/// removed. C++ has no flexible array members, so the zero-length array is
+/// kept there.
+fn load_flexible_array(
+    fields: &mut [(String, Type, Documentation)],
+    marked: &[Option<bool>],
+    config: &Config,
+) -> Result<bool, String> {
+    let last = fields.len().saturating_sub(1);
+    for (i, field) in fields.iter().enumerate() {
+        if marked[i] != Some(true) {
+            continue;
+        }
+        if i != last {
+            return Err(format!(
+                "Flexible array member {} must be the last field.",
+                field.0
+            ));
+        }
+        if last == 0 {
+            return Err(format!(
+                "Flexible array member {} can't be the only field.",
+                field.0
+            ));
+        }
+        match field.1 {
+            Type::Array(_, ArrayLength::Value(ref len)) if len == "0" => {}
+            _ => {
+                return Err(format!(
+                    "Flexible array member {} must be a zero-length array.",
+                    field.0
+                ))
+            }
+        }
+    }
+
+    let field = match fields.last_mut() {
+        Some(field) if last > 0 => field,
+        _ => return Ok(false),
+    };
+    let is_flexible = match (&field.1, marked[last]) {
+        (_, Some(marked)) => marked,
+        (Type::Array(_, ArrayLength::Value(ref len)), None) => {
+            !config.structure.keep_zero_length_arrays && len == "0"
+        }
+        _ => false,
+    };
+    if let Type::Array(_, ref mut len) = field.1 {
+        if is_flexible && config.language != Language::Cxx {
+            *len = ArrayLength::Value(String::new());
+        }
+    }
+    Ok(is_flexible)
+}
+
+/// Loads the size of the trailing padding from the `cbindgen:padding`
+/// annotation, if any.
+fn load_padding(annotations: &AnnotationSet) -> Result<Option<u64>, String> {
+    let padding = match annotations.atom("padding") {
+        Some(padding) => padding.unwrap_or_default(),
+        None => return Ok(None),
+    };
+    match padding.parse::<u64>() {
+        Ok(padding) if padding > 0 => Ok(Some(padding)),
+        _ => Err(format!(
+            "Padding must be a positive integer, not {:?}.",
+            padding
+        )),
+    }
+}
+
+/// Loads the width of a field from its `cbindgen:bitfield` annotation, if any.
+fn load_bitfield(field: &syn::Field, name: &str, ty: &Type) -> Result<Option<u32>, String> {
+    let annotations = AnnotationSet::load(&field.attrs)?;
+    let width = match annotations.atom("bitfield") {
+        Some(Some(width)) => width,
+        Some(None) => return Err(format!("Bit-field {} is missing its width.", name)),
+        None => return Ok(None),
+    };
+    let bits = match *ty {
+        Type::Primitive(PrimitiveType::UInt8) | Type::Primitive(PrimitiveType::UChar) => 8,
+        Type::Primitive(PrimitiveType::UInt16) | Type::Primitive(PrimitiveType::UShort) => 16,
+        Type::Primitive(PrimitiveType::UInt32) | Type::Primitive(PrimitiveType::UInt) => 32,
+        Type::Primitive(PrimitiveType::UInt64) | Type::Primitive(PrimitiveType::ULongLong) => 64,
+        _ => {
+            return Err(format!(
+                "Bit-field {} must have a fixed-size unsigned integer type.",
+                name
+            ))
+        }
+    };
+    match width.parse::<u32>() {
+        Ok(width) if width > 0 && width <= bits => Ok(Some(width)),
+        _ => Err(format!(
+            "Bit-field {} must have a width between 1 and {}, not {}.",
+            name, bits, width
+        )),
+    }
+}
+
+/// Whether a field is `volatile`, from its `cbindgen:volatile` annotation.
+fn load_volatile(field: &syn::Field, name: &str, ty: &Type) -> Result<bool, String> {
+    if AnnotationSet::load(&field.attrs)?.bool("volatile") != Some(true) {
+        return Ok(false);
+    }
+    let mut innermost = ty;
+    loop {
+        match *innermost {
+            Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => innermost = ty,
+            Type::FuncPtr(..) => {
+                return Err(format!("Function pointer {} can't be volatile.", name));
+            }
+            _ => return Ok(true),
+        }
+    }
+}
+
+/// Whether a field is written as an anonymous union, from its
+/// `cbindgen:anonymous-union` annotation. Its type must be a union, which is
+/// only checked when writing it.
+fn load_anonymous_union(field: &syn::Field, name: &str, ty: &Type) -> Result<bool, String> {
+    if AnnotationSet::load(&field.attrs)?.bool("anonymous-union") != Some(true) {
+        return Ok(false);
+    }
+    match *ty {
+        Type::Path(..) => Ok(true),
+        _ => Err(format!(
+            "Anonymous union {} must have a union type, not a pointer, array or primitive.",
+            name
+        )),
+    }
+}
+
+/// A field of a struct with bit-fields, volatile fields or anonymous unions,
+/// written as `uint32_t name : width`, `volatile uint32_t name` or
+/// `union { ... }`.
+struct QualifiedField<'a> {
+    field: &'a (String, Type, Documentation),
+    width: Option<u32>,
+    is_volatile: bool,
+    is_anonymous_union: bool,
+}
+
+impl<'a> QualifiedField<'a> {
+    fn plain(field: &'a (String, Type, Documentation)) -> Self {
+        QualifiedField {
+            field,
+            width: None,
+            is_volatile: false,
+            is_anonymous_union: false,
+        }
+    }
+}
+
+impl<'a> Source for QualifiedField<'a> {
+    fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if config.documentation {
+            self.field.2.write_leading(config, out);
+        }
+        if self.is_anonymous_union {
+            let union_fields = match self.field.1 {
+                Type::Path(ref generic) => out.bindings().union_fields(generic.path()),
+                _ => None,
+            };
+            if let Some(union_fields) = union_fields {
+                out.write("union");
+                out.open_brace();
+                let union_fields: Vec<_> = union_fields.iter().map(QualifiedField::plain).collect();
+                out.write_vertical_source_list(&union_fields[..], ListType::Cap(";"));
+                out.close_brace(false);
+                return;
+            }
+            warn!(
+                "Writing {} as a named field, since its type isn't a union.",
+                self.field.0
+            );
+        }
+        if self.is_volatile {
+            cdecl::write_volatile_field(out, &self.field.1, &self.field.0, config);
+        } else {
+            cdecl::write_field(out, &self.field.1, &self.field.0, config);
+        }
+        if let Some(width) = self.width {
+            write!(out, " : {}", width);
+        }
+    }
+
+    fn write_trailing<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        if config.documentation {
+            self.field.2.write_trailing(config, out);
+        }
+    }
+}
+
 pub trait SynFieldHelpers {
     fn as_ident_and_type(
         &self,
@@ -9,7 +9,7 @@ use crate::bindgen::cdecl;
 use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
-use crate::bindgen::ir::{Documentation, GenericParams, GenericPath, Path};
+use crate::bindgen::ir::{Constant, Documentation, GenericParams, GenericPath, ItemMap, Path};
 use crate::bindgen::library::Library;
 use crate::bindgen::monomorph::Monomorphs;
 use crate::bindgen::utilities::IterHelpers;
@@ -198,10 +198,52 @@ impl ArrayLength {
         }
     }
 
-    fn rename_for_config(&mut self, config: &Config) {
+    fn rename_for_config(&mut self, config: &Config, generic_params: &GenericParams) {
         if let ArrayLength::Name(ref mut name) = self {
+            if generic_params.contains(&Path::new(name.clone())) {
+                return;
+            }
             config.export.rename(name);
+            if config.constants_are_defines() {
+                *name = config.define_name(name).into_owned();
+            }
+        }
+    }
+
+    /// Replaces a length naming a const generic parameter by its value.
+    fn specialize(&self, mappings: &[(&Path, &Type)]) -> ArrayLength {
+        if let ArrayLength::Name(ref name) = *self {
+            for &(param, value) in mappings {
+                if param.name() != name {
+                    continue;
+                }
+                if let Type::Path(ref value) = *value {
+                    if value.is_const_value() {
+                        return ArrayLength::Value(value.name().to_owned());
+                    }
+                    return ArrayLength::Name(value.export_name().to_owned());
+                }
+            }
         }
+        self.clone()
+    }
+
+    fn resolve(&mut self, constants: &ItemMap<Constant>) {
+        let value = match *self {
+            ArrayLength::Name(ref name) => match Constant::integer_value_of(name, constants) {
+                Some(value) => value,
+                None => {
+                    warn!(
+                        "Cannot resolve array length `{}` to an integer value, it will be \
+                         emitted by name.",
+                        name
+                    );
+                    return;
+                }
+            },
+            ArrayLength::Value(..) => return,
+        };
+        *self = ArrayLength::Value(value);
     }
 }
 
@@ -446,6 +488,9 @@ impl Type {
     }
 
     pub fn simplify_standard_types(&mut self, config: &Config) {
+        if let Type::Array(ref mut ty, _) = *self {
+            ty.simplify_standard_types(config);
+        }
         if let Some(ty) = self.simplified_type(config) {
             *self = ty;
         }
@@ -521,8 +566,8 @@ impl Type {
                 Type::Path(specialized)
             }
             Type::Primitive(ref primitive) => Type::Primitive(primitive.clone()),
-            Type::Array(ref ty, ref constant) => {
-                Type::Array(Box::new(ty.specialize(mappings)), constant.clone())
+            Type::Array(ref ty, ref len) => {
+                Type::Array(Box::new(ty.specialize(mappings)), len.specialize(mappings))
             }
             Type::FuncPtr(ref ret, ref args) => Type::FuncPtr(
                 Box::new(ret.specialize(mappings)),
@@ -549,8 +594,11 @@ impl Type {
                     generic_value.add_dependencies_ignoring_generics(generic_params, library, out);
                 }
                 let path = generic.path();
-                if !generic_params.contains(path) {
+                let is_mapped = library.get_config().type_mappings.contains_key(path.name());
+                if !generic_params.contains(path) && !is_mapped && !generic.is_const_value() {
                     if let Some(items) = library.get_items(path) {
+                        // Mark the path as visited before walking its dependencies,
+                        // so that walking types which point to each other terminates.
                         if !out.items.contains(path) {
                             out.items.insert(path.clone());
 
@@ -587,21 +635,93 @@ impl Type {
         self.add_dependencies_ignoring_generics(&GenericParams::default(), library, out)
     }
 
+    /// Calls `f` with the path of every item this type refers to, and whether
+    /// it's used by value, rather than behind a pointer. Generic arguments are
+    /// taken to be used the same way as the type they're given to.
+    pub fn visit_referenced_paths<F: FnMut(&Path, bool)>(
+        &self,
+        generic_params: &GenericParams,
+        by_value: bool,
+        f: &mut F,
+    ) {
+        match *self {
+            Type::Ptr { ref ty, .. } => ty.visit_referenced_paths(generic_params, false, f),
+            Type::Path(ref generic) => {
+                for generic_value in generic.generics() {
+                    generic_value.visit_referenced_paths(generic_params, by_value, f);
+                }
+                if !generic_params.contains(generic.path()) {
+                    f(generic.path(), by_value);
+                }
+            }
+            Type::Primitive(_) => {}
+            Type::Array(ref ty, _) => ty.visit_referenced_paths(generic_params, by_value, f),
+            Type::FuncPtr(ref ret, ref args) => {
+                ret.visit_referenced_paths(generic_params, false, f);
+                for (_, ref arg) in args {
+                    arg.visit_referenced_paths(generic_params, false, f);
+                }
+            }
+        }
+    }
+
+    /// Calls `f` with the name of every constant this type uses as the length
+    /// of an array.
+    pub fn visit_array_length_names<F: FnMut(&str)>(&self, f: &mut F) {
+        match *self {
+            Type::Ptr { ref ty, .. } => ty.visit_array_length_names(f),
+            Type::Path(ref generic) => {
+                for generic_value in generic.generics() {
+                    generic_value.visit_array_length_names(f);
+                }
+            }
+            Type::Primitive(_) => {}
+            Type::Array(ref ty, ref len) => {
+                if let ArrayLength::Name(ref name) = *len {
+                    f(name);
+                }
+                ty.visit_array_length_names(f);
+            }
+            Type::FuncPtr(ref ret, ref args) => {
+                ret.visit_array_length_names(f);
+                for (_, ref arg) in args {
+                    arg.visit_array_length_names(f);
+                }
+            }
+        }
+    }
+
     pub fn add_monomorphs(&self, library: &Library, out: &mut Monomorphs) {
         match *self {
             Type::Ptr { ref ty, .. } => {
                 ty.add_monomorphs(library, out);
             }
             Type::Path(ref generic) => {
-                if generic.generics().is_empty() || out.contains(&generic) {
+                if generic.generics().is_empty()
+                    || out.contains(&generic)
+                    || out.is_pending(generic)
+                {
+                    return;
+                }
+                if out.is_infinitely_recursive(generic) {
+                    error!(
+                        "Cannot instantiate {} since it requires infinitely nested \
+                         instantiations of itself.",
+                        generic.path()
+                    );
+                    return;
+                }
+                if out.exceeds_max_depth(generic) {
                     return;
                 }
                 let path = generic.path();
                 if let Some(items) = library.get_items(path) {
+                    out.push_pending(generic.clone());
                     for item in items {
                         item.deref()
                             .instantiate_monomorph(generic.generics(), library, out);
                     }
+                    out.pop_pending();
                 }
             }
             Type::Primitive(_) => {}
@@ -628,7 +748,7 @@ impl Type {
             Type::Primitive(_) => {}
             Type::Array(ref mut ty, ref mut len) => {
                 ty.rename_for_config(config, generic_params);
-                len.rename_for_config(config);
+                len.rename_for_config(config, generic_params);
             }
             Type::FuncPtr(ref mut ret, ref mut args) => {
                 ret.rename_for_config(config, generic_params);
@@ -639,6 +759,25 @@ impl Type {
         }
     }
 
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        match *self {
+            Type::Ptr { ref mut ty, .. } => {
+                ty.resolve_array_lengths(constants);
+            }
+            Type::Path(..) | Type::Primitive(_) => {}
+            Type::Array(ref mut ty, ref mut len) => {
+                ty.resolve_array_lengths(constants);
+                len.resolve(constants);
+            }
+            Type::FuncPtr(ref mut ret, ref mut args) => {
+                ret.resolve_array_lengths(constants);
+                for (_, ref mut arg) in args {
+                    arg.resolve_array_lengths(constants);
+                }
+            }
+        }
+    }
+
     pub fn resolve_declaration_types(&mut self, resolver: &DeclarationTypeResolver) {
         match *self {
             Type::Ptr { ref mut ty, .. } => {
@@ -734,7 +873,11 @@ impl Source for (String, Type) {
 
 impl Source for (String, Type, Documentation) {
     fn write<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
-        self.2.write(config, out);
+        self.2.write_leading(config, out);
         cdecl::write_field(out, &self.1, &self.0, config);
     }
+
+    fn write_trailing<F: Write>(&self, config: &Config, out: &mut SourceWriter<F>) {
+        self.2.write_trailing(config, out);
+    }
 }
@@ -9,8 +9,8 @@ use crate::bindgen::config::{Config, Language};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, GenericParams, Item,
+    ItemContainer, ItemMap, Path, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -35,7 +35,7 @@ impl Typedef {
             let path = Path::new(item.ident.to_string());
             Ok(Typedef::new(
                 path,
-                GenericParams::new(&item.generics),
+                GenericParams::load(&item.generics)?,
                 x,
                 Cfg::append(mod_cfg, Cfg::load(&item.attrs)),
                 AnnotationSet::load(&item.attrs)?,
@@ -70,6 +70,10 @@ impl Typedef {
         self.aliased.simplify_standard_types(config);
     }
 
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        self.aliased.resolve_array_lengths(constants);
+    }
+
     pub fn transfer_annotations(&mut self, out: &mut HashMap<Path, AnnotationSet>) {
         if self.annotations.is_empty() {
             return;
@@ -134,7 +138,7 @@ impl Item for Typedef {
     }
 
     fn rename_for_config(&mut self, config: &Config) {
-        config.export.rename(&mut self.export_name);
+        config.export.rename_type(&mut self.export_name);
         self.aliased.rename_for_config(config, &self.generic_params);
     }
 
@@ -9,8 +9,8 @@ use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::ir::SynFieldHelpers;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, ConditionWrite, Documentation, GenericParams, Item, ItemContainer, Path,
-    Repr, ReprAlign, ReprStyle, ToCondition, Type,
+    AnnotationSet, Cfg, ConditionWrite, Constant, Documentation, GenericParams, Item,
+    ItemContainer, ItemMap, Path, Repr, ReprAlign, ReprStyle, ToCondition, Type,
 };
 use crate::bindgen::library::Library;
 use crate::bindgen::mangle;
@@ -61,7 +61,7 @@ impl Union {
 
         Ok(Union::new(
             path,
-            GenericParams::new(&item.generics),
+            GenericParams::load(&item.generics)?,
             fields,
             repr.align,
             tuple_union,
@@ -102,6 +102,12 @@ impl Union {
         }
     }
 
+    pub fn resolve_array_lengths(&mut self, constants: &ItemMap<Constant>) {
+        for &mut (_, ref mut ty, _) in &mut self.fields {
+            ty.resolve_array_lengths(constants);
+        }
+    }
+
     pub fn is_generic(&self) -> bool {
         self.generic_params.len() > 0
     }
@@ -161,7 +167,7 @@ impl Item for Union {
     }
 
     fn rename_for_config(&mut self, config: &Config) {
-        config.export.rename(&mut self.export_name);
+        config.export.rename_type(&mut self.export_name);
         for &mut (_, ref mut ty, _) in &mut self.fields {
             ty.rename_for_config(config, &self.generic_params);
         }
@@ -278,7 +284,13 @@ impl Source for Union {
         //   typedef union {
         // C with Both as style:
         //   typedef union Name {
-        if config.language == Language::C && config.style.generate_typedef() {
+        // C with a forward declaration, which has the typedef:
+        //   union Name {
+        let is_forward_declared = out.bindings().is_forward_declared(&self.path);
+        let is_typedef = config.language == Language::C
+            && config.style.generate_typedef()
+            && !is_forward_declared;
+        if is_typedef {
             out.write("typedef ");
         }
 
@@ -299,7 +311,7 @@ impl Source for Union {
             }
         }
 
-        if config.language == Language::Cxx || config.style.generate_tag() {
+        if config.language == Language::Cxx || config.style.generate_tag() || is_forward_declared {
             write!(out, " {}", self.export_name);
         }
 
@@ -328,7 +340,7 @@ impl Source for Union {
             out.write_raw_block(body);
         }
 
-        if config.language == Language::C && config.style.generate_typedef() {
+        if is_typedef {
             out.close_brace(false);
             write!(out, " {};", self.export_name);
         } else {
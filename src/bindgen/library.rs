@@ -2,18 +2,45 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at http://mozilla.org/MPL/2.0/. */
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap, HashSet};
 
 use crate::bindgen::bindings::Bindings;
-use crate::bindgen::config::{Config, Language, SortKey};
+use crate::bindgen::config::{Config, DeclarationOrder, Language, SortKey, TargetPointerWidth};
 use crate::bindgen::declarationtyperesolver::DeclarationTypeResolver;
 use crate::bindgen::dependencies::Dependencies;
 use crate::bindgen::error::Error;
-use crate::bindgen::ir::{Constant, Enum, Function, Item, ItemContainer, ItemMap};
-use crate::bindgen::ir::{OpaqueItem, Path, Static, Struct, Typedef, Union};
+use crate::bindgen::ir::{Cfg, Constant, Documentation, Enum, Function, GenericParams, Item};
+use crate::bindgen::ir::{ItemContainer, ItemMap, LayoutEstimator, OpaqueItem, Path, Static};
+use crate::bindgen::ir::{Struct, Type, Typedef, Union, VariantBody};
 use crate::bindgen::monomorph::Monomorphs;
+use crate::bindgen::parser::{self, SkippedItem};
 use crate::bindgen::ItemType;
 
+/// How `Library::merge` handles items which are defined in both libraries.
+#[allow(unused)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MergeConflictPolicy {
+    /// Fail without merging anything.
+    Error,
+    /// Keep the items of the library being merged in, dropping those of the
+    /// library it's merged into.
+    LastWins,
+}
+
+/// How many items of a kind were loaded, for coverage reports.
+#[allow(unused)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemCoverage {
+    pub kind: ItemType,
+    /// The items of this kind in the library, from any crate. Items made
+    /// opaque are counted as opaque items.
+    pub taken: usize,
+    /// The public items of the binding crate which were skipped.
+    pub skipped: usize,
+    /// The public items of the binding crate which were made opaque.
+    pub made_opaque: usize,
+}
+
 #[derive(Debug, Clone)]
 pub struct Library {
     config: Config,
@@ -25,6 +52,10 @@ pub struct Library {
     opaque_items: ItemMap<OpaqueItem>,
     typedefs: ItemMap<Typedef>,
     functions: Vec<Function>,
+    modules: HashMap<Path, String>,
+    source_order: HashMap<Path, usize>,
+    crate_documentation: Documentation,
+    skipped: Vec<SkippedItem>,
 }
 
 impl Library {
@@ -39,6 +70,10 @@ impl Library {
         opaque_items: ItemMap<OpaqueItem>,
         typedefs: ItemMap<Typedef>,
         functions: Vec<Function>,
+        modules: HashMap<Path, String>,
+        source_order: HashMap<Path, usize>,
+        crate_documentation: Documentation,
+        skipped: Vec<SkippedItem>,
     ) -> Library {
         Library {
             config,
@@ -50,12 +85,38 @@ impl Library {
             opaque_items,
             typedefs,
             functions,
+            modules,
+            source_order,
+            crate_documentation,
+            skipped,
         }
     }
 
     pub fn generate(mut self) -> Result<Bindings, Error> {
+        if self.config.declaration_order == DeclarationOrder::FunctionsFirst
+            && self.config.language == Language::C
+            && !self.config.style.generate_tag()
+        {
+            warn!(
+                "Writing types first, since functions can only come first with struct tags \
+                 (style = \"tag\" or \"both\")."
+            );
+        }
+
+        self.remove_ungenerated_cfgs();
         self.transfer_annotations();
         self.simplify_standard_types();
+        self.remove_unsafe_result_wrappers();
+
+        if self.config.structure.typedef_primitive_newtypes {
+            self.typedef_primitive_newtypes();
+        }
+
+        self.resolve_enum_discriminants();
+
+        if self.config.constant.resolve_array_lengths {
+            self.resolve_array_lengths();
+        }
 
         match self.config.function.sort_by.unwrap_or(self.config.sort_by) {
             SortKey::Name => self.functions.sort_by(|x, y| x.path.cmp(&y.path)),
@@ -63,13 +124,21 @@ impl Library {
         }
 
         if self.config.language == Language::C {
-            self.instantiate_monomorphs();
+            self.instantiate_monomorphs()?;
             self.remove_excluded();
             self.resolve_declaration_types();
         } else {
             self.remove_excluded();
         }
 
+        // Excluded functions aren't written, and excluded or mapped types
+        // aren't written as their Rust definition, so neither is checked.
+        self.check_by_value_types()?;
+
+        if self.config.layout_assertions {
+            self.estimate_struct_layouts();
+        }
+
         self.rename_items();
 
         let mut dependencies = Dependencies::new();
@@ -97,19 +166,41 @@ impl Library {
             }
         }
 
-        dependencies.sort();
-
-        let items = dependencies.order;
-        let constants = if self.config.export.should_generate(ItemType::Constants) {
-            let mut constants = self.constants.to_vec();
-            match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
-                SortKey::Name => constants.sort_by(|x, y| x.path.cmp(&y.path)),
-                SortKey::None => { /* keep input order */ }
+        let mut items = match self.config.export.sort_by.unwrap_or(SortKey::Name) {
+            SortKey::Name => {
+                dependencies.sort();
+                dependencies.order
             }
-            constants
-        } else {
-            vec![]
+            SortKey::None => self.sort_by_source_order(dependencies.order),
         };
+        if self.config.export.group_by_kind {
+            // Stable, so each kind keeps the order of `sort_by`. The types
+            // needed by value by an earlier kind are moved up below.
+            items.sort_by_key(Self::kind_rank);
+        }
+        let (items, forward_declarations) = self.order_by_value_dependencies(items);
+        // The constants used as array lengths are written even if constants
+        // aren't, since the types using them need them.
+        let array_lengths = self.array_length_names(&items);
+        let generate_constants = self.config.export.should_generate(ItemType::Constants);
+        let mut constants: Vec<_> = self
+            .constants
+            .to_vec()
+            .into_iter()
+            .filter(|x| {
+                generate_constants
+                    || array_lengths
+                        .iter()
+                        .any(|(name, _)| name == x.export_name())
+            })
+            .collect();
+        match self.config.constant.sort_by.unwrap_or(self.config.sort_by) {
+            SortKey::Name => constants.sort_by(|x, y| x.path.cmp(&y.path)),
+            SortKey::None => { /* keep input order */ }
+        }
+        if !self.config.constant.resolve_array_lengths {
+            Self::check_array_length_names(&array_lengths, &constants);
+        }
 
         let globals = if self.config.export.should_generate(ItemType::Globals) {
             let mut globals = self.globals.to_vec();
@@ -121,12 +212,20 @@ impl Library {
         } else {
             vec![]
         };
+        let module_dependencies = if self.config.split_by_module {
+            self.module_dependencies(&constants, &globals, &items)
+        } else {
+            HashMap::new()
+        };
+
         let functions = if self.config.export.should_generate(ItemType::Functions) {
             self.functions
         } else {
             vec![]
         };
 
+        Self::check_raw_text_names(&self.config, &constants, &globals, &items, &functions);
+
         Ok(Bindings::new(
             self.config,
             self.structs,
@@ -134,15 +233,783 @@ impl Library {
             globals,
             items,
             functions,
+            self.modules,
+            module_dependencies,
+            forward_declarations,
+            self.crate_documentation,
         ))
     }
 
+    /// Warns about the names in `export.before` and `export.after` which don't
+    /// match any item written to the bindings.
+    fn check_raw_text_names(
+        config: &Config,
+        constants: &[Constant],
+        globals: &[Static],
+        items: &[ItemContainer],
+        functions: &[Function],
+    ) {
+        let mut names = HashSet::new();
+        names.extend(constants.iter().map(|x| x.path.name()));
+        names.extend(globals.iter().map(|x| x.path.name()));
+        names.extend(items.iter().map(|x| x.deref().path().name()));
+        names.extend(functions.iter().map(|x| x.path.name()));
+
+        let export = &config.export;
+        for (table, map) in &[("before", &export.before), ("after", &export.after)] {
+            let mut unknown: Vec<_> = map.keys().filter(|x| !names.contains(&***x)).collect();
+            unknown.sort();
+            for name in unknown {
+                warn!(
+                    "Item `{}` in `export.{}` isn't written to the bindings, ignoring its text.",
+                    name, table
+                );
+            }
+        }
+    }
+
+    /// Reorders `items` by the order in which they were parsed, while still
+    /// writing each item after the items it depends on. Items which weren't
+    /// parsed, like monomorphs, are visited last, but are usually written
+    /// earlier as a dependency of another item.
+    fn sort_by_source_order(&self, mut items: Vec<ItemContainer>) -> Vec<ItemContainer> {
+        let source_index = |item: &ItemContainer| {
+            self.source_order
+                .get(item.deref().path())
+                .cloned()
+                .unwrap_or(usize::MAX)
+        };
+        items.sort_by_key(source_index);
+
+        let mut dependencies = Dependencies::new();
+        for item in items {
+            let path = item.deref().path().clone();
+            if !dependencies.items.insert(path.clone()) {
+                continue;
+            }
+            // Items with several definitions behind cfgs share a path.
+            let items = self.get_items(&path).unwrap_or_else(|| vec![item]);
+            for item in &items {
+                item.deref().add_dependencies(self, &mut dependencies);
+            }
+            dependencies.order.extend(items);
+        }
+        dependencies.order
+    }
+
+    /// Reorders `items` so that each item is written after the items it uses
+    /// by value, and otherwise keeps their order. Structs, unions and tagged
+    /// enums which are only used behind pointers may still be written after
+    /// the items using them, breaking cycles of pointers; these are returned
+    /// as the items to forward-declare before any definition, along with, in
+    /// C, the items pointing to themselves.
+    fn order_by_value_dependencies(
+        &self,
+        items: Vec<ItemContainer>,
+    ) -> (Vec<ItemContainer>, HashSet<Path>) {
+        // Items with several definitions behind cfgs share a path, and are
+        // ordered together.
+        let mut groups: Vec<(Path, Vec<ItemContainer>)> = Vec::new();
+        let mut group_indices: HashMap<Path, usize> = HashMap::new();
+        for item in items {
+            let path = item.deref().path().clone();
+            match group_indices.get(&path) {
+                Some(&i) => groups[i].1.push(item),
+                None => {
+                    group_indices.insert(path.clone(), groups.len());
+                    groups.push((path, vec![item]));
+                }
+            }
+        }
+
+        // The items each group refers to, and whether they must be defined
+        // before it. In C, a typedef can't be used in its own definition, so
+        // the items referring to themselves are forward-declared as well.
+        let mut refers_to_itself = vec![false; groups.len()];
+        let references: Vec<Vec<(usize, bool)>> = groups
+            .iter()
+            .enumerate()
+            .map(|(i, (_, items))| {
+                let mut references = Vec::new();
+                for item in items {
+                    Self::visit_referenced_paths(item, &mut |path, by_value| {
+                        if let Some(&j) = group_indices.get(path) {
+                            if j == i {
+                                refers_to_itself[i] = true;
+                            } else {
+                                let is_required =
+                                    by_value || !Self::can_be_forward_declared(&groups[j].1[0]);
+                                references.push((j, is_required));
+                            }
+                        }
+                    });
+                }
+                references
+            })
+            .collect();
+
+        let mut order = Vec::with_capacity(groups.len());
+        let mut visited = vec![false; groups.len()];
+        let mut finished = vec![false; groups.len()];
+        let components = if self.config.export.minimize_forward_declarations {
+            // Each component only refers to earlier ones, so only the groups
+            // in a cycle of pointers can be used before being defined.
+            Self::strongly_connected_components(&references)
+        } else {
+            vec![(0..groups.len()).collect()]
+        };
+        for component in components {
+            for i in component {
+                Self::visit_group(
+                    i,
+                    &references,
+                    &mut visited,
+                    &mut finished,
+                    &mut order,
+                    &groups,
+                );
+            }
+        }
+
+        let mut position = vec![0; groups.len()];
+        for (pos, &i) in order.iter().enumerate() {
+            position[i] = pos;
+        }
+        let mut forward_declarations = HashSet::new();
+        for &i in &order {
+            if refers_to_itself[i]
+                && self.config.language == Language::C
+                && self.config.style.generate_typedef()
+                && Self::can_be_forward_declared(&groups[i].1[0])
+            {
+                forward_declarations.insert(groups[i].0.clone());
+            }
+            for &(j, _) in &references[i] {
+                if position[j] > position[i] {
+                    forward_declarations.insert(groups[j].0.clone());
+                }
+            }
+        }
+
+        let mut groups: Vec<_> = groups.into_iter().map(Some).collect();
+        let items = order
+            .into_iter()
+            .flat_map(|i| groups[i].take().unwrap().1)
+            .collect();
+        (items, forward_declarations)
+    }
+
+    /// Adds group `i` to `order`, after the groups it requires.
+    fn visit_group(
+        i: usize,
+        references: &[Vec<(usize, bool)>],
+        visited: &mut [bool],
+        finished: &mut [bool],
+        order: &mut Vec<usize>,
+        groups: &[(Path, Vec<ItemContainer>)],
+    ) {
+        if visited[i] {
+            if !finished[i] {
+                warn!(
+                    "{} is part of a cycle of types which must each be defined before the \
+                     next, so it can't be written after all of them.",
+                    groups[i].0
+                );
+            }
+            return;
+        }
+        visited[i] = true;
+        for &(j, is_required) in &references[i] {
+            if is_required {
+                Self::visit_group(j, references, visited, finished, order, groups);
+            }
+        }
+        finished[i] = true;
+        order.push(i);
+    }
+
+    /// The strongly connected components of the graph of `references`, found
+    /// with Tarjan's algorithm. Each component comes after the components it
+    /// refers to, and has its groups in their original order.
+    fn strongly_connected_components(references: &[Vec<(usize, bool)>]) -> Vec<Vec<usize>> {
+        struct State<'a> {
+            references: &'a [Vec<(usize, bool)>],
+            index: Vec<Option<usize>>,
+            low_link: Vec<usize>,
+            next_index: usize,
+            stack: Vec<usize>,
+            on_stack: Vec<bool>,
+            components: Vec<Vec<usize>>,
+        }
+
+        fn visit(state: &mut State, i: usize) {
+            state.index[i] = Some(state.next_index);
+            state.low_link[i] = state.next_index;
+            state.next_index += 1;
+            state.stack.push(i);
+            state.on_stack[i] = true;
+
+            let references = state.references;
+            for &(j, _) in &references[i] {
+                match state.index[j] {
+                    None => {
+                        visit(state, j);
+                        state.low_link[i] = state.low_link[i].min(state.low_link[j]);
+                    }
+                    Some(index) if state.on_stack[j] => {
+                        state.low_link[i] = state.low_link[i].min(index);
+                    }
+                    Some(_) => {}
+                }
+            }
+
+            if state.index[i] == Some(state.low_link[i]) {
+                let mut component = Vec::new();
+                loop {
+                    let j = state.stack.pop().unwrap();
+                    state.on_stack[j] = false;
+                    component.push(j);
+                    if j == i {
+                        break;
+                    }
+                }
+                component.sort_unstable();
+                state.components.push(component);
+            }
+        }
+
+        let mut state = State {
+            references,
+            index: vec![None; references.len()],
+            low_link: vec![0; references.len()],
+            next_index: 0,
+            stack: Vec::new(),
+            on_stack: vec![false; references.len()],
+            components: Vec::new(),
+        };
+        for i in 0..references.len() {
+            if state.index[i].is_none() {
+                visit(&mut state, i);
+            }
+        }
+        state.components
+    }
+
+    /// The position of the kind of `item` with `export.group_by_kind`.
+    fn kind_rank(item: &ItemContainer) -> usize {
+        match *item {
+            ItemContainer::Typedef(..) => 0,
+            ItemContainer::Enum(..) => 1,
+            ItemContainer::Struct(..) | ItemContainer::Union(..) => 2,
+            ItemContainer::OpaqueItem(..) => 3,
+            ItemContainer::Constant(..) | ItemContainer::Static(..) => 4,
+        }
+    }
+
+    /// Whether `write_forward_declaration` of `Bindings` declares `item`
+    /// without writing all of it.
+    fn can_be_forward_declared(item: &ItemContainer) -> bool {
+        match *item {
+            ItemContainer::Struct(ref x) => !x.is_transparent,
+            ItemContainer::Union(..) => true,
+            ItemContainer::Enum(ref x) => x.tag.is_some(),
+            _ => false,
+        }
+    }
+
+    fn visit_referenced_paths<F: FnMut(&Path, bool)>(item: &ItemContainer, f: &mut F) {
+        Self::visit_field_types(item, &mut |ty, generic_params| {
+            ty.visit_referenced_paths(generic_params, true, f)
+        });
+    }
+
+    /// Calls `f` with the type of every field of `item`, or the aliased type of
+    /// typedefs, and the generic parameters of `item`.
+    fn visit_field_types<F: FnMut(&Type, &GenericParams)>(item: &ItemContainer, f: &mut F) {
+        match *item {
+            ItemContainer::Struct(ref x) => {
+                for (_, ty, _) in &x.fields {
+                    f(ty, &x.generic_params);
+                }
+            }
+            ItemContainer::Union(ref x) => {
+                for (_, ty, _) in &x.fields {
+                    f(ty, &x.generic_params);
+                }
+            }
+            ItemContainer::Enum(ref x) => {
+                for variant in &x.variants {
+                    if let VariantBody::Body { ref body, .. } = variant.body {
+                        for (_, ty, _) in &body.fields {
+                            f(ty, &x.generic_params);
+                        }
+                    }
+                }
+            }
+            ItemContainer::Typedef(ref x) => f(&x.aliased, &x.generic_params),
+            ItemContainer::OpaqueItem(..) => {}
+            ItemContainer::Constant(..) | ItemContainer::Static(..) => unreachable!(),
+        }
+    }
+
+    /// The names of the constants used as array lengths by `items` and the
+    /// globals, with the path of the item using each of them.
+    fn array_length_names(&self, items: &[ItemContainer]) -> Vec<(String, Path)> {
+        let mut result = Vec::new();
+        for item in items {
+            let path = item.deref().path();
+            Self::visit_field_types(item, &mut |ty, generic_params| {
+                ty.visit_array_length_names(&mut |name| {
+                    if !generic_params.contains(&Path::new(name)) {
+                        result.push((name.to_owned(), path.clone()));
+                    }
+                });
+            });
+        }
+        self.globals.for_all_items(|global| {
+            global.ty.visit_array_length_names(&mut |name| {
+                result.push((name.to_owned(), global.path.clone()))
+            });
+        });
+        result
+    }
+
+    /// Warns about the array lengths which don't name any of the `constants`
+    /// written to the bindings.
+    fn check_array_length_names(array_lengths: &[(String, Path)], constants: &[Constant]) {
+        let mut reported = HashSet::new();
+        for (name, user) in array_lengths {
+            if constants.iter().any(|x| x.export_name() == name) || !reported.insert(name) {
+                continue;
+            }
+            warn!(
+                "`{}`, the length of an array in {}, isn't an exported constant, so it's \
+                 undefined in the bindings. It should be a `pub const` of the crate.",
+                name, user
+            );
+        }
+    }
+
+    /// Computes the modules that the items of each module depend on, for
+    /// splitting the bindings by module. The root of the crate is `None`.
+    fn module_dependencies(
+        &self,
+        constants: &[Constant],
+        globals: &[Static],
+        items: &[ItemContainer],
+    ) -> HashMap<Option<String>, BTreeSet<Option<String>>> {
+        let mut result: HashMap<_, BTreeSet<_>> = HashMap::new();
+        let mut add = |path: &Path, dependencies: Dependencies| {
+            let module = self.modules.get(path).cloned();
+            let module_dependencies = result.entry(module.clone()).or_default();
+            for dependency in &dependencies.items {
+                let dependency_module = self.modules.get(dependency).cloned();
+                if dependency_module != module {
+                    module_dependencies.insert(dependency_module);
+                }
+            }
+        };
+
+        for item in items {
+            let mut dependencies = Dependencies::new();
+            item.deref().add_dependencies(self, &mut dependencies);
+            add(item.deref().path(), dependencies);
+        }
+        // Types also depend on the constants they use as array lengths.
+        for (name, user) in self.array_length_names(items) {
+            if let Some(constant) = constants.iter().find(|x| x.export_name() == name) {
+                let mut dependencies = Dependencies::new();
+                dependencies.items.insert(constant.path.clone());
+                add(&user, dependencies);
+            }
+        }
+        for constant in constants {
+            let mut dependencies = Dependencies::new();
+            constant.add_dependencies(self, &mut dependencies);
+            add(&constant.path, dependencies);
+        }
+        for global in globals {
+            let mut dependencies = Dependencies::new();
+            global.add_dependencies(self, &mut dependencies);
+            add(&global.path, dependencies);
+        }
+        if self.config.export.should_generate(ItemType::Functions) {
+            for function in &self.functions {
+                let mut dependencies = Dependencies::new();
+                function.add_dependencies(self, &mut dependencies);
+                add(&function.path, dependencies);
+            }
+        }
+
+        let describe = |module: &Option<String>| match *module {
+            Some(ref module) => format!("module `{}`", module),
+            None => "the crate root".to_owned(),
+        };
+        for (module, dependencies) in &result {
+            for dependency in dependencies {
+                let is_cycle = module < dependency
+                    && result.get(dependency).map(|x| x.contains(module)) == Some(true);
+                if is_cycle {
+                    warn!(
+                        "Items of {} and {} depend on each other, their headers can't be \
+                         split and won't compile.",
+                        describe(module),
+                        describe(dependency)
+                    );
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Removes the wrappers declared by `cbindgen:result-wrapper` whose `Ok` or
+    /// error types can't cross the FFI boundary by value.
+    fn remove_unsafe_result_wrappers(&mut self) {
+        let mut unsafe_wrappers = Vec::new();
+        for (i, function) in self.functions.iter().enumerate() {
+            if function.annotations.atom("result-wrapper").is_none() {
+                continue;
+            }
+            let out_arg = function
+                .args
+                .last()
+                .filter(|arg| arg.name.as_ref() == Some(&self.config.function.result_wrapper_arg));
+            let ok = out_arg.and_then(|arg| match arg.ty {
+                Type::Ptr { ref ty, .. } => Some(&**ty),
+                _ => None,
+            });
+            let unsafe_type = ok
+                .and_then(|ok| self.ffi_unsafe_type(ok))
+                .or_else(|| self.ffi_unsafe_type(&function.ret));
+            if let Some(name) = unsafe_type {
+                error!(
+                    "Cannot use fn {} (Result with a non FFI-safe type `{}` can't be lowered).",
+                    function.path, name
+                );
+                unsafe_wrappers.push(i);
+            }
+        }
+        for i in unsafe_wrappers.into_iter().rev() {
+            self.functions.remove(i);
+        }
+    }
+
+    /// Returns the name of the type held by value by `ty` which can't cross
+    /// the FFI boundary, if any: types which aren't defined by the crate with
+    /// a `#[repr]`, nor mapped, like `String`.
+    fn ffi_unsafe_type(&self, ty: &Type) -> Option<Path> {
+        let generic = match *ty {
+            Type::Path(ref generic) => generic,
+            Type::Array(ref ty, _) => return self.ffi_unsafe_type(ty),
+            _ => return None,
+        };
+        let path = generic.path();
+        if self.config.type_mappings.contains_key(path.name()) {
+            return None;
+        }
+        let items = match self.get_items(path) {
+            Some(items) => items,
+            None => return Some(path.clone()),
+        };
+        let is_unsafe = items.iter().any(|item| match *item {
+            ItemContainer::OpaqueItem(..) => true,
+            ItemContainer::Typedef(ref typedef) => self.ffi_unsafe_type(&typedef.aliased).is_some(),
+            _ => false,
+        });
+        if is_unsafe {
+            return Some(path.clone());
+        }
+        generic
+            .generics()
+            .iter()
+            .filter_map(|ty| self.ffi_unsafe_type(ty))
+            .next()
+    }
+
+    /// Checks that functions don't take or return types without a stable
+    /// layout by value.
+    fn check_by_value_types(&self) -> Result<(), Error> {
+        let mut errors = Vec::new();
+        for function in &self.functions {
+            for (i, arg) in function.args.iter().enumerate() {
+                if let Some(name) = self.default_repr_type(&arg.ty) {
+                    let arg_name = arg.name.clone().unwrap_or_else(|| format!("#{}", i));
+                    errors.push(format!(
+                        "argument `{}` of `{}` has type `{}`",
+                        arg_name, function.path, name
+                    ));
+                }
+            }
+            if let Some(name) = self.default_repr_type(&function.ret) {
+                errors.push(format!(
+                    "the return value of `{}` has type `{}`",
+                    function.path, name
+                ));
+            }
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(Error::DefaultReprByValue(errors))
+        }
+    }
+
+    /// Returns the name of the type without a `#[repr]` that `ty` holds by
+    /// value, if any, looking through typedefs.
+    fn default_repr_type(&self, ty: &Type) -> Option<Path> {
+        let path = match *ty {
+            Type::Path(ref generic) => generic.path(),
+            Type::Array(ref ty, _) => return self.default_repr_type(ty),
+            _ => return None,
+        };
+        let mut result = None;
+        self.opaque_items.for_items(path, |item| {
+            if item.is_default_repr {
+                result = Some(path.clone());
+            }
+        });
+        self.typedefs.for_items(path, |item| {
+            if result.is_none() {
+                result = self.default_repr_type(&item.aliased);
+            }
+        });
+        result
+    }
+
+    /// Merges the items of `other`, parsed separately, into this library, e.g.
+    /// to generate a single header for several crates. The configuration of
+    /// this library is kept.
+    ///
+    /// Types, constants, globals and functions with the same name in both
+    /// libraries conflict (types share a single namespace, as in C), except
+    /// for types which are opaque in both, and are handled according to
+    /// `policy`. The names of the conflicting items are
+    /// returned, or, with `MergeConflictPolicy::Error`, reported in an
+    /// `Error::MergeConflicts` without changing this library.
+    #[allow(unused)]
+    pub fn merge(
+        &mut self,
+        other: Library,
+        policy: MergeConflictPolicy,
+    ) -> Result<Vec<Path>, Error> {
+        let conflicts = self.merge_conflicts(&other);
+        if policy == MergeConflictPolicy::Error && !conflicts.is_empty() {
+            return Err(Error::MergeConflicts(
+                conflicts.iter().map(|x| x.to_string()).collect(),
+            ));
+        }
+
+        for path in &conflicts {
+            self.constants.remove(path);
+            self.globals.remove(path);
+            self.enums.remove(path);
+            self.structs.remove(path);
+            self.unions.remove(path);
+            self.opaque_items.remove(path);
+            self.typedefs.remove(path);
+            self.functions.retain(|x| x.path() != path);
+        }
+
+        self.constants.extend_with(&other.constants);
+        self.globals.extend_with(&other.globals);
+        self.enums.extend_with(&other.enums);
+        self.structs.extend_with(&other.structs);
+        self.unions.extend_with(&other.unions);
+        self.opaque_items.extend_with(&other.opaque_items);
+        self.typedefs.extend_with(&other.typedefs);
+        self.functions.extend(other.functions);
+        self.modules.extend(other.modules);
+        parser::extend_source_order(&mut self.source_order, &other.source_order);
+        if self.crate_documentation.doc_comment.is_empty() {
+            self.crate_documentation = other.crate_documentation;
+        }
+        self.skipped.extend(other.skipped);
+
+        Ok(conflicts)
+    }
+
+    /// The names of the items of `other` which are also defined in this
+    /// library, in the order they're found in `other`.
+    fn merge_conflicts(&self, other: &Library) -> Vec<Path> {
+        let is_type = |path: &Path| {
+            self.enums.contains(path)
+                || self.structs.contains(path)
+                || self.unions.contains(path)
+                || self.opaque_items.contains(path)
+                || self.typedefs.contains(path)
+        };
+
+        let mut conflicts = Vec::new();
+        conflicts.extend(
+            other
+                .constants
+                .paths()
+                .filter(|x| self.constants.contains(x))
+                .cloned(),
+        );
+        conflicts.extend(
+            other
+                .globals
+                .paths()
+                .filter(|x| self.globals.contains(x))
+                .cloned(),
+        );
+        let types = other
+            .enums
+            .paths()
+            .chain(other.structs.paths())
+            .chain(other.unions.paths())
+            .chain(other.typedefs.paths());
+        conflicts.extend(types.filter(|x| is_type(x)).cloned());
+        // Opaque items, like those for standard types, are just declarations,
+        // so they only conflict with types which aren't opaque.
+        conflicts.extend(
+            other
+                .opaque_items
+                .paths()
+                .filter(|x| is_type(x) && !self.opaque_items.contains(x))
+                .cloned(),
+        );
+        conflicts.extend(
+            other
+                .functions
+                .iter()
+                .map(|x| x.path())
+                .filter(|x| self.functions.iter().any(|y| y.path() == *x))
+                .cloned(),
+        );
+        conflicts.dedup();
+        conflicts
+    }
+
+    /// The functions found in the sources.
+    #[allow(unused)]
+    pub fn functions(&self) -> impl Iterator<Item = &Function> {
+        self.functions.iter()
+    }
+
+    /// The constants found in the sources, excluding associated constants of
+    /// structs.
+    #[allow(unused)]
+    pub fn constants(&self) -> impl Iterator<Item = &Constant> {
+        self.constants.iter()
+    }
+
+    /// The `static` variables found in the sources.
+    #[allow(unused)]
+    pub fn globals(&self) -> impl Iterator<Item = &Static> {
+        self.globals.iter()
+    }
+
+    /// The enums found in the sources.
+    #[allow(unused)]
+    pub fn enums(&self) -> impl Iterator<Item = &Enum> {
+        self.enums.iter()
+    }
+
+    /// The structs found in the sources.
+    #[allow(unused)]
+    pub fn structs(&self) -> impl Iterator<Item = &Struct> {
+        self.structs.iter()
+    }
+
+    /// The unions found in the sources.
+    #[allow(unused)]
+    pub fn unions(&self) -> impl Iterator<Item = &Union> {
+        self.unions.iter()
+    }
+
+    /// The types which can only be exported as opaque declarations, either
+    /// because they don't have a C-compatible layout or because they come from
+    /// the standard library.
+    #[allow(unused)]
+    pub fn opaque_items(&self) -> impl Iterator<Item = &OpaqueItem> {
+        self.opaque_items.iter()
+    }
+
+    /// The type aliases found in the sources.
+    #[allow(unused)]
+    pub fn typedefs(&self) -> impl Iterator<Item = &Typedef> {
+        self.typedefs.iter()
+    }
+
+    /// The public items of the binding crate which were skipped or made
+    /// opaque while parsing, except those in `export.exclude`.
+    #[allow(unused)]
+    pub fn skipped_items(&self) -> &[SkippedItem] {
+        &self.skipped
+    }
+
+    /// The number of items of each kind which were taken, skipped or made
+    /// opaque while parsing, to compute how much of the crate is exported.
+    #[allow(unused)]
+    pub fn coverage(&self) -> Vec<ItemCoverage> {
+        let taken = [
+            (ItemType::Constants, self.constants.len()),
+            (ItemType::Globals, self.globals.len()),
+            (ItemType::Enums, self.enums.len()),
+            (ItemType::Structs, self.structs.len()),
+            (ItemType::Unions, self.unions.len()),
+            (ItemType::Typedefs, self.typedefs.len()),
+            (ItemType::OpaqueItems, self.opaque_items.len()),
+            (ItemType::Functions, self.functions.len()),
+        ];
+        taken
+            .iter()
+            .map(|&(kind, taken)| {
+                let skipped = self.skipped.iter().filter(|x| x.kind == kind);
+                let made_opaque = skipped.clone().filter(|x| x.made_opaque).count();
+                ItemCoverage {
+                    kind,
+                    taken,
+                    skipped: skipped.count() - made_opaque,
+                    made_opaque,
+                }
+            })
+            .collect()
+    }
+
+    /// The paths of the types which the type at `path` uses, directly or
+    /// through other types, in the order in which they're first reached. Types
+    /// which aren't in the library are left out, and `path` is only included
+    /// if it refers back to itself.
+    #[allow(unused)]
+    pub fn dependencies_of(&self, path: &Path) -> Vec<Path> {
+        let mut visited = HashSet::new();
+        let mut result = Vec::new();
+        self.collect_dependencies(path, &mut visited, &mut result);
+        result
+    }
+
+    fn collect_dependencies(&self, path: &Path, visited: &mut HashSet<Path>, out: &mut Vec<Path>) {
+        let items = match self.get_items(path) {
+            Some(items) => items,
+            None => return,
+        };
+        let mut referenced = Vec::new();
+        for item in &items {
+            Self::visit_referenced_paths(item, &mut |path, _| referenced.push(path.clone()));
+        }
+        for path in referenced {
+            if self.get_items(&path).is_some() && visited.insert(path.clone()) {
+                out.push(path.clone());
+                self.collect_dependencies(&path, visited, out);
+            }
+        }
+    }
+
+    /// Looks up the types named by `p`, if any. There can be several of them
+    /// when they depend on `cfg`s.
     pub fn get_items(&self, p: &Path) -> Option<Vec<ItemContainer>> {
+        // The definitions behind different cfgs may be of different kinds, e.g.
+        // an enum which is opaque on some targets.
+        let mut items = Vec::new();
         macro_rules! find {
             ($field:ident, $kind:ident) => {
                 if self.config.export.should_generate(ItemType::$kind) {
                     if let Some(x) = self.$field.get_items(p) {
-                        return Some(x);
+                        items.extend(x);
                     }
                 }
             };
@@ -154,13 +1021,44 @@ impl Library {
         find!(opaque_items, OpaqueItems);
         find!(typedefs, Typedefs);
 
-        None
+        if items.is_empty() {
+            None
+        } else {
+            Some(items)
+        }
     }
 
     pub fn get_config(&self) -> &Config {
         &self.config
     }
 
+    /// Removes the items whose cfg can't hold on the configured target.
+    fn remove_ungenerated_cfgs(&mut self) {
+        let config = &self.config;
+        if config.target_pointer_width.is_none()
+            && config.target_os.is_none()
+            && config.target_arch.is_none()
+        {
+            return;
+        }
+        self.functions
+            .retain(|x| Cfg::is_generated(x.cfg.as_ref(), config));
+        self.enums.filter(|x| !Cfg::is_generated(x.cfg(), config));
+        self.enums.for_all_items_mut(|x| {
+            x.variants
+                .retain(|variant| Cfg::is_generated(variant.cfg.as_ref(), config));
+        });
+        self.structs.filter(|x| !Cfg::is_generated(x.cfg(), config));
+        self.unions.filter(|x| !Cfg::is_generated(x.cfg(), config));
+        self.opaque_items
+            .filter(|x| !Cfg::is_generated(x.cfg(), config));
+        self.typedefs
+            .filter(|x| !Cfg::is_generated(x.cfg(), config));
+        self.globals.filter(|x| !Cfg::is_generated(x.cfg(), config));
+        self.constants
+            .filter(|x| !Cfg::is_generated(x.cfg(), config));
+    }
+
     fn remove_excluded(&mut self) {
         let config = &self.config;
         // FIXME: interpret `config.export.exclude` as `Path`s.
@@ -180,6 +1078,15 @@ impl Library {
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
         self.constants
             .filter(|x| config.export.exclude.iter().any(|y| y == x.path().name()));
+
+        // Types with a mapping are written as the C type they're mapped to, so
+        // their Rust definitions, if any, aren't used.
+        let is_mapped = |x: &Path| config.type_mappings.contains_key(x.name());
+        self.enums.filter(|x| is_mapped(x.path()));
+        self.structs.filter(|x| is_mapped(x.path()));
+        self.unions.filter(|x| is_mapped(x.path()));
+        self.opaque_items.filter(|x| is_mapped(x.path()));
+        self.typedefs.filter(|x| is_mapped(x.path()));
     }
 
     fn transfer_annotations(&mut self) {
@@ -322,8 +1229,12 @@ impl Library {
             x.collect_declaration_types(&mut resolver);
         });
 
+        let config = &self.config;
         self.enums.for_all_items(|x| {
-            x.collect_declaration_types(&mut resolver);
+            // Enums written as defines are referred to by their typedef.
+            if !x.is_written_as_defines(config) {
+                x.collect_declaration_types(&mut resolver);
+            }
         });
 
         self.unions.for_all_items(|x| {
@@ -370,9 +1281,106 @@ impl Library {
         }
     }
 
-    fn instantiate_monomorphs(&mut self) {
+    fn typedef_primitive_newtypes(&mut self) {
+        self.structs.for_all_items_mut(|x| {
+            if x.is_primitive_newtype() {
+                x.is_transparent = true;
+            }
+        });
+    }
+
+    /// Estimates the layouts of structs for `layout_assertions`. If they
+    /// depend on the size of pointers and it isn't known, they are estimated
+    /// for both 32 and 64 bits, to be checked with preprocessor guards.
+    fn estimate_struct_layouts(&mut self) {
+        // The pointer sizes to try, with the pointer width in bits to guard
+        // the assertions with.
+        let pointer_sizes = match self.config.target_pointer_width {
+            Some(TargetPointerWidth::Bits(bits)) => vec![(None, Some(u64::from(bits) / 8))],
+            Some(TargetPointerWidth::Preprocessor) => {
+                vec![(None, None), (Some(32), Some(4)), (Some(64), Some(8))]
+            }
+            None => vec![(None, None)],
+        };
+
+        // The layouts are estimated for each cfg variant of a struct, looking
+        // up the types of its fields in the unchanged library.
+        let mut structs = self.structs.clone();
+        structs.for_all_items_mut(|st| {
+            let mut struct_layouts = Vec::new();
+            for &(bits, pointer_size) in &pointer_sizes {
+                let estimator = LayoutEstimator::new(self, &self.constants, pointer_size);
+                if let Some(layout) = estimator.struct_layout(st) {
+                    struct_layouts.push((bits, layout));
+                    if bits.is_none() {
+                        break;
+                    }
+                }
+            }
+            if struct_layouts.is_empty() {
+                info!(
+                    "Not asserting the layout of {}, since it can't be estimated.",
+                    st.path.name()
+                );
+            }
+            st.layouts = struct_layouts;
+        });
+        self.structs = structs;
+    }
+
+    fn resolve_enum_discriminants(&mut self) {
+        let constants = &self.constants;
+        let mut unresolved = Vec::new();
+        self.enums.for_all_items_mut(|x| {
+            if let Err(msg) = x.resolve_discriminants(constants) {
+                warn!("Take {} - opaque ({})", x.path.name(), msg);
+                unresolved.push(OpaqueItem::new(
+                    x.path.clone(),
+                    x.generic_params.clone(),
+                    x.cfg.clone(),
+                    x.annotations.clone(),
+                    x.documentation.clone(),
+                ));
+            }
+        });
+        // Only the cfg variants of an enum which failed keep the discriminant
+        // expressions they couldn't evaluate.
+        self.enums.filter(|x| {
+            x.variants
+                .iter()
+                .any(|variant| variant.discriminant_expr.is_some())
+        });
+        for opaque in unresolved {
+            self.opaque_items.try_insert(opaque);
+        }
+    }
+
+    fn resolve_array_lengths(&mut self) {
+        let constants = &self.constants;
+
+        self.structs.for_all_items_mut(|x| {
+            x.resolve_array_lengths(constants);
+        });
+        self.unions.for_all_items_mut(|x| {
+            x.resolve_array_lengths(constants);
+        });
+        self.enums.for_all_items_mut(|x| {
+            x.resolve_array_lengths(constants);
+        });
+        self.globals.for_all_items_mut(|x| {
+            x.resolve_array_lengths(constants);
+        });
+        self.typedefs.for_all_items_mut(|x| {
+            x.resolve_array_lengths(constants);
+        });
+        for x in &mut self.functions {
+            x.resolve_array_lengths(constants);
+        }
+    }
+
+    fn instantiate_monomorphs(&mut self) -> Result<(), Error> {
         // Collect a list of monomorphs
-        let mut monomorphs = Monomorphs::default();
+        let mut monomorphs = Monomorphs::with_max_depth(self.config.max_specialization_depth);
 
         self.structs.for_all_items(|x| {
             x.add_monomorphs(self, &mut monomorphs);
@@ -390,6 +1398,10 @@ impl Library {
             x.add_monomorphs(self, &mut monomorphs);
         }
 
+        if let Some(chain) = monomorphs.too_deep_chain() {
+            return Err(Error::SpecializationTooDeep(chain));
+        }
+
         // Insert the monomorphs into self
         for monomorph in monomorphs.drain_structs() {
             self.structs.try_insert(monomorph);
@@ -426,5 +1438,7 @@ impl Library {
         for x in &mut self.functions {
             x.mangle_paths(&monomorphs);
         }
+
+        Ok(())
     }
 }
@@ -62,3 +62,11 @@ pub use self::bindings::Bindings;
 pub use self::builder::Builder;
 pub use self::config::*;
 pub use self::error::Error;
+#[allow(unused)]
+pub use self::ir::{
+    Constant, Enum, Function, Item, OpaqueItem, Path, Static, Struct, Typedef, Union,
+};
+#[allow(unused)]
+pub use self::library::{ItemCoverage, Library, MergeConflictPolicy};
+#[allow(unused)]
+pub use self::parser::SkippedItem;
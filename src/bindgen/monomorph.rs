@@ -5,7 +5,9 @@
 use std::collections::HashMap;
 use std::mem;
 
-use crate::bindgen::ir::{Enum, GenericPath, OpaqueItem, Path, Struct, Type, Typedef, Union};
+use crate::bindgen::ir::{
+    Enum, GenericPath, OpaqueItem, Path, PrimitiveType, Struct, Type, Typedef, Union,
+};
 
 #[derive(Default, Clone, Debug)]
 pub struct Monomorphs {
@@ -15,13 +17,76 @@ pub struct Monomorphs {
     unions: Vec<Union>,
     typedefs: Vec<Typedef>,
     enums: Vec<Enum>,
+    /// The monomorphs being instantiated, innermost last.
+    pending: Vec<GenericPath>,
+    /// The maximum length of `pending`, or 0 for no maximum.
+    max_depth: usize,
+    /// The chain of monomorphs that exceeded `max_depth`, outermost first.
+    too_deep: Option<Vec<GenericPath>>,
 }
 
 impl Monomorphs {
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Monomorphs {
+            max_depth,
+            ..Monomorphs::default()
+        }
+    }
+
+    /// Whether instantiating `path` would nest instantiations deeper than
+    /// allowed. The first time this happens, the offending chain is recorded
+    /// and all further instantiations are abandoned.
+    pub fn exceeds_max_depth(&mut self, path: &GenericPath) -> bool {
+        if self.too_deep.is_some() {
+            return true;
+        }
+        if self.max_depth == 0 || self.pending.len() < self.max_depth {
+            return false;
+        }
+        let mut chain = self.pending.clone();
+        chain.push(path.clone());
+        self.too_deep = Some(chain);
+        true
+    }
+
+    /// The chain of instantiations which exceeded the maximum depth, if any,
+    /// as Rust-like type names.
+    pub fn too_deep_chain(&self) -> Option<Vec<String>> {
+        let chain = self.too_deep.as_ref()?;
+        Some(chain.iter().map(generic_path_name).collect())
+    }
+
     pub fn contains(&self, path: &GenericPath) -> bool {
         self.replacements.contains_key(path)
     }
 
+    /// Whether `path` is being instantiated, i.e. it refers to itself through
+    /// its fields.
+    pub fn is_pending(&self, path: &GenericPath) -> bool {
+        self.pending.contains(path)
+    }
+
+    /// Whether instantiating `path` would need to instantiate ever more nested
+    /// monomorphs of the same item, like `Node<T>` referring to `Node<Node<T>>`.
+    pub fn is_infinitely_recursive(&self, path: &GenericPath) -> bool {
+        self.pending.iter().any(|pending| {
+            pending.path() == path.path()
+                && pending
+                    .generics()
+                    .iter()
+                    .zip(path.generics())
+                    .all(|(inner, outer)| type_contains(outer, inner))
+        })
+    }
+
+    pub fn push_pending(&mut self, path: GenericPath) {
+        self.pending.push(path);
+    }
+
+    pub fn pop_pending(&mut self) {
+        self.pending.pop();
+    }
+
     pub fn insert_struct(&mut self, generic: &Struct, monomorph: Struct, parameters: Vec<Type>) {
         let replacement_path = GenericPath::new(generic.path.clone(), parameters);
 
@@ -106,3 +171,55 @@ impl Monomorphs {
         mem::take(&mut self.enums)
     }
 }
+
+/// Whether `inner` is `outer` or appears somewhere inside of it.
+fn type_contains(outer: &Type, inner: &Type) -> bool {
+    if outer == inner {
+        return true;
+    }
+    match *outer {
+        Type::Ptr { ref ty, .. } | Type::Array(ref ty, _) => type_contains(ty, inner),
+        Type::Path(ref generic) => generic.generics().iter().any(|x| type_contains(x, inner)),
+        Type::Primitive(_) => false,
+        Type::FuncPtr(ref ret, ref args) => {
+            type_contains(ret, inner) || args.iter().any(|(_, x)| type_contains(x, inner))
+        }
+    }
+}
+
+fn generic_path_name(path: &GenericPath) -> String {
+    if path.generics().is_empty() {
+        return path.path().to_string();
+    }
+    let generics: Vec<_> = path.generics().iter().map(type_name).collect();
+    format!("{}<{}>", path.path(), generics.join(", "))
+}
+
+/// The Rust name of `ty`, for error messages.
+fn type_name(ty: &Type) -> String {
+    match *ty {
+        Type::Ptr {
+            ref ty,
+            is_const,
+            is_ref,
+            ..
+        } => match (is_ref, is_const) {
+            (true, true) => format!("&{}", type_name(ty)),
+            (true, false) => format!("&mut {}", type_name(ty)),
+            (false, true) => format!("*const {}", type_name(ty)),
+            (false, false) => format!("*mut {}", type_name(ty)),
+        },
+        Type::Path(ref generic) => generic_path_name(generic),
+        Type::Primitive(ref primitive) => primitive.to_repr_rust().to_owned(),
+        Type::Array(ref ty, ref len) => format!("[{}; {}]", type_name(ty), len.as_str()),
+        Type::FuncPtr(ref ret, ref args) => {
+            let args: Vec<_> = args.iter().map(|(_, x)| type_name(x)).collect();
+            match **ret {
+                Type::Primitive(PrimitiveType::Void) => {
+                    format!("extern \"C\" fn({})", args.join(", "))
+                }
+                _ => format!("extern \"C\" fn({}) -> {}", args.join(", "), type_name(ret)),
+            }
+        }
+    }
+}
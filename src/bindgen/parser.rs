@@ -4,16 +4,19 @@
 
 use std::collections::hash_map::Entry;
 use std::collections::{HashMap, HashSet};
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::Read;
+use std::mem;
 use std::path::{Path as FilePath, PathBuf as FilePathBuf};
 
+use syn::spanned::Spanned;
+
 use crate::bindgen::bitflags;
 use crate::bindgen::cargo::{Cargo, PackageRef};
-use crate::bindgen::config::{Config, ParseConfig};
+use crate::bindgen::config::{Config, ItemType, ParseConfig};
 use crate::bindgen::error::Error;
 use crate::bindgen::ir::{
-    AnnotationSet, Cfg, Constant, Documentation, Enum, Function, GenericParams, ItemMap,
+    AnnotationSet, Cfg, Constant, Documentation, Enum, Function, GenericParams, Item, ItemMap,
     OpaqueItem, Path, Static, Struct, Type, Typedef, Union,
 };
 use crate::bindgen::utilities::{SynAbiHelpers, SynAttributeHelpers, SynItemFnHelpers};
@@ -29,12 +32,36 @@ const STD_CRATES: &[&str] = &[
 
 type ParseResult = Result<Parse, Error>;
 
+/// Describes where the item at `span` is in `src_path` for diagnostics, e.g.
+/// ` at src/lib.rs:12`. Falls back to the file name alone if the line isn't
+/// known, and to nothing for expanded crates which have no source file.
+fn location(src_path: Option<&FilePath>, span: proc_macro2::Span) -> String {
+    match src_path {
+        Some(src_path) => match span.start().line {
+            0 => format!(" at {}", src_path.display()),
+            line => format!(" at {}:{}", src_path.display(), line),
+        },
+        None => String::new(),
+    }
+}
+
+/// Like `location`, but only when tracing, to keep the messages about every
+/// taken or skipped item short.
+fn log_location(src_path: Option<&FilePath>, span: proc_macro2::Span) -> String {
+    if log_enabled!(log::Level::Trace) {
+        location(src_path, span)
+    } else {
+        String::new()
+    }
+}
+
 /// Parses a single rust source file, not following `mod` or `extern crate`.
 pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
     let mod_name = src_file.file_stem().unwrap().to_str().unwrap();
     let mut config = config.clone();
     config.parse = ParseConfig {
         parse_deps: true,
+        skip_attribute: config.parse.skip_attribute.clone(),
         ..ParseConfig::default()
     };
 
@@ -46,6 +73,8 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        module: None,
+        mod_path: Vec::new(),
         out: Parse::new(),
     };
 
@@ -54,10 +83,212 @@ pub fn parse_src(src_file: &FilePath, config: &Config) -> ParseResult {
         version: None,
     };
 
-    context.parse_mod(&pkg_ref, src_file, 0)?;
+    context.parse_mod(&pkg_ref, src_file, 0, true)?;
     Ok(context.out)
 }
 
+/// Parses all the rust source files found in a directory and its
+/// subdirectories, as filtered by the `include_files` and `exclude_files`
+/// globs of the parse config. Each file is parsed like with `parse_src`, using
+/// its path relative to `src_dir` as module path, and conflicting definitions
+/// across files are reported.
+pub fn parse_src_dir(src_dir: &FilePath, config: &Config) -> ParseResult {
+    let dir_name = src_dir.file_name().unwrap_or_default().to_string_lossy();
+
+    let mut src_files = Vec::new();
+    collect_src_files(src_dir, &mut src_files).map_err(|_| Error::ParseCannotOpenFile {
+        crate_name: dir_name.clone().into(),
+        src_path: src_dir.to_string_lossy().into(),
+    })?;
+    // Parse the files of a directory before its subdirectories, and `mod.rs`
+    // and `lib.rs` first within a directory, so that files declared as a `mod`
+    // are found through their parent module.
+    src_files.sort_by_key(|x| {
+        let stem = x.file_stem().unwrap();
+        (
+            x.parent().map(FilePath::to_path_buf),
+            stem != "mod" && stem != "lib",
+            x.clone(),
+        )
+    });
+
+    let include_files = config.parse.include_files.clone();
+    let exclude_files = config.parse.exclude_files.clone();
+
+    let mut config = config.clone();
+    config.parse = ParseConfig {
+        parse_deps: true,
+        skip_attribute: config.parse.skip_attribute.clone(),
+        ..ParseConfig::default()
+    };
+
+    let mut context = Parser {
+        binding_crate_name: dir_name.clone().into(),
+        config: &config,
+        lib: None,
+        parsed_crates: HashSet::new(),
+        cache_src: HashMap::new(),
+        cache_expanded_crate: HashMap::new(),
+        cfg_stack: Vec::new(),
+        module: None,
+        mod_path: Vec::new(),
+        out: Parse::new(),
+    };
+    let mut result = Parse::new();
+
+    for src_file in src_files {
+        let relative_path = src_file.strip_prefix(src_dir).unwrap();
+        let relative_name = relative_path
+            .to_string_lossy()
+            .replace(std::path::MAIN_SEPARATOR, "/");
+
+        if let Some(ref include_files) = include_files {
+            if !include_files
+                .iter()
+                .any(|x| glob_matches(x, &relative_name))
+            {
+                debug!("Excluding file {}", relative_name);
+                continue;
+            }
+        }
+        if exclude_files
+            .iter()
+            .any(|x| glob_matches(x, &relative_name))
+        {
+            debug!("Excluding file {}", relative_name);
+            continue;
+        }
+
+        // Files declared as a `mod` of a file we already parsed have been
+        // parsed along with it.
+        if context.cache_src.contains_key(&src_file) {
+            continue;
+        }
+
+        let mod_name = module_path(relative_path, &dir_name);
+        let pkg_ref = PackageRef {
+            name: mod_name.clone(),
+            version: None,
+        };
+        context.module = if mod_name == dir_name {
+            None
+        } else {
+            mod_name.split("::").next().map(str::to_owned)
+        };
+        context.mod_path = if mod_name == dir_name {
+            Vec::new()
+        } else {
+            mod_name.split("::").map(str::to_owned).collect()
+        };
+        if (1..=context.mod_path.len()).any(|i| config.export.excludes_path(&context.mod_path[..i]))
+        {
+            debug!("Excluding file {}", relative_name);
+            continue;
+        }
+        context.binding_crate_name = mod_name;
+        context.parse_mod(&pkg_ref, &src_file, 0, true)?;
+
+        let parsed = mem::replace(&mut context.out, Parse::new());
+        result.extend_with_file(&parsed, &src_file);
+        if context.module.is_none() && result.crate_documentation.doc_comment.is_empty() {
+            result.crate_documentation = parsed.crate_documentation;
+        }
+    }
+
+    Ok(result)
+}
+
+fn collect_src_files(dir: &FilePath, out: &mut Vec<FilePathBuf>) -> std::io::Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_src_files(&path, out)?;
+        } else if path.extension() == Some("rs".as_ref()) {
+            out.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Computes the module path of a source file from its path relative to the
+/// source directory, e.g. `ffi/types.rs` and `ffi/types/mod.rs` both give
+/// `ffi::types`. Files at the root named `lib.rs` or `mod.rs` get `root_name`.
+fn module_path(relative_path: &FilePath, root_name: &str) -> String {
+    let mut segments: Vec<_> = relative_path
+        .parent()
+        .into_iter()
+        .flat_map(|x| x.iter())
+        .map(|x| x.to_string_lossy().into_owned())
+        .collect();
+    let stem = relative_path.file_stem().unwrap().to_string_lossy();
+    if stem != "mod" && (stem != "lib" || !segments.is_empty()) {
+        segments.push(stem.into_owned());
+    }
+    if segments.is_empty() {
+        return root_name.to_owned();
+    }
+    segments.join("::")
+}
+
+/// Adds the items of `other` which aren't in `order` after its items, in their
+/// order in `other`.
+pub(crate) fn extend_source_order(order: &mut HashMap<Path, usize>, other: &HashMap<Path, usize>) {
+    let next = order.values().max().map_or(0, |x| x + 1);
+    for (path, index) in other {
+        order.entry(path.clone()).or_insert(next + index);
+    }
+}
+
+/// The path of a `#[path = "..."]` attribute, if any.
+fn path_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| match attr.parse_meta() {
+        Ok(syn::Meta::NameValue(syn::MetaNameValue {
+            ref path,
+            lit: syn::Lit::Str(ref lit),
+            ..
+        })) if path.is_ident("path") => Some(lit.value()),
+        _ => None,
+    })
+}
+
+/// Whether `item` is an `include!(...)` macro.
+fn is_include_macro(item: &syn::Item) -> bool {
+    match *item {
+        syn::Item::Macro(ref item) => item.mac.path.is_ident("include"),
+        _ => false,
+    }
+}
+
+fn is_pub(vis: &syn::Visibility) -> bool {
+    matches!(*vis, syn::Visibility::Public(_))
+}
+
+/// Matches a `/` separated path against a glob pattern. `*` and `?` match any
+/// characters but `/`, while `**` matches across directories.
+fn glob_matches(pattern: &str, path: &str) -> bool {
+    fn matches(pattern: &[u8], path: &[u8]) -> bool {
+        match pattern.split_first() {
+            None => path.is_empty(),
+            Some((b'*', rest)) if rest.first() == Some(&b'*') => match rest[1..].split_first() {
+                // `**/` matches any number of leading directories.
+                Some((b'/', rest)) => (0..=path.len())
+                    .filter(|&i| i == 0 || path[i - 1] == b'/')
+                    .any(|i| matches(rest, &path[i..])),
+                _ => (0..=path.len()).any(|i| matches(&rest[1..], &path[i..])),
+            },
+            Some((b'*', rest)) => (0..=path.len())
+                .take_while(|&i| i == 0 || path[i - 1] != b'/')
+                .any(|i| matches(rest, &path[i..])),
+            Some((b'?', rest)) => match path.split_first() {
+                Some((c, path)) if *c != b'/' => matches(rest, path),
+                _ => false,
+            },
+            Some((c, rest)) => path.first() == Some(c) && matches(rest, &path[1..]),
+        }
+    }
+    matches(pattern.as_bytes(), path.as_bytes())
+}
+
 /// Recursively parses a rust library starting at the root crate's directory.
 ///
 /// Inside a crate, `mod` and `extern crate` declarations are followed
@@ -72,6 +303,8 @@ pub(crate) fn parse_lib(lib: Cargo, config: &Config) -> ParseResult {
         cache_src: HashMap::new(),
         cache_expanded_crate: HashMap::new(),
         cfg_stack: Vec::new(),
+        module: None,
+        mod_path: Vec::new(),
         out: Parse::new(),
     };
 
@@ -92,6 +325,12 @@ struct Parser<'a> {
 
     cfg_stack: Vec<Cfg>,
 
+    /// The top-level module of the binding crate being parsed, if any.
+    module: Option<String>,
+    /// The path of the module being parsed, from the root of its crate, for
+    /// `export.exclude`.
+    mod_path: Vec<String>,
+
     out: Parse,
 }
 
@@ -140,7 +379,7 @@ impl<'a> Parser<'a> {
             let crate_src = self.lib.as_ref().unwrap().find_crate_src(pkg);
 
             match crate_src {
-                Some(crate_src) => self.parse_mod(pkg, crate_src.as_path(), 0)?,
+                Some(crate_src) => self.parse_mod(pkg, crate_src.as_path(), 0, true)?,
                 None => {
                     // This should be an error, but is common enough to just elicit a warning
                     warn!(
@@ -198,83 +437,204 @@ impl<'a> Parser<'a> {
                     src_path: "".to_owned(),
                     error: x,
                 })?;
+                if pkg.name == self.binding_crate_name {
+                    self.out.crate_documentation = Documentation::load_inner(&i.attrs);
+                }
                 self.cache_expanded_crate.insert(pkg.name.clone(), i.items);
             }
 
             self.cache_expanded_crate.get(&pkg.name).unwrap().clone()
         };
 
-        self.process_mod(pkg, None, &mod_items, 0)
+        self.process_mod(pkg, None, None, false, &mod_items, 0)
     }
 
+    /// `is_mod_rs` is whether the modules declared in the file are in its
+    /// directory, as for crate roots, `mod.rs` files and files loaded with
+    /// `#[path]`, rather than in a directory named after the file.
     fn parse_mod(
         &mut self,
         pkg: &PackageRef,
         mod_path: &FilePath,
         depth: usize,
+        is_mod_rs: bool,
     ) -> Result<(), Error> {
-        let mod_items = match self.cache_src.entry(mod_path.to_path_buf()) {
+        let mod_items = self.load_src(pkg, mod_path, depth == 0)?;
+
+        // Compute module directory according to Rust 2018 rules
+        let mod_dir_2018;
+
+        let mod_dir = if is_mod_rs || mod_path.ends_with("mod.rs") {
+            mod_path.parent().unwrap()
+        } else {
+            mod_dir_2018 = mod_path
+                .parent()
+                .unwrap()
+                .join(mod_path.file_stem().unwrap());
+            &mod_dir_2018
+        };
+
+        self.process_mod(
+            pkg,
+            Some(mod_path),
+            Some(&mod_dir),
+            false,
+            &mod_items,
+            depth,
+        )
+    }
+
+    /// Parses the file at `path`, or returns the items parsed from it before.
+    /// The documentation of the crate is loaded from it if `is_crate_root`.
+    fn load_src(
+        &mut self,
+        pkg: &PackageRef,
+        path: &FilePath,
+        is_crate_root: bool,
+    ) -> Result<Vec<syn::Item>, Error> {
+        match self.cache_src.entry(path.to_path_buf()) {
             Entry::Vacant(vacant_entry) => {
                 let mut s = String::new();
-                let mut f = File::open(mod_path).map_err(|_| Error::ParseCannotOpenFile {
+                let mut f = File::open(path).map_err(|_| Error::ParseCannotOpenFile {
                     crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_str().unwrap().to_owned(),
+                    src_path: path.to_str().unwrap().to_owned(),
                 })?;
 
                 f.read_to_string(&mut s)
                     .map_err(|_| Error::ParseCannotOpenFile {
                         crate_name: pkg.name.clone(),
-                        src_path: mod_path.to_str().unwrap().to_owned(),
+                        src_path: path.to_str().unwrap().to_owned(),
                     })?;
 
                 let i = syn::parse_file(&s).map_err(|x| Error::ParseSyntaxError {
                     crate_name: pkg.name.clone(),
-                    src_path: mod_path.to_string_lossy().into(),
+                    src_path: path.to_string_lossy().into(),
                     error: x,
                 })?;
+                if is_crate_root
+                    && pkg.name == self.binding_crate_name
+                    && self.out.crate_documentation.doc_comment.is_empty()
+                {
+                    self.out.crate_documentation = Documentation::load_inner(&i.attrs);
+                }
 
-                vacant_entry.insert(i.items).clone()
+                Ok(vacant_entry.insert(i.items).clone())
             }
-            Entry::Occupied(occupied_entry) => occupied_entry.get().clone(),
-        };
+            Entry::Occupied(occupied_entry) => Ok(occupied_entry.get().clone()),
+        }
+    }
 
-        // Compute module directory according to Rust 2018 rules
-        let mod_dir_2018;
+    /// Replaces the `include!("file.rs")` macros in `items`, from the file at
+    /// `src_path`, by the items of the files they include.
+    fn expand_includes(
+        &mut self,
+        pkg: &PackageRef,
+        src_path: &FilePath,
+        items: &[syn::Item],
+    ) -> Result<Vec<syn::Item>, Error> {
+        let mut expanded = Vec::with_capacity(items.len());
+        for item in items {
+            let item_macro = match *item {
+                syn::Item::Macro(ref item_macro) if is_include_macro(item) => item_macro,
+                _ => {
+                    expanded.push(item.clone());
+                    continue;
+                }
+            };
 
-        let mod_dir = if depth == 0 || mod_path.ends_with("mod.rs") {
-            mod_path.parent().unwrap()
-        } else {
-            mod_dir_2018 = mod_path
-                .parent()
-                .unwrap()
-                .join(mod_path.file_stem().unwrap());
-            &mod_dir_2018
-        };
+            // Like `#[path]`, the path is relative to the current file.
+            let path = match item_macro.mac.parse_body::<syn::LitStr>() {
+                Ok(path) => src_path.parent().unwrap().join(path.value()),
+                Err(_) => {
+                    warn!(
+                        "Parsing crate `{}`: can't resolve `include!` in {}, only string literal paths are supported.",
+                        pkg.name,
+                        src_path.display()
+                    );
+                    continue;
+                }
+            };
+            if !path.exists() {
+                warn!(
+                    "Parsing crate `{}`: can't find included file {}.",
+                    pkg.name,
+                    path.display()
+                );
+                continue;
+            }
 
-        self.process_mod(pkg, Some(&mod_dir), &mod_items, depth)
+            let included = self.load_src(pkg, &path, false)?;
+            expanded.extend(self.expand_includes(pkg, &path, &included)?);
+        }
+        Ok(expanded)
     }
 
-    /// `mod_dir` is the path to the current directory of the module. It may be
-    /// `None` for pre-expanded modules.
+    /// `src_path` is the path to the file containing the items, and `mod_dir`
+    /// is the path to the current directory of the module. Both may be `None`
+    /// for pre-expanded modules. `is_inline` is whether the items are in a
+    /// `mod name { ... }` block rather than at the top level of the file.
     fn process_mod(
         &mut self,
         pkg: &PackageRef,
+        src_path: Option<&FilePath>,
         mod_dir: Option<&FilePath>,
+        is_inline: bool,
         items: &[syn::Item],
         depth: usize,
     ) -> Result<(), Error> {
+        let expanded;
+        let items = match src_path {
+            Some(src_path) if items.iter().any(is_include_macro) => {
+                expanded = self.expand_includes(pkg, src_path, items)?;
+                &expanded[..]
+            }
+            _ => items,
+        };
+
+        // Items excluded by their path, including `crate::Foo` at the root,
+        // are dropped here, since only their names are known after parsing.
+        let kept = items
+            .iter()
+            .filter(|x| !self.is_excluded_item(pkg, x))
+            .cloned()
+            .collect::<Vec<_>>();
+        let items = &kept[..];
+
         // We process the items first then the nested modules.
+        let sizes = self.out.sizes();
         let nested_modules = self.out.load_syn_crate_mod(
             &self.config,
             &self.binding_crate_name,
             &pkg.name,
+            src_path,
             Cfg::join(&self.cfg_stack).as_ref(),
             items,
         );
+        if let Some(ref module) = self.module {
+            self.out.set_module_since(sizes, module);
+        }
 
         for item in nested_modules {
             let next_mod_name = item.ident.to_string();
 
+            self.mod_path.push(next_mod_name.clone());
+            if self.config.export.excludes_path(&self.mod_path) {
+                info!(
+                    "Skip {}::{} - (excluded).",
+                    pkg.name,
+                    self.mod_path.join("::")
+                );
+                self.mod_path.pop();
+                continue;
+            }
+
+            // Keep track of the top-level module of the binding crate the
+            // items are in, to be able to split the bindings by module.
+            let is_top_level = self.module.is_none() && pkg.name == self.binding_crate_name;
+            if is_top_level {
+                self.module = Some(next_mod_name.clone());
+            }
+
             let cfg = Cfg::load(&item.attrs);
             if let Some(ref cfg) = cfg {
                 self.cfg_stack.push(cfg.clone());
@@ -282,45 +642,46 @@ impl<'a> Parser<'a> {
 
             if let Some((_, ref inline_items)) = item.content {
                 let next_mod_dir = mod_dir.map(|dir| dir.join(&next_mod_name));
-                self.process_mod(pkg, next_mod_dir.as_deref(), inline_items, depth)?;
+                self.process_mod(
+                    pkg,
+                    src_path,
+                    next_mod_dir.as_deref(),
+                    true,
+                    inline_items,
+                    depth,
+                )?;
             } else if let Some(mod_dir) = mod_dir {
                 let next_mod_path1 = mod_dir.join(next_mod_name.clone() + ".rs");
                 let next_mod_path2 = mod_dir.join(next_mod_name.clone()).join("mod.rs");
 
-                if next_mod_path1.exists() {
-                    self.parse_mod(pkg, next_mod_path1.as_path(), depth + 1)?;
+                if let Some(path) = path_attr(&item.attrs) {
+                    // `#[path]` is relative to the directory of the current
+                    // file, or to the module directory in inline modules.
+                    let next_mod_path = match src_path.and_then(FilePath::parent) {
+                        Some(dir) if !is_inline => dir.join(path),
+                        _ => mod_dir.join(path),
+                    };
+                    if next_mod_path.exists() {
+                        self.parse_mod(pkg, &next_mod_path, depth + 1, true)?;
+                    } else {
+                        warn!(
+                            "Parsing crate `{}`: can't find mod {} at {}.",
+                            pkg.name,
+                            next_mod_name,
+                            next_mod_path.display()
+                        );
+                    }
+                } else if next_mod_path1.exists() {
+                    self.parse_mod(pkg, next_mod_path1.as_path(), depth + 1, false)?;
                 } else if next_mod_path2.exists() {
-                    self.parse_mod(pkg, next_mod_path2.as_path(), depth + 1)?;
+                    self.parse_mod(pkg, next_mod_path2.as_path(), depth + 1, true)?;
                 } else {
-                    // Last chance to find a module path
-                    let mut path_attr_found = false;
-                    for attr in &item.attrs {
-                        if let Ok(syn::Meta::NameValue(syn::MetaNameValue { path, lit, .. })) =
-                            attr.parse_meta()
-                        {
-                            match lit {
-                                syn::Lit::Str(ref path_lit) if path.is_ident("path") => {
-                                    path_attr_found = true;
-                                    self.parse_mod(
-                                        pkg,
-                                        &mod_dir.join(path_lit.value()),
-                                        depth + 1,
-                                    )?;
-                                    break;
-                                }
-                                _ => (),
-                            }
-                        }
-                    }
-
                     // This should be an error, but it's common enough to
                     // just elicit a warning
-                    if !path_attr_found {
-                        warn!(
-                            "Parsing crate `{}`: can't find mod {}`.",
-                            pkg.name, next_mod_name
-                        );
-                    }
+                    warn!(
+                        "Parsing crate `{}`: can't find mod {}`.",
+                        pkg.name, next_mod_name
+                    );
                 }
             } else {
                 warn!(
@@ -332,10 +693,35 @@ impl<'a> Parser<'a> {
             if cfg.is_some() {
                 self.cfg_stack.pop();
             }
+            if is_top_level {
+                self.module = None;
+            }
+            self.mod_path.pop();
         }
 
         Ok(())
     }
+
+    /// Whether `export.exclude` has the path of `item` in the current module.
+    fn is_excluded_item(&self, pkg: &PackageRef, item: &syn::Item) -> bool {
+        let ident = match *item {
+            syn::Item::Const(ref item) => &item.ident,
+            syn::Item::Enum(ref item) => &item.ident,
+            syn::Item::Fn(ref item) => &item.sig.ident,
+            syn::Item::Static(ref item) => &item.ident,
+            syn::Item::Struct(ref item) => &item.ident,
+            syn::Item::Type(ref item) => &item.ident,
+            syn::Item::Union(ref item) => &item.ident,
+            _ => return false,
+        };
+        let mut path = self.mod_path.clone();
+        path.push(ident.to_string());
+        if !self.config.export.excludes_path(&path) {
+            return false;
+        }
+        info!("Skip {}::{} - (excluded).", pkg.name, path.join("::"));
+        true
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -348,6 +734,43 @@ pub struct Parse {
     pub opaque_items: ItemMap<OpaqueItem>,
     pub typedefs: ItemMap<Typedef>,
     pub functions: Vec<Function>,
+    /// The top-level module of the binding crate each item comes from. Items
+    /// at the root of the crate or from other crates aren't in the map.
+    pub modules: HashMap<Path, String>,
+    /// The order in which each item was first seen while parsing, for
+    /// `export.sort_by = "None"`.
+    pub source_order: HashMap<Path, usize>,
+    /// The public items of the binding crate which were skipped or made
+    /// opaque, for `strict` mode.
+    pub skipped: Vec<SkippedItem>,
+    /// The `//!` documentation of the root of the binding crate.
+    pub crate_documentation: Documentation,
+}
+
+/// A public item which couldn't be exported as is.
+#[derive(Debug, Clone)]
+pub struct SkippedItem {
+    /// The name of the item, as used in `[export] exclude`.
+    pub name: String,
+    /// The kind of the item in Rust.
+    pub kind: ItemType,
+    /// Whether the item was made opaque, rather than skipped.
+    pub made_opaque: bool,
+    /// Where and why the item was skipped, for diagnostics.
+    pub description: String,
+}
+
+/// The number of items of each kind in a `Parse`.
+#[derive(Clone, Copy)]
+struct ParseSizes {
+    constants: usize,
+    globals: usize,
+    enums: usize,
+    structs: usize,
+    unions: usize,
+    opaque_items: usize,
+    typedefs: usize,
+    functions: usize,
 }
 
 impl Parse {
@@ -361,6 +784,55 @@ impl Parse {
             opaque_items: ItemMap::default(),
             typedefs: ItemMap::default(),
             functions: Vec::new(),
+            modules: HashMap::new(),
+            source_order: HashMap::new(),
+            skipped: Vec::new(),
+            crate_documentation: Documentation::none(),
+        }
+    }
+
+    fn sizes(&self) -> ParseSizes {
+        ParseSizes {
+            constants: self.constants.len(),
+            globals: self.globals.len(),
+            enums: self.enums.len(),
+            structs: self.structs.len(),
+            unions: self.unions.len(),
+            opaque_items: self.opaque_items.len(),
+            typedefs: self.typedefs.len(),
+            functions: self.functions.len(),
+        }
+    }
+
+    /// The paths of the items added since `sizes` was taken.
+    fn paths_since(&self, sizes: ParseSizes) -> Vec<Path> {
+        self.constants
+            .paths()
+            .skip(sizes.constants)
+            .chain(self.globals.paths().skip(sizes.globals))
+            .chain(self.enums.paths().skip(sizes.enums))
+            .chain(self.structs.paths().skip(sizes.structs))
+            .chain(self.unions.paths().skip(sizes.unions))
+            .chain(self.opaque_items.paths().skip(sizes.opaque_items))
+            .chain(self.typedefs.paths().skip(sizes.typedefs))
+            .chain(self.functions[sizes.functions..].iter().map(|x| &x.path))
+            .cloned()
+            .collect()
+    }
+
+    /// Records that the items added since `sizes` was taken come from `module`.
+    fn set_module_since(&mut self, sizes: ParseSizes, module: &str) {
+        for path in self.paths_since(sizes) {
+            self.modules.insert(path, module.to_owned());
+        }
+    }
+
+    /// Records that the items added since `sizes` was taken are the next ones
+    /// in source order, unless they were seen before.
+    fn set_source_order_since(&mut self, sizes: ParseSizes) {
+        for path in self.paths_since(sizes) {
+            let next = self.source_order.len();
+            self.source_order.entry(path).or_insert(next);
         }
     }
 
@@ -370,7 +842,7 @@ impl Parse {
             let generic_params: Vec<_> = generic_params.into_iter().map(Path::new).collect();
             self.opaque_items.try_insert(OpaqueItem::new(
                 path,
-                GenericParams(generic_params),
+                GenericParams::with_type_params(generic_params),
                 None,
                 AnnotationSet::new(),
                 Documentation::none(),
@@ -405,6 +877,101 @@ impl Parse {
         self.opaque_items.extend_with(&other.opaque_items);
         self.typedefs.extend_with(&other.typedefs);
         self.functions.extend_from_slice(&other.functions);
+        self.extend_modules_with(other);
+        self.skipped.extend_from_slice(&other.skipped);
+        if self.crate_documentation.doc_comment.is_empty() {
+            self.crate_documentation = other.crate_documentation.clone();
+        }
+    }
+
+    fn extend_modules_with(&mut self, other: &Parse) {
+        for (path, module) in &other.modules {
+            self.modules
+                .entry(path.clone())
+                .or_insert_with(|| module.clone());
+        }
+        extend_source_order(&mut self.source_order, &other.source_order);
+    }
+
+    /// Like `extend_with`, but reports items of `other`, parsed from
+    /// `src_path`, which conflict with items that are already present.
+    fn extend_with_file(&mut self, other: &Parse, src_path: &FilePath) {
+        fn extend<T: Item + Clone>(items: &mut ItemMap<T>, other: &ItemMap<T>, src: &FilePath) {
+            other.for_all_items(|item| {
+                if !items.try_insert(item.clone()) {
+                    error!(
+                        "Conflicting definition of {} in {}, ignoring it.",
+                        item.path(),
+                        src.display()
+                    );
+                }
+            });
+        }
+
+        extend(&mut self.constants, &other.constants, src_path);
+        extend(&mut self.globals, &other.globals, src_path);
+        extend(&mut self.enums, &other.enums, src_path);
+        extend(&mut self.structs, &other.structs, src_path);
+        extend(&mut self.unions, &other.unions, src_path);
+        extend(&mut self.opaque_items, &other.opaque_items, src_path);
+        extend(&mut self.typedefs, &other.typedefs, src_path);
+
+        for function in &other.functions {
+            let conflicts = function.cfg.is_none()
+                && self
+                    .functions
+                    .iter()
+                    .any(|x| x.path == function.path && x.cfg.is_none());
+            if conflicts {
+                error!(
+                    "Conflicting definition of fn {} in {}, ignoring it.",
+                    function.path,
+                    src_path.display()
+                );
+                continue;
+            }
+            self.functions.push(function.clone());
+        }
+        self.extend_modules_with(other);
+        self.skipped.extend_from_slice(&other.skipped);
+    }
+
+    fn add_skipped(
+        &mut self,
+        name: &syn::Ident,
+        src_path: Option<&FilePath>,
+        kind: ItemType,
+        reason: String,
+    ) {
+        let reason = format!("skipped ({})", reason);
+        self.push_skipped(name, src_path, kind, false, reason);
+    }
+
+    fn add_made_opaque(
+        &mut self,
+        name: &syn::Ident,
+        src_path: Option<&FilePath>,
+        kind: ItemType,
+        reason: String,
+    ) {
+        let reason = format!("made opaque ({})", reason);
+        self.push_skipped(name, src_path, kind, true, reason);
+    }
+
+    fn push_skipped(
+        &mut self,
+        name: &syn::Ident,
+        src_path: Option<&FilePath>,
+        kind: ItemType,
+        made_opaque: bool,
+        reason: String,
+    ) {
+        self.skipped.push(SkippedItem {
+            name: name.to_string(),
+            kind,
+            made_opaque,
+            description: format!("{}{} - {}", name, location(src_path, name.span()), reason),
+        });
     }
 
     fn load_syn_crate_mod<'a>(
@@ -412,6 +979,7 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         items: &'a [syn::Item],
     ) -> Vec<&'a syn::ItemMod> {
@@ -419,39 +987,92 @@ impl Parse {
         let mut nested_modules = Vec::new();
 
         for item in items {
+            let sizes = self.sizes();
             if item.should_skip_parsing() {
                 continue;
             }
+            if config.parse.should_skip_item(item) {
+                info!(
+                    "Skip {}{} - (marked with `#[{}]`).",
+                    crate_name,
+                    log_location(src_path, item.span()),
+                    config.parse.skip_attribute.as_ref().unwrap()
+                );
+                continue;
+            }
             match item {
                 syn::Item::ForeignMod(ref item) => {
                     self.load_syn_foreign_mod(
                         config,
                         binding_crate_name,
                         crate_name,
+                        src_path,
                         mod_cfg,
                         item,
                     );
                 }
                 syn::Item::Fn(ref item) => {
-                    self.load_syn_fn(config, binding_crate_name, crate_name, mod_cfg, item);
+                    self.load_syn_fn(
+                        config,
+                        binding_crate_name,
+                        crate_name,
+                        src_path,
+                        mod_cfg,
+                        item,
+                    );
                 }
                 syn::Item::Const(ref item) => {
-                    self.load_syn_const(config, binding_crate_name, crate_name, mod_cfg, item);
+                    self.load_syn_const(
+                        config,
+                        binding_crate_name,
+                        crate_name,
+                        src_path,
+                        mod_cfg,
+                        item,
+                    );
                 }
                 syn::Item::Static(ref item) => {
-                    self.load_syn_static(config, binding_crate_name, crate_name, mod_cfg, item);
+                    self.load_syn_static(
+                        config,
+                        binding_crate_name,
+                        crate_name,
+                        src_path,
+                        mod_cfg,
+                        item,
+                    );
                 }
                 syn::Item::Struct(ref item) => {
-                    self.load_syn_struct(config, crate_name, mod_cfg, item);
+                    self.load_syn_struct(
+                        config,
+                        binding_crate_name,
+                        crate_name,
+                        src_path,
+                        mod_cfg,
+                        item,
+                    );
                 }
                 syn::Item::Union(ref item) => {
-                    self.load_syn_union(config, crate_name, mod_cfg, item);
+                    self.load_syn_union(
+                        config,
+                        binding_crate_name,
+                        crate_name,
+                        src_path,
+                        mod_cfg,
+                        item,
+                    );
                 }
                 syn::Item::Enum(ref item) => {
-                    self.load_syn_enum(config, crate_name, mod_cfg, item);
+                    self.load_syn_enum(
+                        config,
+                        binding_crate_name,
+                        crate_name,
+                        src_path,
+                        mod_cfg,
+                        item,
+                    );
                 }
                 syn::Item::Type(ref item) => {
-                    self.load_syn_ty(crate_name, mod_cfg, item);
+                    self.load_syn_ty(binding_crate_name, crate_name, src_path, mod_cfg, item);
                 }
                 syn::Item::Impl(ref item_impl) => {
                     let has_assoc_const = item_impl.items.iter().any(|item| match item {
@@ -465,13 +1086,18 @@ impl Parse {
                     if let syn::Type::Path(ref path) = *item_impl.self_ty {
                         if let Some(type_name) = path.path.get_ident() {
                             for method in item_impl.items.iter().filter_map(|item| match item {
-                                syn::ImplItem::Method(method) => Some(method),
+                                syn::ImplItem::Method(method)
+                                    if !config.parse.should_skip_item(method) =>
+                                {
+                                    Some(method)
+                                }
                                 _ => None,
                             }) {
                                 self.load_syn_method(
                                     config,
                                     binding_crate_name,
                                     crate_name,
+                                    src_path,
                                     mod_cfg,
                                     &Path::new(type_name.to_string()),
                                     method,
@@ -480,26 +1106,42 @@ impl Parse {
                         }
                     }
                 }
-                syn::Item::Macro(ref item) => {
-                    self.load_builtin_macro(config, crate_name, mod_cfg, item)
-                }
+                syn::Item::Macro(ref item) => self.load_builtin_macro(
+                    config,
+                    binding_crate_name,
+                    crate_name,
+                    src_path,
+                    mod_cfg,
+                    item,
+                ),
                 syn::Item::Mod(ref item) => {
                     nested_modules.push(item);
                 }
                 _ => {}
             }
+            self.set_source_order_since(sizes);
         }
 
+        let sizes = self.sizes();
         for item_impl in impls_with_assoc_consts {
-            self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, item_impl)
+            self.load_syn_assoc_consts_from_impl(
+                binding_crate_name,
+                crate_name,
+                src_path,
+                mod_cfg,
+                item_impl,
+            )
         }
+        self.set_source_order_since(sizes);
 
         nested_modules
     }
 
     fn load_syn_assoc_consts_from_impl(
         &mut self,
+        binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item_impl: &syn::ItemImpl,
     ) {
@@ -508,7 +1150,9 @@ impl Parse {
             _ => None,
         });
         self.load_syn_assoc_consts(
+            binding_crate_name,
             crate_name,
+            src_path,
             mod_cfg,
             &item_impl.self_ty,
             associated_constants,
@@ -521,11 +1165,16 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemForeignMod,
     ) {
         if !item.abi.is_c() {
-            info!("Skip {} - (extern block must be extern C).", crate_name);
+            info!(
+                "Skip {}{} - (extern block must be extern C).",
+                crate_name,
+                log_location(src_path, item.abi.extern_token.span)
+            );
             return;
         }
 
@@ -536,23 +1185,42 @@ impl Parse {
                     .should_generate_top_level_item(crate_name, binding_crate_name)
                 {
                     info!(
-                        "Skip {}::{} - (fn's outside of the binding crate are not used).",
-                        crate_name, &function.sig.ident
+                        "Skip {}::{}{} - (fn's outside of the binding crate are not used).",
+                        crate_name,
+                        &function.sig.ident,
+                        log_location(src_path, function.sig.ident.span())
                     );
                     return;
                 }
                 let path = Path::new(function.sig.ident.to_string());
-                match Function::load(path, None, &function.sig, true, &function.attrs, mod_cfg) {
+                match Function::load(
+                    path,
+                    None,
+                    &function.sig,
+                    true,
+                    &function.attrs,
+                    mod_cfg,
+                    config,
+                ) {
                     Ok(func) => {
-                        info!("Take {}::{}.", crate_name, &function.sig.ident);
+                        info!(
+                            "Take {}::{}{}.",
+                            crate_name,
+                            &function.sig.ident,
+                            log_location(src_path, function.sig.ident.span())
+                        );
 
                         self.functions.push(func);
                     }
                     Err(msg) => {
                         error!(
-                            "Cannot use fn {}::{} ({}).",
-                            crate_name, &function.sig.ident, msg
+                            "Cannot use fn {}::{}{} ({}).",
+                            crate_name,
+                            &function.sig.ident,
+                            location(src_path, function.sig.ident.span()),
+                            msg
                         );
+                        self.add_skipped(&function.sig.ident, src_path, ItemType::Functions, msg);
                     }
                 }
             }
@@ -560,11 +1228,13 @@ impl Parse {
     }
 
     /// Loads a `fn` declaration inside an `impl` block, if the type is a simple identifier
+    #[allow(clippy::too_many_arguments)]
     fn load_syn_method(
         &mut self,
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         self_type: &Path,
         item: &syn::ImplItemMethod,
@@ -573,6 +1243,7 @@ impl Parse {
             config,
             binding_crate_name,
             crate_name,
+            src_path,
             mod_cfg,
             item,
             Some(self_type),
@@ -588,6 +1259,7 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemFn,
     ) {
@@ -595,6 +1267,7 @@ impl Parse {
             config,
             binding_crate_name,
             crate_name,
+            src_path,
             mod_cfg,
             item,
             None,
@@ -610,6 +1283,7 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         named_symbol: &dyn SynItemFnHelpers,
         self_type: Option<&Path>,
@@ -622,8 +1296,10 @@ impl Parse {
             .should_generate_top_level_item(crate_name, binding_crate_name)
         {
             info!(
-                "Skip {}::{} - (fn's outside of the binding crate are not used).",
-                crate_name, &sig.ident
+                "Skip {}::{}{} - (fn's outside of the binding crate are not used).",
+                crate_name,
+                &sig.ident,
+                log_location(src_path, sig.ident.span())
             );
             return;
         }
@@ -637,32 +1313,60 @@ impl Parse {
             items.push(sig.ident.to_string());
             items.join("::")
         };
+        let item_location = || location(src_path, sig.ident.span());
 
-        let is_extern_c = sig.abi.is_omitted() || sig.abi.is_c();
-        let exported_name = named_symbol.exported_name();
+        // A function returning a `Result` is declared as the `extern "C"`
+        // wrapper the crate exports for it, if it names one.
+        let result_wrapper = AnnotationSet::load(attrs)
+            .ok()
+            .and_then(|x| x.atom("result-wrapper"))
+            .flatten();
+        let is_extern_c = result_wrapper.is_some() || sig.abi.is_omitted() || sig.abi.is_c();
+        let exported_name = result_wrapper.or_else(|| named_symbol.exported_name());
 
         if let syn::Visibility::Public(_) = vis {
             match (is_extern_c, exported_name) {
                 (true, Some(exported_name)) => {
                     let path = Path::new(exported_name);
-                    match Function::load(path, self_type, &sig, false, &attrs, mod_cfg) {
+                    match Function::load(path, self_type, &sig, false, &attrs, mod_cfg, config) {
                         Ok(func) => {
-                            info!("Take {}.", loggable_item_name());
+                            info!(
+                                "Take {}{}.",
+                                loggable_item_name(),
+                                log_location(src_path, sig.ident.span())
+                            );
                             self.functions.push(func);
                         }
                         Err(msg) => {
-                            error!("Cannot use fn {} ({}).", loggable_item_name(), msg);
+                            error!(
+                                "Cannot use fn {}{} ({}).",
+                                loggable_item_name(),
+                                item_location(),
+                                msg
+                            );
+                            self.add_skipped(&sig.ident, src_path, ItemType::Functions, msg);
                         }
                     }
                 }
                 (true, None) => {
                     warn!(
-                        "Skipping {} - (not `no_mangle`, and has no `export_name` attribute)",
-                        loggable_item_name()
+                        "Skipping {}{} - (not `no_mangle`, and has no `export_name` attribute)",
+                        loggable_item_name(),
+                        item_location()
                     );
                 }
                 (false, Some(_exported_name)) => {
-                    warn!("Skipping {} - (not `extern \"C\"`", loggable_item_name());
+                    warn!(
+                        "Skipping {}{} - (not `extern \"C\"`",
+                        loggable_item_name(),
+                        item_location()
+                    );
+                    self.add_skipped(
+                        &sig.ident,
+                        src_path,
+                        ItemType::Functions,
+                        "not `extern \"C\"`".to_owned(),
+                    );
                 }
                 (false, None) => {}
             }
@@ -670,20 +1374,23 @@ impl Parse {
             match (is_extern_c, exported_name) {
                 (true, Some(..)) => {
                     warn!(
-                        "Skipping {} - (not `pub` but is `extern \"C\"` and `no_mangle`)",
-                        loggable_item_name()
+                        "Skipping {}{} - (not `pub` but is `extern \"C\"` and `no_mangle`)",
+                        loggable_item_name(),
+                        item_location()
                     );
                 }
                 (true, None) => {
                     warn!(
-                        "Skipping {} - (not `pub` but is `extern \"C\"`)",
-                        loggable_item_name()
+                        "Skipping {}{} - (not `pub` but is `extern \"C\"`)",
+                        loggable_item_name(),
+                        item_location()
                     );
                 }
                 (false, Some(..)) => {
                     warn!(
-                        "Skipping {} - (not `pub` but is `no_mangle`)",
-                        loggable_item_name()
+                        "Skipping {}{} - (not `pub` but is `no_mangle`)",
+                        loggable_item_name(),
+                        item_location()
                     );
                 }
                 (false, None) => {}
@@ -694,7 +1401,9 @@ impl Parse {
     /// Loads associated `const` declarations
     fn load_syn_assoc_consts<'a, I>(
         &mut self,
+        binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         impl_ty: &syn::Type,
         items: I,
@@ -728,7 +1437,12 @@ impl Parse {
         for item in items.into_iter() {
             if let syn::Visibility::Public(_) = item.vis {
             } else {
-                warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+                warn!(
+                    "Skip {}::{}{} - (not `pub`).",
+                    crate_name,
+                    &item.ident,
+                    location(src_path, item.ident.span())
+                );
                 return;
             }
 
@@ -742,7 +1456,13 @@ impl Parse {
                 Some(impl_path.clone()),
             ) {
                 Ok(constant) => {
-                    info!("Take {}::{}::{}.", crate_name, impl_path, &item.ident);
+                    info!(
+                        "Take {}::{}::{}{}.",
+                        crate_name,
+                        impl_path,
+                        &item.ident,
+                        log_location(src_path, item.ident.span())
+                    );
                     let mut any = false;
                     self.structs.for_items_mut(&impl_path, |item| {
                         any = true;
@@ -752,13 +1472,25 @@ impl Parse {
                     // not structs like enums or such as regular constants.
                     if !any && !self.constants.try_insert(constant) {
                         error!(
-                            "Conflicting name for constant {}::{}::{}.",
-                            crate_name, impl_path, &item.ident,
+                            "Conflicting name for constant {}::{}::{}{}.",
+                            crate_name,
+                            impl_path,
+                            &item.ident,
+                            location(src_path, item.ident.span()),
                         );
                     }
                 }
                 Err(msg) => {
-                    warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                    warn!(
+                        "Skip {}::{}{} - ({})",
+                        crate_name,
+                        &item.ident,
+                        location(src_path, item.ident.span()),
+                        msg
+                    );
+                    if crate_name == binding_crate_name {
+                        self.add_skipped(&item.ident, src_path, ItemType::Constants, msg);
+                    }
                 }
             }
         }
@@ -770,6 +1502,7 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemConst,
     ) {
@@ -778,22 +1511,34 @@ impl Parse {
             .should_generate_top_level_item(crate_name, binding_crate_name)
         {
             info!(
-                "Skip {}::{} - (const's outside of the binding crate are not used).",
-                crate_name, &item.ident
+                "Skip {}::{}{} - (const's outside of the binding crate are not used).",
+                crate_name,
+                &item.ident,
+                log_location(src_path, item.ident.span())
             );
             return;
         }
 
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            warn!(
+                "Skip {}::{}{} - (not `pub`).",
+                crate_name,
+                &item.ident,
+                location(src_path, item.ident.span())
+            );
             return;
         }
 
         let path = Path::new(item.ident.to_string());
         match Constant::load(path, mod_cfg, &item.ty, &item.expr, &item.attrs, None) {
             Ok(constant) => {
-                info!("Take {}::{}.", crate_name, &item.ident);
+                info!(
+                    "Take {}::{}{}.",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span())
+                );
 
                 let full_name = constant.path.clone();
                 if !self.constants.try_insert(constant) {
@@ -801,7 +1546,14 @@ impl Parse {
                 }
             }
             Err(msg) => {
-                warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                warn!(
+                    "Skip {}::{}{} - ({})",
+                    crate_name,
+                    &item.ident,
+                    location(src_path, item.ident.span()),
+                    msg
+                );
+                self.add_skipped(&item.ident, src_path, ItemType::Constants, msg);
             }
         }
     }
@@ -812,6 +1564,7 @@ impl Parse {
         config: &Config,
         binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemStatic,
     ) {
@@ -820,22 +1573,40 @@ impl Parse {
             .should_generate_top_level_item(crate_name, binding_crate_name)
         {
             info!(
-                "Skip {}::{} - (static's outside of the binding crate are not used).",
-                crate_name, &item.ident
+                "Skip {}::{}{} - (static's outside of the binding crate are not used).",
+                crate_name,
+                &item.ident,
+                log_location(src_path, item.ident.span())
             );
             return;
         }
 
+        // `#[used]` only keeps the static in the object file, so it doesn't
+        // affect whether or how it's exported.
+        let exported_name = item.exported_name();
+
         if let syn::Visibility::Public(_) = item.vis {
-            if item.is_no_mangle() {
-                match Static::load(item, mod_cfg) {
+            if let Some(ref exported_name) = exported_name {
+                match Static::load(Path::new(exported_name.clone()), item, mod_cfg) {
                     Ok(constant) => {
-                        info!("Take {}::{}.", crate_name, &item.ident);
+                        info!(
+                            "Take {}::{}{}.",
+                            crate_name,
+                            &item.ident,
+                            log_location(src_path, item.ident.span())
+                        );
 
                         self.globals.try_insert(constant);
                     }
                     Err(msg) => {
-                        warn!("Skip {}::{} - ({})", crate_name, &item.ident, msg);
+                        warn!(
+                            "Skip {}::{}{} - ({})",
+                            crate_name,
+                            &item.ident,
+                            location(src_path, item.ident.span()),
+                            msg
+                        );
+                        self.add_skipped(&item.ident, src_path, ItemType::Globals, msg);
                     }
                 }
             }
@@ -844,10 +1615,20 @@ impl Parse {
         // TODO
         if let syn::Visibility::Public(_) = item.vis {
         } else {
-            warn!("Skip {}::{} - (not `pub`).", crate_name, &item.ident);
+            warn!(
+                "Skip {}::{}{} - (not `pub`).",
+                crate_name,
+                &item.ident,
+                location(src_path, item.ident.span())
+            );
         }
-        if !item.is_no_mangle() {
-            warn!("Skip {}::{} - (not `no_mangle`).", crate_name, &item.ident);
+        if exported_name.is_none() {
+            warn!(
+                "Skip {}::{}{} - (not `no_mangle`, and has no `export_name` attribute).",
+                crate_name,
+                &item.ident,
+                location(src_path, item.ident.span())
+            );
         }
     }
 
@@ -855,17 +1636,33 @@ impl Parse {
     fn load_syn_struct(
         &mut self,
         config: &Config,
+        binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemStruct,
     ) {
-        match Struct::load(&config.layout, item, mod_cfg) {
+        match Struct::load(config, item, mod_cfg) {
             Ok(st) => {
-                info!("Take {}::{}.", crate_name, &item.ident);
+                info!(
+                    "Take {}::{}{}.",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span())
+                );
                 self.structs.try_insert(st);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                info!(
+                    "Take {}::{}{} - opaque ({}).",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span()),
+                    msg
+                );
+                if is_pub(&item.vis) && crate_name == binding_crate_name {
+                    self.add_made_opaque(&item.ident, src_path, ItemType::Structs, msg);
+                }
                 let path = Path::new(item.ident.to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -878,18 +1675,34 @@ impl Parse {
     fn load_syn_union(
         &mut self,
         config: &Config,
+        binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemUnion,
     ) {
         match Union::load(&config.layout, item, mod_cfg) {
             Ok(st) => {
-                info!("Take {}::{}.", crate_name, &item.ident);
+                info!(
+                    "Take {}::{}{}.",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span())
+                );
 
                 self.unions.try_insert(st);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                info!(
+                    "Take {}::{}{} - opaque ({}).",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span()),
+                    msg
+                );
+                if is_pub(&item.vis) && crate_name == binding_crate_name {
+                    self.add_made_opaque(&item.ident, src_path, ItemType::Unions, msg);
+                }
                 let path = Path::new(item.ident.to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -902,25 +1715,35 @@ impl Parse {
     fn load_syn_enum(
         &mut self,
         config: &Config,
+        binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemEnum,
     ) {
-        if item.generics.lifetimes().count() > 0 {
-            info!(
-                "Skip {}::{} - (has generics or lifetimes or where bounds).",
-                crate_name, &item.ident
-            );
-            return;
-        }
-
+        // Lifetimes are erased, like in structs, so only type parameters
+        // make the enum generic.
         match Enum::load(item, mod_cfg, config) {
             Ok(en) => {
-                info!("Take {}::{}.", crate_name, &item.ident);
+                info!(
+                    "Take {}::{}{}.",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span())
+                );
                 self.enums.try_insert(en);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                info!(
+                    "Take {}::{}{} - opaque ({}).",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span()),
+                    msg
+                );
+                if is_pub(&item.vis) && crate_name == binding_crate_name {
+                    self.add_made_opaque(&item.ident, src_path, ItemType::Enums, msg);
+                }
                 let path = Path::new(item.ident.to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -930,15 +1753,36 @@ impl Parse {
     }
 
     /// Loads a `type` declaration
-    fn load_syn_ty(&mut self, crate_name: &str, mod_cfg: Option<&Cfg>, item: &syn::ItemType) {
+    fn load_syn_ty(
+        &mut self,
+        binding_crate_name: &str,
+        crate_name: &str,
+        src_path: Option<&FilePath>,
+        mod_cfg: Option<&Cfg>,
+        item: &syn::ItemType,
+    ) {
         match Typedef::load(item, mod_cfg) {
             Ok(st) => {
-                info!("Take {}::{}.", crate_name, &item.ident);
+                info!(
+                    "Take {}::{}{}.",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span())
+                );
 
                 self.typedefs.try_insert(st);
             }
             Err(msg) => {
-                info!("Take {}::{} - opaque ({}).", crate_name, &item.ident, msg);
+                info!(
+                    "Take {}::{}{} - opaque ({}).",
+                    crate_name,
+                    &item.ident,
+                    log_location(src_path, item.ident.span()),
+                    msg
+                );
+                if is_pub(&item.vis) && crate_name == binding_crate_name {
+                    self.add_made_opaque(&item.ident, src_path, ItemType::Typedefs, msg);
+                }
                 let path = Path::new(item.ident.to_string());
                 self.opaque_items.try_insert(
                     OpaqueItem::load(path, &item.generics, &item.attrs, mod_cfg).unwrap(),
@@ -950,7 +1794,9 @@ impl Parse {
     fn load_builtin_macro(
         &mut self,
         config: &Config,
+        binding_crate_name: &str,
         crate_name: &str,
+        src_path: Option<&FilePath>,
         mod_cfg: Option<&Cfg>,
         item: &syn::ItemMacro,
     ) {
@@ -972,10 +1818,23 @@ impl Parse {
         };
 
         let (struct_, impl_) = bitflags.expand();
-        self.load_syn_struct(config, crate_name, mod_cfg, &struct_);
+        self.load_syn_struct(
+            config,
+            binding_crate_name,
+            crate_name,
+            src_path,
+            mod_cfg,
+            &struct_,
+        );
         // We know that the expansion will only reference `struct_`, so it's
         // fine to just do it here instead of deferring it like we do with the
         // other calls to this function.
-        self.load_syn_assoc_consts_from_impl(crate_name, mod_cfg, &impl_);
+        self.load_syn_assoc_consts_from_impl(
+            binding_crate_name,
+            crate_name,
+            src_path,
+            mod_cfg,
+            &impl_,
+        );
     }
 }
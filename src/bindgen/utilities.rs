@@ -60,6 +60,20 @@ impl SynItemFnHelpers for syn::ImplItemMethod {
     }
 }
 
+impl SynItemFnHelpers for syn::ItemStatic {
+    fn exported_name(&self) -> Option<String> {
+        self.attrs
+            .attr_name_value_lookup("export_name")
+            .or_else(|| {
+                if self.is_no_mangle() {
+                    Some(self.ident.to_string())
+                } else {
+                    None
+                }
+            })
+    }
+}
+
 /// Returns whether this attribute causes us to skip at item. This basically
 /// checks for `#[cfg(test)]`, `#[test]`, `/// cbindgen::ignore` and
 /// variations thereof.
@@ -94,6 +108,33 @@ fn is_skip_item_attr(attr: &syn::Meta) -> bool {
     }
 }
 
+fn path_to_string(path: &syn::Path) -> String {
+    let segments: Vec<_> = path.segments.iter().map(|x| x.ident.to_string()).collect();
+    segments.join("::")
+}
+
+fn meta_has_path(meta: &syn::Meta, path: &str) -> bool {
+    if path_to_string(meta.path()) == path {
+        return true;
+    }
+    let list = match *meta {
+        syn::Meta::List(ref list) => list,
+        _ => return false,
+    };
+    // The first item of a `cfg_attr` is its predicate.
+    let skip = if list.path.is_ident("allow") {
+        0
+    } else if list.path.is_ident("cfg_attr") {
+        1
+    } else {
+        return false;
+    };
+    list.nested.iter().skip(skip).any(|nested| match *nested {
+        syn::NestedMeta::Meta(ref meta) => meta_has_path(meta, path),
+        syn::NestedMeta::Lit(..) => false,
+    })
+}
+
 pub trait SynAttributeHelpers {
     /// Returns the list of attributes for an item.
     fn attrs(&self) -> &[syn::Attribute];
@@ -118,6 +159,27 @@ pub trait SynAttributeHelpers {
         self.has_attr_word("no_mangle")
     }
 
+    /// Whether the item is hinted to be inlined, with `#[inline]` or
+    /// `#[inline(always)]`.
+    fn is_inline(&self) -> bool {
+        self.attrs()
+            .iter()
+            .filter_map(|x| x.parse_meta().ok())
+            .any(|attr| match attr {
+                syn::Meta::Path(ref path) => path.is_ident("inline"),
+                syn::Meta::List(ref list) => {
+                    list.path.is_ident("inline")
+                        && list.nested.iter().any(|nested| match *nested {
+                            syn::NestedMeta::Meta(syn::Meta::Path(ref path)) => {
+                                path.is_ident("always")
+                            }
+                            _ => false,
+                        })
+                }
+                _ => false,
+            })
+    }
+
     /// Sees whether we should skip parsing a given item.
     fn should_skip_parsing(&self) -> bool {
         for attr in self.attrs() {
@@ -133,6 +195,16 @@ pub trait SynAttributeHelpers {
         false
     }
 
+    /// Searches for an attribute with the given path, like `ffi::skip`, either
+    /// directly (`#[ffi::skip]`) or nested in an `allow` or a `cfg_attr`
+    /// (`#[cfg_attr(cbindgen, ffi::skip)]`).
+    fn has_attr_path(&self, path: &str) -> bool {
+        self.attrs()
+            .iter()
+            .filter_map(|x| x.parse_meta().ok())
+            .any(|attr| meta_has_path(&attr, path))
+    }
+
     fn attr_name_value_lookup(&self, name: &str) -> Option<String> {
         self.attrs()
             .iter()
@@ -154,10 +226,19 @@ pub trait SynAttributeHelpers {
     }
 
     fn get_comment_lines(&self) -> Vec<String> {
+        self.get_comment_lines_with_style(syn::AttrStyle::Outer)
+    }
+
+    /// Like `get_comment_lines`, but for inner `//!` comments.
+    fn get_inner_comment_lines(&self) -> Vec<String> {
+        self.get_comment_lines_with_style(syn::AttrStyle::Inner(Default::default()))
+    }
+
+    fn get_comment_lines_with_style(&self, style: syn::AttrStyle) -> Vec<String> {
         let mut comment = Vec::new();
 
         for attr in self.attrs() {
-            if attr.style == syn::AttrStyle::Outer {
+            if attr.style == style {
                 if let Ok(syn::Meta::NameValue(syn::MetaNameValue {
                     path,
                     lit: syn::Lit::Str(content),
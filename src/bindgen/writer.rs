@@ -152,6 +152,16 @@ impl<'a, F: Write> SourceWriter<'a, F> {
         }
     }
 
+    /// Separates the declaration about to be written from the previous one
+    /// with `blank_lines_between_items` blank lines, unless it's the first.
+    pub fn new_lines_between_items(&mut self) {
+        if self.line_number != 1 {
+            for _ in 0..self.bindings.config.blank_lines_between_items {
+                self.new_line();
+            }
+        }
+    }
+
     pub fn open_brace(&mut self) {
         match self.bindings.config.braces {
             Braces::SameLine => {
@@ -233,6 +243,8 @@ impl<'a, F: Write> SourceWriter<'a, F> {
                 }
             }
 
+            item.write_trailing(&self.bindings.config, self);
+
             if i != items.len() - 1 {
                 self.new_line();
             }
@@ -243,4 +255,8 @@ impl<'a, F: Write> SourceWriter<'a, F> {
 
 pub trait Source {
     fn write<F: Write>(&self, config: &Config, _: &mut SourceWriter<F>);
+
+    /// Writes anything which should follow the item and its list separator on
+    /// the same line, when written as part of a vertical list.
+    fn write_trailing<F: Write>(&self, _config: &Config, _: &mut SourceWriter<F>) {}
 }
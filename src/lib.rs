@@ -18,11 +18,11 @@ mod bindgen;
 
 pub use crate::bindgen::*;
 
-use std::path::Path;
+use std::path;
 
 /// A utility function for build scripts to generate bindings for a crate, using
 /// a `cbindgen.toml` if it exists.
-pub fn generate<P: AsRef<Path>>(crate_dir: P) -> Result<Bindings, Error> {
+pub fn generate<P: AsRef<path::Path>>(crate_dir: P) -> Result<Bindings, Error> {
     let config = Config::from_root_or_default(crate_dir.as_ref());
 
     generate_with_config(crate_dir, config)
@@ -30,7 +30,7 @@ pub fn generate<P: AsRef<Path>>(crate_dir: P) -> Result<Bindings, Error> {
 
 /// A utility function for build scripts to generate bindings for a crate with a
 /// custom config.
-pub fn generate_with_config<P: AsRef<Path>>(
+pub fn generate_with_config<P: AsRef<path::Path>>(
     crate_dir: P,
     config: Config,
 ) -> Result<Bindings, Error> {
@@ -7,90 +7,21 @@ use std::io::Write;
 
 use log::*;
 
-pub struct TraceLogger;
-pub struct WarnLogger;
-pub struct InfoLogger;
-pub struct ErrorLogger;
-
-impl TraceLogger {
-    pub fn init() -> Result<(), SetLoggerError> {
-        log::set_logger(&InfoLogger)?;
-        log::set_max_level(LevelFilter::Trace);
-        Ok(())
-    }
-}
-impl log::Log for TraceLogger {
-    fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Trace
-    }
-
-    fn log(&self, record: &Record) {
-        if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
-        }
-    }
-
-    fn flush(&self) {
-        io::stderr().flush().unwrap();
-    }
-}
-
-impl WarnLogger {
-    pub fn init() -> Result<(), SetLoggerError> {
-        log::set_logger(&InfoLogger)?;
-        log::set_max_level(LevelFilter::Warn);
+/// Logs to stderr everything allowed by the max level, which can be changed
+/// after initialization once the config is loaded.
+pub struct StderrLogger;
+
+impl StderrLogger {
+    pub fn init(level: LevelFilter) -> Result<(), SetLoggerError> {
+        log::set_logger(&StderrLogger)?;
+        log::set_max_level(level);
         Ok(())
     }
 }
-impl log::Log for WarnLogger {
-    fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Warn
-    }
-
-    fn log(&self, record: &Record) {
-        if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
-        }
-    }
 
-    fn flush(&self) {
-        io::stderr().flush().unwrap();
-    }
-}
-
-impl ErrorLogger {
-    pub fn init() -> Result<(), SetLoggerError> {
-        log::set_logger(&InfoLogger)?;
-        log::set_max_level(LevelFilter::Error);
-        Ok(())
-    }
-}
-impl log::Log for ErrorLogger {
-    fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Error
-    }
-
-    fn log(&self, record: &Record) {
-        if self.enabled(record.metadata()) {
-            eprintln!("{}: {}", record.level(), record.args());
-        }
-    }
-
-    fn flush(&self) {
-        io::stderr().flush().unwrap();
-    }
-}
-
-impl InfoLogger {
-    pub fn init() -> Result<(), SetLoggerError> {
-        log::set_logger(&InfoLogger)?;
-        log::set_max_level(LevelFilter::Info);
-        Ok(())
-    }
-}
-impl log::Log for InfoLogger {
+impl log::Log for StderrLogger {
     fn enabled(&self, metadata: &Metadata) -> bool {
-        metadata.level() <= Level::Info
+        metadata.level() <= log::max_level()
     }
 
     fn log(&self, record: &Record) {
@@ -24,7 +24,7 @@ use clap::{App, Arg, ArgMatches};
 mod bindgen;
 mod logging;
 
-use crate::bindgen::{Bindings, Builder, Cargo, Config, Error, Language, Style};
+use crate::bindgen::{Bindings, Builder, Cargo, Config, Error, Language, Style, Verbosity};
 
 fn apply_config_overrides<'a>(config: &mut Config, matches: &ArgMatches<'a>) {
     // We allow specifying a language to override the config default. This is
@@ -64,11 +64,30 @@ fn apply_config_overrides<'a>(config: &mut Config, matches: &ArgMatches<'a>) {
     if matches.is_present("d") {
         config.parse.parse_deps = true;
     }
+
+    if matches.is_present("manifest") {
+        config.manifest = true;
+    }
+
+    if matches.is_present("verify-compile") {
+        config.verify_compile = true;
+    }
+
+    // The logger is initialized before the config is loaded, so only the
+    // verbosity of the config is left to apply.
+    if !has_verbosity_flags(matches) {
+        log::set_max_level(config.verbosity.level_filter());
+    }
+}
+
+fn has_verbosity_flags<'a>(matches: &ArgMatches<'a>) -> bool {
+    matches.is_present("quiet") || matches.is_present("v") || matches.is_present("verbosity")
 }
 
 fn load_bindings<'a>(input: &Path, matches: &ArgMatches<'a>) -> Result<Bindings, Error> {
-    // If a file is specified then we load it as a single source
-    if !input.is_dir() {
+    // If a file, or a directory which isn't a crate, is specified then we load
+    // it as a source
+    if !input.is_dir() || !input.join("Cargo.toml").exists() {
         // Load any config specified or search in the input directory
         let mut config = match matches.value_of("config") {
             Some(c) => Config::from_file(c).unwrap(),
@@ -124,7 +143,15 @@ fn main() {
             Arg::with_name("v")
                 .short("v")
                 .multiple(true)
-                .help("Enable verbose logging"),
+                .help("Enable verbose logging (`-v` for the normal verbosity, `-vv` for the verbose one)"),
+        )
+        .arg(
+            Arg::with_name("verbosity")
+                .long("verbosity")
+                .value_name("LEVEL")
+                .help("How much to report about the parsed items (overrides the config).")
+                .possible_values(&["quiet", "normal", "verbose"])
+                .required(false),
         )
         .arg(
             Arg::with_name("verify")
@@ -200,6 +227,24 @@ fn main() {
                 .help("The file to output the bindings to")
                 .required(false),
         )
+        .arg(
+            Arg::with_name("manifest")
+                .long("manifest")
+                .help(
+                    "Also write a JSON manifest of the items in the bindings, \
+                     next to the output file with a .json extension",
+                )
+                .required(false),
+        )
+        .arg(
+            Arg::with_name("verify-compile")
+                .long("verify-compile")
+                .help(
+                    "Check that the bindings compile, with the `compiler` of the config \
+                     or $CC or $CXX, and fail with the diagnostics of the compiler otherwise",
+                )
+                .required(false),
+        )
         .arg(
             Arg::with_name("lockfile")
                 .long("lockfile")
@@ -242,16 +287,24 @@ fn main() {
         std::process::exit(2);
     }
 
+    if !matches.is_present("out") && matches.is_present("manifest") {
+        error!("Cannot write a manifest for `stdout`, please specify an output file.");
+        std::process::exit(2);
+    }
+
     // Initialize logging
-    if matches.is_present("quiet") {
-        logging::ErrorLogger::init().unwrap();
+    let level = if matches.is_present("quiet") {
+        log::LevelFilter::Error
+    } else if let Some(verbosity) = matches.value_of("verbosity") {
+        verbosity.parse::<Verbosity>().unwrap().level_filter()
     } else {
         match matches.occurrences_of("v") {
-            0 => logging::WarnLogger::init().unwrap(),
-            1 => logging::InfoLogger::init().unwrap(),
-            _ => logging::TraceLogger::init().unwrap(),
+            0 => Verbosity::default().level_filter(),
+            1 => Verbosity::Normal.level_filter(),
+            _ => Verbosity::Verbose.level_filter(),
         }
-    }
+    };
+    logging::StderrLogger::init(level).unwrap();
 
     // Find the input directory
     let input = match matches.value_of("INPUT") {
@@ -6,7 +6,7 @@
 
 enum class Status : uint32_t {
   Ok,
-  Err,
+  Err
 };
 
 struct Dep {
@@ -5,7 +5,7 @@
 
 enum C {
   X = 2,
-  Y,
+  Y
 };
 typedef uint32_t C;
 
@@ -21,7 +21,7 @@ typedef struct {
 enum F_Tag {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 typedef uint8_t F_Tag;
 
@@ -45,7 +45,7 @@ typedef union {
 enum H_Tag {
   Hello,
   There,
-  Everyone,
+  Everyone
 };
 typedef uint8_t H_Tag;
 
@@ -6,7 +6,7 @@
 
 enum class C : uint32_t {
   X = 2,
-  Y,
+  Y
 };
 
 struct A {
@@ -33,7 +33,7 @@ union F {
   enum class Tag : uint8_t {
     Foo,
     Bar,
-    Baz,
+    Baz
   };
 
   struct Foo_Body {
@@ -92,7 +92,7 @@ struct H {
   enum class Tag : uint8_t {
     Hello,
     There,
-    Everyone,
+    Everyone
   };
 
   struct Hello_Body {
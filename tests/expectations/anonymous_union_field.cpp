@@ -0,0 +1,40 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+union Value {
+  /// The value as an integer.
+  int32_t as_int;
+  float as_float;
+  const uint8_t *as_ptr;
+};
+
+struct Variant {
+  uint8_t kind;
+  union {
+    /// The value as an integer.
+    int32_t as_int;
+    float as_float;
+    const uint8_t *as_ptr;
+  };
+  /// Still a named field.
+  Value other;
+};
+
+struct Tuple {
+  uint32_t _0;
+  union {
+    /// The value as an integer.
+    int32_t as_int;
+    float as_float;
+    const uint8_t *as_ptr;
+  };
+};
+
+extern "C" {
+
+void root(Variant v, Tuple t);
+
+} // extern "C"
@@ -4,7 +4,7 @@
 #include <stdlib.h>
 
 typedef enum {
-  A,
+  A
 } Foo_Tag;
 
 typedef struct {
@@ -6,7 +6,7 @@
 
 struct Foo {
   enum class Tag {
-    A,
+    A
   };
 
   struct A_Body {
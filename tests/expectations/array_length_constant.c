@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define NAME_LEN 16
+
+typedef struct {
+  uint32_t id;
+  uint8_t name[NAME_LEN];
+} Record;
+
+void record_init(Record *record);
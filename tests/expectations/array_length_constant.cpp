@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t NAME_LEN = 16;
+
+struct Record {
+  uint32_t id;
+  uint8_t name[NAME_LEN];
+};
+
+extern "C" {
+
+void record_init(Record *record);
+
+} // extern "C"
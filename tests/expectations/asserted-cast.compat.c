@@ -16,7 +16,7 @@ enum H_Tag
  {
   H_Foo,
   H_Bar,
-  H_Baz,
+  H_Baz
 };
 #ifndef __cplusplus
 typedef uint8_t H_Tag;
@@ -46,7 +46,7 @@ enum J_Tag
  {
   J_Foo,
   J_Bar,
-  J_Baz,
+  J_Baz
 };
 #ifndef __cplusplus
 typedef uint8_t J_Tag;
@@ -76,7 +76,7 @@ enum K_Tag
  {
   K_Foo,
   K_Bar,
-  K_Baz,
+  K_Baz
 };
 #ifndef __cplusplus
 typedef uint8_t K_Tag;
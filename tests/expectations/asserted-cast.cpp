@@ -14,7 +14,7 @@ struct H {
   enum class Tag : uint8_t {
     H_Foo,
     H_Bar,
-    H_Baz,
+    H_Baz
   };
 
   struct H_Foo_Body {
@@ -91,7 +91,7 @@ struct J {
   enum class Tag : uint8_t {
     J_Foo,
     J_Bar,
-    J_Baz,
+    J_Baz
   };
 
   struct J_Foo_Body {
@@ -168,7 +168,7 @@ union K {
   enum class Tag : uint8_t {
     K_Foo,
     K_Bar,
-    K_Baz,
+    K_Baz
   };
 
   struct K_Foo_Body {
@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct NotAssumed;
+
+struct Assumed {
+  int32_t x;
+  float y;
+};
+
+extern "C" {
+
+void root(Assumed a, const NotAssumed *b);
+
+} // extern "C"
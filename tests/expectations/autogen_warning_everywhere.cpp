@@ -0,0 +1,27 @@
+#ifndef AUTOGEN_WARNING_H
+#define AUTOGEN_WARNING_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+extern "C" {
+
+Point origin();
+
+} // extern "C"
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#endif // AUTOGEN_WARNING_H
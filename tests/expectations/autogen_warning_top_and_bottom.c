@@ -0,0 +1,20 @@
+#ifndef AUTOGEN_WARNING_H
+#define AUTOGEN_WARNING_H
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+Point origin(void);
+
+/* Warning, this file is autogenerated by cbindgen. Don't modify this manually. */
+
+#endif /* AUTOGEN_WARNING_H */
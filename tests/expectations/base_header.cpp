@@ -0,0 +1,24 @@
+/* License */
+
+#ifndef BASE_HEADER_H
+#define BASE_HEADER_H
+
+#include "testing-helpers.h"
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <stdio.h>
+
+struct Foo {
+  int32_t x;
+};
+
+extern "C" {
+
+void root(Foo foo);
+
+} // extern "C"
+
+#endif // BASE_HEADER_H
@@ -0,0 +1,31 @@
+/* Warning, this file is autogenerated. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The maximum number of items.
+#define LIMIT 10
+
+#pragma pack(push, 1)
+typedef struct {
+  uint8_t a;
+  uint32_t b;
+} Packed;
+#pragma pack(pop)
+
+typedef struct {
+  uint8_t a;
+} Plain;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Takes a packed struct.
+void root(Packed p, Plain q);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
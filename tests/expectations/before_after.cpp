@@ -0,0 +1,28 @@
+/* Warning, this file is autogenerated. */
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+// The maximum number of items.
+static const uint32_t LIMIT = 10;
+
+#pragma pack(push, 1)
+struct Packed {
+  uint8_t a;
+  uint32_t b;
+};
+#pragma pack(pop)
+
+struct Plain {
+  uint8_t a;
+};
+
+extern "C" {
+
+// Takes a packed struct.
+void root(Packed p, Plain q);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Flags {
+  uint32_t visible : 1;
+  /// Whether the item can be resized.
+  uint32_t resizable : 1;
+  uint32_t layer : 6;
+  uint8_t kind : 4;
+  uint32_t id;
+};
+
+struct Packed {
+  uint16_t _0 : 3;
+  uint16_t _1 : 13;
+};
+
+extern "C" {
+
+void root(Flags flags, Packed packed);
+
+} // extern "C"
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+
+#define ONE 1
+
+
+#define TWO 2
+
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+
+extern const Point ORIGIN;
+
+
+void first(Point p);
+
+
+void second(Point p);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
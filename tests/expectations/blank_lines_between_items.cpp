@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+
+static const uint32_t ONE = 1;
+
+
+static const uint32_t TWO = 2;
+
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+
+extern const Point ORIGIN;
+
+
+void first(Point p);
+
+
+void second(Point p);
+
+} // extern "C"
@@ -6,13 +6,13 @@
 typedef enum {
   Foo1,
   Bar1,
-  Baz1,
+  Baz1
 } MyCLikeEnum;
 
 typedef enum {
   Foo1_Prepended,
   Bar1_Prepended,
-  Baz1_Prepended,
+  Baz1_Prepended
 } MyCLikeEnum_Prepended;
 
 typedef struct {
@@ -25,7 +25,7 @@ typedef struct {
 typedef enum {
   Foo,
   Bar,
-  Baz,
+  Baz
 } MyFancyEnum_Tag;
 
 typedef struct {
@@ -63,7 +63,7 @@ typedef struct {
 typedef enum {
   Foo_Prepended,
   Bar_Prepended,
-  Baz_Prepended,
+  Baz_Prepended
 } MyFancyEnum_Prepended_Tag;
 
 typedef struct {
@@ -7,13 +7,13 @@
 enum class MyCLikeEnum {
   Foo1,
   Bar1,
-  Baz1,
+  Baz1
 };
 
 enum class MyCLikeEnum_Prepended {
   Foo1_Prepended,
   Bar1_Prepended,
-  Baz1_Prepended,
+  Baz1_Prepended
 };
 
 struct MyFancyStruct {
@@ -27,7 +27,7 @@ struct MyFancyEnum {
   enum class Tag {
     Foo,
     Bar,
-    Baz,
+    Baz
   };
 
   struct Bar_Body {
@@ -68,7 +68,7 @@ struct MyFancyEnum_Prepended {
   enum class Tag {
     Foo_Prepended,
     Bar_Prepended,
-    Baz_Prepended,
+    Baz_Prepended
   };
 
   struct Bar_Prepended_Body {
@@ -5,7 +5,7 @@
 
 enum Status {
   Ok,
-  Err,
+  Err
 };
 typedef uint32_t Status;
 
@@ -9,7 +9,7 @@ enum Status
 #endif // __cplusplus
  {
   Ok,
-  Err,
+  Err
 };
 #ifndef __cplusplus
 typedef uint32_t Status;
@@ -0,0 +1,51 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union Value {
+  /**
+   * The value as an integer.
+   */
+  int32_t as_int;
+  float as_float;
+  const uint8_t *as_ptr;
+} Value;
+
+typedef struct Variant {
+  uint8_t kind;
+  union {
+    /**
+     * The value as an integer.
+     */
+    int32_t as_int;
+    float as_float;
+    const uint8_t *as_ptr;
+  };
+  /**
+   * Still a named field.
+   */
+  Value other;
+} Variant;
+
+typedef struct Tuple {
+  uint32_t _0;
+  union {
+    /**
+     * The value as an integer.
+     */
+    int32_t as_int;
+    float as_float;
+    const uint8_t *as_ptr;
+  };
+} Tuple;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Variant v, Tuple t);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
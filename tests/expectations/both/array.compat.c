@@ -4,7 +4,7 @@
 #include <stdlib.h>
 
 typedef enum Foo_Tag {
-  A,
+  A
 } Foo_Tag;
 
 typedef struct A_Body {
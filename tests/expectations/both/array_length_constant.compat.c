@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define NAME_LEN 16
+
+typedef struct Record {
+  uint32_t id;
+  uint8_t name[NAME_LEN];
+} Record;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void record_init(Record *record);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -12,7 +12,7 @@ typedef struct I I;
 enum H_Tag {
   H_Foo,
   H_Bar,
-  H_Baz,
+  H_Baz
 };
 typedef uint8_t H_Tag;
 
@@ -36,7 +36,7 @@ typedef struct H {
 enum J_Tag {
   J_Foo,
   J_Bar,
-  J_Baz,
+  J_Baz
 };
 typedef uint8_t J_Tag;
 
@@ -60,7 +60,7 @@ typedef struct J {
 enum K_Tag {
   K_Foo,
   K_Bar,
-  K_Baz,
+  K_Baz
 };
 typedef uint8_t K_Tag;
 
@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct NotAssumed NotAssumed;
+
+typedef struct Assumed {
+  int32_t x;
+  float y;
+} Assumed;
+
+void root(Assumed a, const NotAssumed *b);
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct NotAssumed NotAssumed;
+
+typedef struct Assumed {
+  int32_t x;
+  float y;
+} Assumed;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Assumed a, const NotAssumed *b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,19 @@
+/* License */
+
+#ifndef BASE_HEADER_H
+#define BASE_HEADER_H
+
+#include "testing-helpers.h"
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stdio.h>
+
+typedef struct Foo {
+  int32_t x;
+} Foo;
+
+void root(Foo foo);
+
+#endif /* BASE_HEADER_H */
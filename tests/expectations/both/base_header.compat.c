@@ -0,0 +1,27 @@
+/* License */
+
+#ifndef BASE_HEADER_H
+#define BASE_HEADER_H
+
+#include "testing-helpers.h"
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stdio.h>
+
+typedef struct Foo {
+  int32_t x;
+} Foo;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Foo foo);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* BASE_HEADER_H */
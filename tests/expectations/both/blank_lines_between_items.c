@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+
+#define ONE 1
+
+
+#define TWO 2
+
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
+
+extern const Point ORIGIN;
+
+
+void first(Point p);
+
+
+void second(Point p);
@@ -6,13 +6,13 @@
 typedef enum MyCLikeEnum {
   Foo1,
   Bar1,
-  Baz1,
+  Baz1
 } MyCLikeEnum;
 
 typedef enum MyCLikeEnum_Prepended {
   Foo1_Prepended,
   Bar1_Prepended,
-  Baz1_Prepended,
+  Baz1_Prepended
 } MyCLikeEnum_Prepended;
 
 typedef struct MyFancyStruct {
@@ -25,7 +25,7 @@ typedef struct MyFancyStruct {
 typedef enum MyFancyEnum_Tag {
   Foo,
   Bar,
-  Baz,
+  Baz
 } MyFancyEnum_Tag;
 
 typedef struct Bar_Body {
@@ -63,7 +63,7 @@ typedef struct MyFancyStruct_Prepended {
 typedef enum MyFancyEnum_Prepended_Tag {
   Foo_Prepended,
   Bar_Prepended,
-  Baz_Prepended,
+  Baz_Prepended
 } MyFancyEnum_Prepended_Tag;
 
 typedef struct Bar_Prepended_Body {
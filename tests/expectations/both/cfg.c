@@ -7,7 +7,7 @@
 enum BarType {
   A,
   B,
-  C,
+  C
 };
 typedef uint32_t BarType;
 #endif
@@ -16,7 +16,7 @@ typedef uint32_t BarType;
 enum FooType {
   A,
   B,
-  C,
+  C
 };
 typedef uint32_t FooType;
 #endif
@@ -36,7 +36,7 @@ enum C_Tag {
   C3,
 #endif
 #if defined(PLATFORM_UNIX)
-  C5,
+  C5
 #endif
 };
 typedef uint8_t C_Tag;
@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Node {
+  int32_t value;
+} Node;
+
+typedef struct Pointers {
+  const uint8_t *const_prim;
+  uint8_t *mut_prim;
+  const Node *const_struct;
+  Node *mut_struct;
+  Node *const *const_to_mut;
+  const Node **mut_to_const;
+  const Node *shared_ref;
+  Node *mut_ref;
+  const Node *nullable;
+} Pointers;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const Pointers *pointers(const uint8_t *const_prim,
+                         uint8_t *mut_prim,
+                         const Node *const_struct,
+                         Node *mut_struct,
+                         Node *const *const_to_mut,
+                         const Node **mut_to_const,
+                         const Node *shared_ref,
+                         Node *mut_ref,
+                         const Node *nullable);
+
+Pointers *mut_return(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -4,7 +4,7 @@
 #include <stdlib.h>
 
 typedef enum E {
-  V,
+  V
 } E;
 
 typedef struct S {
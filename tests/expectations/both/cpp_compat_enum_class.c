@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Without an integer repr, the enum stays a plain enum.
+ */
+typedef enum Color {
+  Red,
+  Green
+} Color;
+
+enum Flags {
+  FlagNone,
+  FlagAll
+};
+typedef uint16_t Flags;
+
+enum Mode {
+  Read,
+  Write = 4,
+  Append
+};
+typedef uint8_t Mode;
+
+/**
+ * The tag of an enum with fields stays a plain enum.
+ */
+enum Shape_Tag {
+  Circle,
+  Square
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  Shape_Tag tag;
+  float _0;
+} Circle_Body;
+
+typedef struct Square_Body {
+  Shape_Tag tag;
+  float _0;
+} Square_Body;
+
+typedef union Shape {
+  Shape_Tag tag;
+  Circle_Body circle;
+  Square_Body square;
+} Shape;
+
+typedef struct Options {
+  Mode mode;
+  Flags flags;
+  Color color;
+  Shape shape;
+} Options;
+
+Mode root(Options options);
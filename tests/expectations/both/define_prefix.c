@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MYLIB_LEN 4
+
+#define MYLIB_DOUBLE_LEN (MYLIB_LEN * 2)
+
+typedef uint8_t Mode;
+#define MYLIB_Read 0
+#define MYLIB_Write 1
+
+#define MYLIB_Mode_COUNT 2
+
+typedef struct Buffer {
+  uint8_t data[MYLIB_DOUBLE_LEN];
+  Mode mode;
+} Buffer;
+#define MYLIB_Buffer_CAPACITY 16
+
+int32_t scale_i32(int32_t x, int32_t factor);
+
+float scale_f32(float x, float factor);
+
+void root(Buffer *buffer);
+
+#if defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define MYLIB_scale(x, factor) _Generic((x), \
+  int32_t: scale_i32, \
+  float: scale_f32)(x, factor)
+#endif
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Base {
+  int32_t value;
+} Base;
+
+typedef struct Right {
+  Base base;
+  Base extra[2];
+} Right;
+
+typedef struct Left {
+  Base base;
+  const Right *right;
+} Left;
+
+typedef struct Top {
+  Left left;
+  Right right;
+} Top;
+
+void root(Top top);
@@ -9,7 +9,7 @@ enum C
 #endif // __cplusplus
  {
   X = 2,
-  Y,
+  Y
 };
 #ifndef __cplusplus
 typedef uint32_t C;
@@ -37,7 +37,7 @@ enum F_Tag
  {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 #ifndef __cplusplus
 typedef uint8_t F_Tag;
@@ -67,7 +67,7 @@ enum H_Tag
  {
   Hello,
   There,
-  Everyone,
+  Everyone
 };
 #ifndef __cplusplus
 typedef uint8_t H_Tag;
@@ -96,7 +96,7 @@ enum I_Tag
 #endif // __cplusplus
  {
   ThereAgain,
-  SomethingElse,
+  SomethingElse
 };
 #ifndef __cplusplus
 typedef uint8_t I_Tag;
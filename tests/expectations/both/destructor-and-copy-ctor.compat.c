@@ -13,7 +13,7 @@ enum FillRule
 #endif // __cplusplus
  {
   A,
-  B,
+  B
 };
 #ifndef __cplusplus
 typedef uint8_t FillRule;
@@ -52,7 +52,7 @@ enum Foo_u32_Tag
   Slice1_u32,
   Slice2_u32,
   Slice3_u32,
-  Slice4_u32,
+  Slice4_u32
 };
 #ifndef __cplusplus
 typedef uint8_t Foo_u32_Tag;
@@ -106,7 +106,7 @@ enum Baz_i32_Tag
   Slice21_i32,
   Slice22_i32,
   Slice23_i32,
-  Slice24_i32,
+  Slice24_i32
 };
 #ifndef __cplusplus
 typedef uint8_t Baz_i32_Tag;
@@ -155,7 +155,7 @@ enum Taz_Tag
  {
   Bar3,
   Taz1,
-  Taz3,
+  Taz3
 };
 #ifndef __cplusplus
 typedef uint8_t Taz_Tag;
@@ -183,7 +183,7 @@ enum Tazz_Tag
 #endif // __cplusplus
  {
   Bar4,
-  Taz2,
+  Taz2
 };
 #ifndef __cplusplus
 typedef uint8_t Tazz_Tag;
@@ -205,7 +205,7 @@ enum Tazzz_Tag
 #endif // __cplusplus
  {
   Bar5,
-  Taz5,
+  Taz5
 };
 #ifndef __cplusplus
 typedef uint8_t Tazzz_Tag;
@@ -227,7 +227,7 @@ enum Tazzzz_Tag
 #endif // __cplusplus
  {
   Taz6,
-  Taz7,
+  Taz7
 };
 #ifndef __cplusplus
 typedef uint8_t Tazzzz_Tag;
@@ -255,7 +255,7 @@ enum Qux_Tag
 #endif // __cplusplus
  {
   Qux1,
-  Qux2,
+  Qux2
 };
 #ifndef __cplusplus
 typedef uint8_t Qux_Tag;
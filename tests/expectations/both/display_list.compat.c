@@ -24,7 +24,7 @@ enum DisplayItem_Tag
  {
   Fill,
   Image,
-  ClearScreen,
+  ClearScreen
 };
 #ifndef __cplusplus
 typedef uint8_t DisplayItem_Tag;
@@ -0,0 +1,52 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Copies bytes into a buffer.
+ *
+ * @param dest Where to copy to.
+ * @param src Where to copy from.
+ * @param len
+ *
+ * @return The number of bytes copied.
+ */
+uintptr_t copy_bytes(uint8_t *dest, const uint8_t *src, uintptr_t len);
+
+/**
+ * Resets the state.
+ *
+ * @param flags Which parts of the state to reset.
+ * @param verbose
+ */
+void reset(uint32_t flags, bool verbose);
+
+/**
+ * @return
+ */
+uint32_t version(void);
+
+/**
+ * Never returns.
+ *
+ * @param code
+ */
+void abort_now(int32_t code);
+
+/**
+ * Fills a buffer.
+ *
+ * \param[out] buffer The buffer to fill.
+ * @param len
+ * @return Whether the buffer was filled.
+ */
+bool fill(uint8_t *buffer, uintptr_t len);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,49 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A plain enum.
+ */
+typedef enum Color {
+  Red, /**< Red. */
+  Green = 4, /**< Green. */
+  Blue
+} Color;
+
+/**
+ * A point in space.
+ */
+typedef struct Point {
+  float x; /**< The x coordinate. */
+  /**
+   * The y coordinate.
+   *
+   * Spans several lines, so it stays leading.
+   */
+  float y;
+  float z;
+} Point;
+
+/**
+ * A tagged enum.
+ */
+enum Shape_Tag {
+  Circle, /**< A circle. */
+  Empty /**< Nothing. */
+};
+typedef uint8_t Shape_Tag;
+
+typedef struct Circle_Body {
+  float radius; /**< Its radius. */
+} Circle_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+  };
+} Shape;
+
+void root(Point p, Color c, Shape s);
@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint8_t Color;
+/**
+ * The default color.
+ */
+#define Color_Red 0
+#define Color_Green 4
+#define Color_Blue 5
+
+typedef int32_t Offset;
+#define Offset_Before (-2)
+#define Offset_Here (-1)
+#define Offset_After 0
+
+typedef enum Plain {
+  Plain_First,
+  Plain_Second
+} Plain;
+
+typedef int64_t Wide;
+#define Wide_Small 1
+#define Wide_Max 9223372036854775807
+
+void root(Color c, Offset o, Plain p, Wide w);
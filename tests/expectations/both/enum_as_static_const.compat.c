@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint8_t Color;
+/**
+ * The default color.
+ */
+static const Color Color_Red = 0;
+static const Color Color_Green = 4;
+static const Color Color_Blue = 5;
+
+typedef int32_t Offset;
+static const Offset Offset_Before = -2;
+static const Offset Offset_Here = -1;
+static const Offset Offset_After = 0;
+
+typedef enum Plain {
+  Plain_First,
+  Plain_Second
+} Plain;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Color c, Offset o, Plain p);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Fieldless {
+  FieldlessA,
+  FieldlessB
+};
+typedef uint8_t Fieldless;
+
+enum Signed {
+  SignedA = -1,
+  SignedB = 1
+};
+typedef int16_t Signed;
+
+enum Tagged_Tag {
+  TaggedA,
+  TaggedB,
+  TaggedC
+};
+typedef uint32_t Tagged_Tag;
+
+typedef struct TaggedA_Body {
+  uint8_t _0;
+} TaggedA_Body;
+
+typedef struct TaggedB_Body {
+  int16_t x;
+} TaggedB_Body;
+
+typedef struct Tagged {
+  Tagged_Tag tag;
+  union {
+    TaggedA_Body tagged_a;
+    TaggedB_Body tagged_b;
+  };
+} Tagged;
+
+void root(Fieldless a, Signed b, Tagged c);
@@ -0,0 +1,67 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Fieldless
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  FieldlessA,
+  FieldlessB
+};
+#ifndef __cplusplus
+typedef uint8_t Fieldless;
+#endif // __cplusplus
+
+enum Signed
+#ifdef __cplusplus
+  : int16_t
+#endif // __cplusplus
+ {
+  SignedA = -1,
+  SignedB = 1
+};
+#ifndef __cplusplus
+typedef int16_t Signed;
+#endif // __cplusplus
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  TaggedA,
+  TaggedB,
+  TaggedC
+};
+#ifndef __cplusplus
+typedef uint32_t Tagged_Tag;
+#endif // __cplusplus
+
+typedef struct TaggedA_Body {
+  uint8_t _0;
+} TaggedA_Body;
+
+typedef struct TaggedB_Body {
+  int16_t x;
+} TaggedB_Body;
+
+typedef struct Tagged {
+  Tagged_Tag tag;
+  union {
+    TaggedA_Body tagged_a;
+    TaggedB_Body tagged_b;
+  };
+} Tagged;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Fieldless a, Signed b, Tagged c);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
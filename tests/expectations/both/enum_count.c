@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Contiguous {
+  A,
+  B,
+  C
+};
+typedef uint8_t Contiguous;
+
+#define Contiguous_VARIANT_COUNT 3
+
+typedef enum Explicit {
+  X = 0,
+  Y = 1,
+  Z = 2
+} Explicit;
+
+#define Explicit_VARIANT_COUNT 3
+
+enum Gaps {
+  First = 1,
+  Second = 5
+};
+typedef uint32_t Gaps;
+
+typedef enum NotCounted {
+  P,
+  Q
+} NotCounted;
+
+enum Tagged_Tag {
+  Foo,
+  Bar,
+  Baz
+};
+typedef uint8_t Tagged_Tag;
+
+typedef struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+} Foo_Body;
+
+typedef struct Bar_Body {
+  Tagged_Tag tag;
+  uint8_t x;
+} Bar_Body;
+
+typedef union Tagged {
+  Tagged_Tag tag;
+  Foo_Body foo;
+  Bar_Body bar;
+} Tagged;
+
+#define Tagged_VARIANT_COUNT 3
+
+void root(Contiguous a, Explicit b, NotCounted c, Gaps d, Tagged e);
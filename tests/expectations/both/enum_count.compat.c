@@ -0,0 +1,85 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Contiguous
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+  C
+};
+#ifndef __cplusplus
+typedef uint8_t Contiguous;
+#endif // __cplusplus
+
+#define Contiguous_VARIANT_COUNT 3
+
+typedef enum Explicit {
+  X = 0,
+  Y = 1,
+  Z = 2
+} Explicit;
+
+#define Explicit_VARIANT_COUNT 3
+
+enum Gaps
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  First = 1,
+  Second = 5
+};
+#ifndef __cplusplus
+typedef uint32_t Gaps;
+#endif // __cplusplus
+
+typedef enum NotCounted {
+  P,
+  Q
+} NotCounted;
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Foo,
+  Bar,
+  Baz
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_Tag;
+#endif // __cplusplus
+
+typedef struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+} Foo_Body;
+
+typedef struct Bar_Body {
+  Tagged_Tag tag;
+  uint8_t x;
+} Bar_Body;
+
+typedef union Tagged {
+  Tagged_Tag tag;
+  Foo_Body foo;
+  Bar_Body bar;
+} Tagged;
+
+#define Tagged_VARIANT_COUNT 3
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Contiguous a, Explicit b, NotCounted c, Gaps d, Tagged e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
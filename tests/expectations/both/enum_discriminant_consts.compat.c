@@ -0,0 +1,90 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define BASE 16
+
+#define SHIFT 2
+
+#define DERIVED (BASE * 2)
+
+#define NOT_AN_INTEGER 1.5
+
+#define LOW 2
+
+#define SIGNED 5
+
+enum Complement
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  AllOnes = 255,
+  Parenthesized = 254,
+  OfConstant = 253
+};
+#ifndef __cplusplus
+typedef uint8_t Complement;
+#endif // __cplusplus
+
+enum Flags
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  None = 0,
+  First = 4,
+  Second = 8,
+  Both = 12,
+  Derived = 33,
+  Next
+};
+#ifndef __cplusplus
+typedef uint32_t Flags;
+#endif // __cplusplus
+
+enum Offsets
+#ifdef __cplusplus
+  : int32_t
+#endif // __cplusplus
+ {
+  Low = -16,
+  Mid = -8,
+  High = 15
+};
+#ifndef __cplusplus
+typedef int32_t Offsets;
+#endif // __cplusplus
+
+enum SignedComplement
+#ifdef __cplusplus
+  : int8_t
+#endif // __cplusplus
+ {
+  MinusOne = -1,
+  MinusTwo = -2,
+  OfSigned = -6
+};
+#ifndef __cplusplus
+typedef int8_t SignedComplement;
+#endif // __cplusplus
+
+typedef struct FloatConstant FloatConstant;
+
+typedef struct UnknownConstant UnknownConstant;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Flags f,
+          Offsets o,
+          Complement c,
+          SignedComplement s,
+          const UnknownConstant *u,
+          const FloatConstant *x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -10,7 +10,7 @@ typedef struct Foo_Bar {
 enum Bar_Tag {
   Min,
   Max,
-  Other,
+  Other
 };
 typedef uint8_t Bar_Tag;
 
@@ -14,7 +14,7 @@ enum Bar_Tag
  {
   Min,
   Max,
-  Other,
+  Other
 };
 #ifndef __cplusplus
 typedef uint8_t Bar_Tag;
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A type-level tag.
+ */
+typedef int Marker;
+#define Marker_Only 0
+
+/**
+ * Still written as an `enum`, since it has two variants.
+ */
+typedef enum Pair {
+  Pair_First,
+  Pair_Second
+} Pair;
+
+typedef uint8_t Version;
+#define Version_V1 1
+
+void root(Marker m, Version v, Pair p);
@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Conditional {
+  First,
+#if defined(DEFINE_SECOND)
+  Second,
+#endif
+} Conditional;
+
+typedef enum Plain {
+  A,
+  /**
+   * The last variant.
+   */
+  B = 5,
+} Plain;
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Foo,
+  Bar,
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_Tag;
+#endif // __cplusplus
+
+typedef struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+} Foo_Body;
+
+typedef struct Bar_Body {
+  Tagged_Tag tag;
+  float x;
+} Bar_Body;
+
+typedef union Tagged {
+  Tagged_Tag tag;
+  Foo_Body foo;
+  Bar_Body bar;
+} Tagged;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Plain plain, Tagged tagged, Conditional conditional);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
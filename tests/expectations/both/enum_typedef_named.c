@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue
+} Color;
+
+enum Small {
+  One,
+  Two
+};
+typedef uint8_t Small;
+
+typedef enum Shape_Tag {
+  Circle,
+  Square
+} Shape_Tag;
+
+typedef struct Circle_Body {
+  float _0;
+} Circle_Body;
+
+typedef struct Square_Body {
+  float _0;
+} Square_Body;
+
+typedef struct Shape {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+} Shape;
+
+void root(Color color, Small small, Shape shape);
@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Large {
+  LargeA,
+  LargeB = 1099511627776
+};
+typedef uint64_t Large;
+
+enum Medium {
+  MediumA = -1,
+  MediumB = 1
+};
+typedef int16_t Medium;
+
+enum Small {
+  SmallA,
+  SmallB
+};
+typedef uint8_t Small;
+
+/**
+ * No width is specified, so the C++ enum is left to the compiler.
+ */
+typedef enum Unsized {
+  UnsizedA,
+  UnsizedB
+} Unsized;
+
+void root(Small s, Medium m, Large l, Unsized u);
+
+#include "testing-helpers.h"
+static_assert(sizeof(Small) == 1, "unexpected size for Small");
+static_assert(sizeof(Medium) == 2, "unexpected size for Medium");
+static_assert(sizeof(Large) == 8, "unexpected size for Large");
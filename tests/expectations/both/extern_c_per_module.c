@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t VERSION;
+
+void init(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern uintptr_t BUFFER_SIZE;
+
+void flush(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+int32_t add(int32_t a, int32_t b);
+
+int32_t sub(int32_t a, int32_t b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
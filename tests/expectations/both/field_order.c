@@ -0,0 +1,46 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Header {
+  uint8_t zeta;
+  uint32_t alpha;
+  uint16_t mu;
+  uint64_t beta;
+} Header;
+
+typedef struct Pair {
+  uint64_t _0;
+  uint8_t _1;
+} Pair;
+
+typedef union Payload {
+  uint32_t zz;
+  float aa;
+} Payload;
+
+typedef enum Message_Tag {
+  Ping,
+  Data
+} Message_Tag;
+
+typedef struct Ping_Body {
+  uint32_t seq;
+  uint64_t at;
+} Ping_Body;
+
+typedef struct Data_Body {
+  Header _0;
+  Payload _1;
+} Data_Body;
+
+typedef struct Message {
+  Message_Tag tag;
+  union {
+    Ping_Body ping;
+    Data_Body data;
+  };
+} Message;
+
+void root(Header h, Pair p, Message m);
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct NotLast NotLast;
+
+typedef struct NotZeroLength NotZeroLength;
+
+typedef struct ZeroLength {
+  uintptr_t len;
+  uint8_t data[];
+} ZeroLength;
+
+typedef struct Marked {
+  uint32_t len;
+  int32_t items[];
+} Marked;
+
+typedef struct Unmarked {
+  uint32_t len;
+  uint16_t data[0];
+} Unmarked;
+
+void root(const ZeroLength *a,
+          const Marked *b,
+          const NotLast *c,
+          const Unmarked *d,
+          const NotZeroLength *e);
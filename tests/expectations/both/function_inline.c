@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * `#[inline]` doesn't change the declaration.
+ */
+int32_t hinted(int32_t x);
+
+int32_t always(int32_t x);
+
+int32_t never(int32_t x);
+
+int32_t plain(int32_t x);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * `#[inline]` doesn't change the declaration.
+ */
+int32_t hinted(int32_t x);
+
+int32_t always(int32_t x);
+
+int32_t never(int32_t x);
+
+int32_t plain(int32_t x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,73 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+enum Kind : uint8_t;
+#else
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef enum Plain {
+  A,
+  B
+} Plain;
+
+typedef struct Handle Handle;
+
+typedef struct Point Point;
+
+typedef const Point *Points;
+
+typedef struct Shape Shape;
+
+typedef float Meters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const Point ORIGIN;
+
+Point draw(Handle *h, Points p, Kind k, Shape s, Meters m, Plain plain);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Solid,
+  Dashed
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+typedef enum Shape_Tag {
+  Dot,
+  Line
+} Shape_Tag;
+
+typedef struct Dot_Body {
+  Point _0;
+} Dot_Body;
+
+typedef struct Line_Body {
+  Point _0;
+  Point _1;
+} Line_Body;
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    Dot_Body dot;
+    Line_Body line;
+  };
+};
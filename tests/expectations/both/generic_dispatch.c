@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Vec2 {
+  float x;
+  float y;
+} Vec2;
+
+int32_t add_i32(int32_t a, int32_t b);
+
+float add_f32(float a, float b);
+
+float length_Vec2(const Vec2 *v);
+
+double length_f64(double x);
+
+void scale_vector(Vec2 *v, float factor);
+
+void scale_number(double *x, float factor);
+
+/**
+ * The only function named after its first argument type with this name, so
+ * it isn't grouped.
+ */
+int64_t negate_i64(int64_t x);
+
+#if defined(__STDC_VERSION__) && __STDC_VERSION__ >= 201112L
+#define add(a, b) _Generic((a), \
+  int32_t: add_i32, \
+  float: add_f32)(a, b)
+
+#define length(v) _Generic((v), \
+  const Vec2*: length_Vec2, \
+  double: length_f64, \
+  Vec2*: length_Vec2)(v)
+
+#define scale(v, factor) _Generic((v), \
+  Vec2*: scale_vector, \
+  double*: scale_number)(v, factor)
+#endif
@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Opt_i32_Tag {
+  None_i32,
+  Some_i32
+} Opt_i32_Tag;
+
+typedef struct Some_Body_i32 {
+  int32_t _0;
+} Some_Body_i32;
+
+typedef struct Opt_i32 {
+  Opt_i32_Tag tag;
+  union {
+    Some_Body_i32 some;
+  };
+} Opt_i32;
+
+typedef Opt_i32 IntOption;
+
+enum Tagged_f32_Tag {
+  A_f32,
+  B_f32
+};
+typedef uint8_t Tagged_f32_Tag;
+
+typedef struct A_Body_f32 {
+  Tagged_f32_Tag tag;
+  float _0;
+} A_Body_f32;
+
+typedef struct B_Body_f32 {
+  Tagged_f32_Tag tag;
+  float x;
+  uint8_t y;
+} B_Body_f32;
+
+typedef union Tagged_f32 {
+  Tagged_f32_Tag tag;
+  A_Body_f32 a;
+  B_Body_f32 b;
+} Tagged_f32;
+
+typedef enum Opt______u8_Tag {
+  None______u8,
+  Some______u8
+} Opt______u8_Tag;
+
+typedef struct Some_Body______u8 {
+  const uint8_t *_0;
+} Some_Body______u8;
+
+typedef struct Opt______u8 {
+  Opt______u8_Tag tag;
+  union {
+    Some_Body______u8 some;
+  };
+} Opt______u8;
+
+void root(IntOption a, Tagged_f32 b, Opt______u8 c);
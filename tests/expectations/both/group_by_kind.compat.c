@@ -0,0 +1,56 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Registry Registry;
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+/**
+ * Needs `Point` by value, so `Point` is moved up among the typedefs.
+ */
+typedef Point Position;
+
+/**
+ * Only uses `Registry` behind a pointer, so it stays with the typedefs, and
+ * `Registry` is forward-declared.
+ */
+typedef Registry *RegistryPtr;
+
+enum Shape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square
+};
+#ifndef __cplusplus
+typedef uint8_t Shape;
+#endif // __cplusplus
+
+typedef struct Node {
+  Position position;
+  Shape shape;
+} Node;
+
+struct Registry {
+  Node *first;
+  uintptr_t len;
+};
+
+typedef struct Handle Handle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Node *registry_first(RegistryPtr registry, Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,48 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Zebra {
+  int32_t value;
+} Zebra;
+
+enum Mode {
+  Fast,
+  Slow
+};
+typedef uint8_t Mode;
+
+typedef struct Inner {
+  Zebra zebra;
+} Inner;
+
+typedef struct Pair_f32 {
+  float first;
+  float second;
+} Pair_f32;
+
+/**
+ * Uses a type which is defined after it.
+ */
+typedef struct Outer {
+  Inner inner;
+  Mode mode;
+  Pair_f32 pair;
+} Outer;
+
+typedef struct Opaque Opaque;
+
+typedef void (*Callback)(const Opaque*);
+
+typedef union Value {
+  int32_t as_int;
+  float as_float;
+} Value;
+
+typedef enum Alpha {
+  A,
+  B
+} Alpha;
+
+void root(Outer outer, Callback callback, Value value, Alpha alpha);
@@ -5,6 +5,6 @@
 
 enum StyleOnlyThisShouldBeGenerated {
   Foo,
-  Bar,
+  Bar
 };
 typedef uint8_t StyleOnlyThisShouldBeGenerated;
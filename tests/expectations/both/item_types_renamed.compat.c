@@ -9,7 +9,7 @@ enum StyleOnlyThisShouldBeGenerated
 #endif // __cplusplus
  {
   Foo,
-  Bar,
+  Bar
 };
 #ifndef __cplusplus
 typedef uint8_t StyleOnlyThisShouldBeGenerated;
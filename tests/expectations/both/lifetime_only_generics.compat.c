@@ -0,0 +1,56 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Only has a lifetime parameter, so it's written like a non-generic struct.
+ */
+typedef struct Ref {
+  const uint32_t *ptr;
+  uintptr_t len;
+} Ref;
+
+typedef struct Pair {
+  Ref first;
+  Ref *second;
+} Pair;
+
+enum Borrowed_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Nothing,
+  Value,
+  Slice
+};
+#ifndef __cplusplus
+typedef uint8_t Borrowed_Tag;
+#endif // __cplusplus
+
+typedef struct Value_Body {
+  const uint32_t *_0;
+} Value_Body;
+
+typedef struct Slice_Body {
+  Ref _0;
+} Slice_Body;
+
+typedef struct Borrowed {
+  Borrowed_Tag tag;
+  union {
+    Value_Body value;
+    Slice_Body slice;
+  };
+} Borrowed;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Pair pair, Borrowed borrowed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
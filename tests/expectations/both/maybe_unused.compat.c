@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+__attribute__((unused)) extern const uint32_t DEBUG_LEVEL;
+
+__attribute__((unused)) void debug_hook(uint32_t level);
+
+void always_used(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
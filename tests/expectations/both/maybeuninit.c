@@ -13,8 +13,16 @@ typedef struct NotReprC_MaybeUninit______i32 NotReprC_MaybeUninit______i32;
 
 typedef NotReprC_MaybeUninit______i32 Foo;
 
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+
 typedef struct MyStruct {
   const int32_t *number;
+  Point point;
 } MyStruct;
 
 void root(const Foo *a, const MyStruct *with_maybe_uninit);
+
+void take(Point with_maybe_uninit);
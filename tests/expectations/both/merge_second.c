@@ -0,0 +1,11 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Size {
+  uint32_t w;
+  uint32_t h;
+} Size;
+
+void second(Size s);
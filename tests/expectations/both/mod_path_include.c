@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Generated {
+  uint32_t id;
+} Generated;
+
+typedef struct Handle {
+  int32_t fd;
+} Handle;
+
+typedef struct Device {
+  Handle handle;
+} Device;
+
+typedef struct Real {
+  float value;
+} Real;
+
+void generated_fn(const Generated *g);
+
+void root(Device a, Generated b);
+
+void real(Real r);
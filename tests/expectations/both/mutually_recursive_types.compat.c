@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Graph Graph;
+
+typedef struct Edge Edge;
+
+typedef struct Node Node;
+
+struct Graph {
+  Node *root;
+  void (*walk)(const Graph*);
+};
+
+struct Edge {
+  Node *to;
+  const Edge *next;
+  const Graph *graph;
+};
+
+struct Node {
+  Node *parent;
+  const Edge *edges;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void visit(Node *node);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
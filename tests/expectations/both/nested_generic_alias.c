@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Array_f32 {
+  float *data;
+  uintptr_t len;
+} Array_f32;
+
+typedef struct Array_Array_f32 {
+  Array_f32 *data;
+  uintptr_t len;
+} Array_Array_f32;
+
+typedef Array_Array_f32 Matrix;
+
+typedef Array_f32 Row;
+
+typedef struct Wrap_i32 {
+  int32_t inner;
+} Wrap_i32;
+
+typedef struct Wrap_Wrap_i32 {
+  Wrap_i32 inner;
+} Wrap_Wrap_i32;
+
+typedef struct Wrap_Wrap_Wrap_i32 {
+  Wrap_Wrap_i32 inner;
+} Wrap_Wrap_Wrap_i32;
+
+typedef Wrap_Wrap_Wrap_i32 Deep;
+
+void root(Matrix m, Row r, Deep d);
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Event {
+  uint32_t kind;
+} Event;
+
+typedef struct Listener {
+  /**
+   * May be null, in which case events are dropped.
+   */
+  bool (*on_event)(const Event *event, uint8_t *user_data);
+  void (*on_close)(uint8_t*);
+  void (*on_error)(int32_t code);
+  uint8_t *user_data;
+} Listener;
+
+void listener_attach(Listener *listener);
@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+typedef struct Options {
+  Handle *handle;
+  uint32_t flags;
+} Options;
+
+Handle *handle_new(const Options *options);
+
+void handle_free(Handle *handle);
@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Raw {
+  char c_char;
+  signed char c_schar;
+  unsigned char c_uchar;
+  short c_short;
+  unsigned short c_ushort;
+  int c_int;
+  unsigned int c_uint;
+  long c_long;
+  unsigned long c_ulong;
+  long long c_longlong;
+  unsigned long long c_ulonglong;
+  float c_float;
+  double c_double;
+  signed char core_c_schar;
+  unsigned char libc_c_uchar;
+} Raw;
+
+void root(Raw raw);
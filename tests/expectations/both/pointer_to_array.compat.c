@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffers {
+  const uint8_t (*block)[4];
+  uint16_t (*rows)[3][2];
+  void (*callback)(const uint8_t(*)[4]);
+} Buffers;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void by_ptr(const uint8_t (*block)[4]);
+
+void by_ref(const uint8_t (*block)[4], uint16_t (*rows)[3][2]);
+
+uint32_t (*returns_ptr(const Buffers *buffers))[2];
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
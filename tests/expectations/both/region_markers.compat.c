@@ -0,0 +1,54 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// region: Constants
+
+#define LIMIT 16
+
+// endregion
+
+// region: Types
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Small,
+  Large
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef struct Point {
+  int32_t x;
+  int32_t y;
+} Point;
+#define Point_ORIGIN (Point){ .x = 0, .y = 0 }
+
+typedef Point Points[4];
+
+// endregion
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// region: Globals
+
+extern uint32_t COUNTER;
+
+// endregion
+
+// region: Functions
+
+void root(Point p, Kind k, const Points *ps);
+
+// endregion
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
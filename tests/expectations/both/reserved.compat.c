@@ -18,7 +18,7 @@ enum C_Tag
   : uint8_t
 #endif // __cplusplus
  {
-  D,
+  D
 };
 #ifndef __cplusplus
 typedef uint8_t C_Tag;
@@ -42,7 +42,7 @@ enum E_Tag
 #endif // __cplusplus
  {
   Double,
-  Float,
+  Float
 };
 #ifndef __cplusplus
 typedef uint8_t E_Tag;
@@ -70,7 +70,7 @@ enum F_Tag
 #endif // __cplusplus
  {
   double_,
-  float_,
+  float_
 };
 #ifndef __cplusplus
 typedef uint8_t F_Tag;
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_LEN 32
+
+#define ALIAS_LEN MAX_LEN
+
+#define SHIFTED_LEN (1 << 2)
+
+typedef struct Buffer {
+  uint8_t data[32];
+  uint8_t aliased[32];
+  uint16_t nested[2][32];
+  uint8_t unresolved[SHIFTED_LEN];
+} Buffer;
+
+typedef uint8_t Name[32];
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Buffer buffer, const Name *name);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Exported {
+  int32_t x;
+} Exported;
+
+void root(Exported e);
+
+int32_t handle_new(void);
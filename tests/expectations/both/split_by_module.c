@@ -0,0 +1,28 @@
+#ifndef SPLIT_BY_MODULE_H
+#define SPLIT_BY_MODULE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module_geometry.c"
+#include "split_by_module_limits.c"
+#include "split_by_module_render.c"
+
+typedef struct Context {
+  Canvas canvas;
+  float scale;
+} Context;
+
+/**
+ * Only uses `limits` for the length of its array.
+ */
+typedef struct Name {
+  uint8_t chars[NAME_LEN];
+} Name;
+
+Context context_new(uint32_t width, uint32_t height);
+
+void name_init(Name *name);
+
+#endif /* SPLIT_BY_MODULE_H */
@@ -0,0 +1,26 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H
+#define SPLIT_BY_MODULE_CRATE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module_crate.compat_geometry.c"
+#include "split_by_module_crate.compat_render.c"
+
+typedef struct Context {
+  Canvas canvas;
+  float scale;
+} Context;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Context context_new(uint32_t width, uint32_t height);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* SPLIT_BY_MODULE_CRATE_H */
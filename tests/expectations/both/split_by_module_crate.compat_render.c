@@ -0,0 +1,34 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H_RENDER
+#define SPLIT_BY_MODULE_CRATE_H_RENDER
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module_crate.compat_geometry.c"
+
+/**
+ * In a nested module, so it's written with its top-level module, `render`.
+ */
+typedef struct Color {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+} Color;
+
+typedef struct Canvas {
+  Rect bounds;
+  Color color;
+} Canvas;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void canvas_draw_point(Canvas *canvas, Point point);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* SPLIT_BY_MODULE_CRATE_H_RENDER */
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+typedef struct Point {
+  float x;
+  float y;
+} Point;
+
+typedef struct Points {
+  Point points[16];
+  uintptr_t len;
+} Points;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float point_length(Point p);
+
+uintptr_t points_count(const Points *points);
+
+void internal(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,17 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LEN 4
+
+typedef struct Entry {
+  uint32_t key;
+  float value;
+} Entry;
+
+extern const uint32_t TABLE[4];
+
+extern const Entry ENTRIES[LEN];
+
+extern uint8_t GRID[3][2];
@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counters {
+  uint32_t hits;
+  uint32_t misses;
+} Counters;
+
+extern Counters custom_counters;
+
+extern const uint32_t library_version;
+
+extern const uint8_t KEEP_ME;
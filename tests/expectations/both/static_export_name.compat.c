@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Counters {
+  uint32_t hits;
+  uint32_t misses;
+} Counters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern Counters custom_counters;
+
+extern const uint32_t library_version;
+
+extern const uint8_t KEEP_ME;
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct InvalidPadding InvalidPadding;
+
+typedef struct ZeroPadding ZeroPadding;
+
+typedef struct Padded {
+  uint32_t a;
+  uint8_t b;
+  uint8_t _pad[4];
+} Padded;
+
+typedef struct OnlyPadding {
+  uint8_t _pad[16];
+} OnlyPadding;
+
+void root(Padded a, OnlyPadding b, const ZeroPadding *c, const InvalidPadding *d);
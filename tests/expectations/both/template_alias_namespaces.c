@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Vec2_i32 {
+  int32_t x;
+  int32_t y;
+} Vec2_i32;
+
+typedef Vec2_i32 IntVec;
+
+typedef struct Vec2_f32 {
+  float x;
+  float y;
+} Vec2_f32;
+
+typedef Vec2_f32 FloatVec;
+
+typedef struct Pair_u32__IntVec {
+  uint32_t key;
+  IntVec value;
+} Pair_u32__IntVec;
+
+typedef Pair_u32__IntVec Entry;
+
+typedef struct Vec2_u8 {
+  uint8_t x;
+  uint8_t y;
+} Vec2_u8;
+
+void root(IntVec a, FloatVec b, Entry c, Vec2_u8 d);
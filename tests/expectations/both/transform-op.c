@@ -17,7 +17,7 @@ enum StyleFoo_i32_Tag {
   Foo_i32,
   Bar_i32,
   Baz_i32,
-  Bazz_i32,
+  Bazz_i32
 };
 typedef uint8_t StyleFoo_i32_Tag;
 
@@ -49,7 +49,7 @@ typedef enum StyleBar_i32_Tag {
   Bar1_i32,
   Bar2_i32,
   Bar3_i32,
-  Bar4_i32,
+  Bar4_i32
 } StyleBar_i32_Tag;
 
 typedef struct StyleBar1_Body_i32 {
@@ -85,7 +85,7 @@ typedef enum StyleBar_u32_Tag {
   Bar1_u32,
   Bar2_u32,
   Bar3_u32,
-  Bar4_u32,
+  Bar4_u32
 } StyleBar_u32_Tag;
 
 typedef struct StyleBar1_Body_u32 {
@@ -115,7 +115,7 @@ typedef struct StyleBar_u32 {
 enum StyleBaz_Tag {
   Baz1,
   Baz2,
-  Baz3,
+  Baz3
 };
 typedef uint8_t StyleBaz_Tag;
 
@@ -138,7 +138,7 @@ typedef union StyleBaz {
 enum StyleTaz_Tag {
   Taz1,
   Taz2,
-  Taz3,
+  Taz3
 };
 typedef uint8_t StyleTaz_Tag;
 
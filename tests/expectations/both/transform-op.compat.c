@@ -21,7 +21,7 @@ enum StyleFoo_i32_Tag
   Foo_i32,
   Bar_i32,
   Baz_i32,
-  Bazz_i32,
+  Bazz_i32
 };
 #ifndef __cplusplus
 typedef uint8_t StyleFoo_i32_Tag;
@@ -55,7 +55,7 @@ typedef enum StyleBar_i32_Tag {
   Bar1_i32,
   Bar2_i32,
   Bar3_i32,
-  Bar4_i32,
+  Bar4_i32
 } StyleBar_i32_Tag;
 
 typedef struct StyleBar1_Body_i32 {
@@ -91,7 +91,7 @@ typedef enum StyleBar_u32_Tag {
   Bar1_u32,
   Bar2_u32,
   Bar3_u32,
-  Bar4_u32,
+  Bar4_u32
 } StyleBar_u32_Tag;
 
 typedef struct StyleBar1_Body_u32 {
@@ -125,7 +125,7 @@ enum StyleBaz_Tag
  {
   Baz1,
   Baz2,
-  Baz3,
+  Baz3
 };
 #ifndef __cplusplus
 typedef uint8_t StyleBaz_Tag;
@@ -154,7 +154,7 @@ enum StyleTaz_Tag
  {
   Taz1,
   Taz2,
-  Taz3,
+  Taz3
 };
 #ifndef __cplusplus
 typedef uint8_t StyleTaz_Tag;
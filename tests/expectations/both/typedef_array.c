@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef float Mat4[4][4];
+
+typedef float Row[4];
+
+typedef struct Camera {
+  Mat4 view;
+  Mat4 projection;
+  Row origin;
+} Camera;
+
+void root(Camera camera, const Mat4 *transform);
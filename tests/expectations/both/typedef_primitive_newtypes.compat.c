@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A distance in meters.
+ */
+typedef double Meters;
+
+typedef uint32_t Id;
+#define Id_INVALID 0
+
+typedef struct Handle {
+  uint8_t *_0;
+} Handle;
+
+typedef struct Pair {
+  uint32_t a;
+  uint32_t b;
+} Pair;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Meters m, Id id, Handle h, Pair p);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union MyUnion {
+  uint32_t as_int;
+  float as_float;
+  uint8_t as_bytes[4];
+} MyUnion;
+
+typedef union GenericUnion_f64 {
+  double value;
+  uint64_t raw;
+} GenericUnion_f64;
+
+typedef MyUnion Alias;
+
+MyUnion f(MyUnion u);
+
+GenericUnion_f64 g(GenericUnion_f64 u, Alias alias);
@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+void buffer_clear(Buffer *restrict self);
+
+void copy(Buffer *restrict dst, const Buffer *src, Buffer *raw, uintptr_t *restrict len);
+
+void optional(Buffer *dst);
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+} Buffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void buffer_clear(Buffer *self);
+
+void copy(Buffer *dst, const Buffer *src, Buffer *raw, uintptr_t *len);
+
+void optional(Buffer *dst);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,11 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Has no `#[repr]`, so it can't be passed by value.
+ */
+typedef struct Handle Handle;
+
+uint32_t handle_id(const Handle *h);
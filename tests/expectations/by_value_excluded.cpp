@@ -0,0 +1,14 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Has no `#[repr]`, so it can't be passed by value.
+struct Handle;
+
+extern "C" {
+
+uint32_t handle_id(const Handle *h);
+
+} // extern "C"
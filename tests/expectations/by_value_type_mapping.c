@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint64_t Alias;
+
+void handle_close(Alias h);
+
+uint64_t handle_open(void);
+
+void bits_set(uint32_t bits);
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Alias = uint64_t;
+
+extern "C" {
+
+void handle_close(Alias h);
+
+uint64_t handle_open();
+
+void bits_set(uint32_t bits);
+
+} // extern "C"
@@ -0,0 +1,10 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+const char *name(void);
+
+char *name_copy(void);
+
+void set_name(const char *name, uint32_t initial);
@@ -0,0 +1,15 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+const char *name();
+
+char *name_copy();
+
+void set_name(const char *name, uint32_t initial);
+
+} // extern "C"
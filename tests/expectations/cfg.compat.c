@@ -11,7 +11,7 @@ enum BarType
  {
   A,
   B,
-  C,
+  C
 };
 #ifndef __cplusplus
 typedef uint32_t BarType;
@@ -26,7 +26,7 @@ enum FooType
  {
   A,
   B,
-  C,
+  C
 };
 #ifndef __cplusplus
 typedef uint32_t FooType;
@@ -52,7 +52,7 @@ enum C_Tag
   C3,
 #endif
 #if defined(PLATFORM_UNIX)
-  C5,
+  C5
 #endif
 };
 #ifndef __cplusplus
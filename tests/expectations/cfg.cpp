@@ -8,7 +8,7 @@
 enum class BarType : uint32_t {
   A,
   B,
-  C,
+  C
 };
 #endif
 
@@ -16,7 +16,7 @@ enum class BarType : uint32_t {
 enum class FooType : uint32_t {
   A,
   B,
-  C,
+  C
 };
 #endif
 
@@ -47,7 +47,7 @@ union C {
     C3,
 #endif
 #if defined(PLATFORM_UNIX)
-    C5,
+    C5
 #endif
   };
 
@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t data[4];
+  uintptr_t len;
+} Buf_4;
+
+typedef Buf_4 SmallBuf;
+
+typedef struct {
+  uint8_t data[16];
+  uintptr_t len;
+} Buf_16;
+
+typedef struct {
+  uint32_t header;
+  Buf_16 payload;
+} Packet_16;
+
+typedef struct {
+  float rows[2][3];
+} Matrix_f32__2__3;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(SmallBuf small, const Packet_16 *packet, Matrix_f32__2__3 matrix);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<uintptr_t N>
+struct Buf {
+  uint8_t data[N];
+  uintptr_t len;
+};
+
+using SmallBuf = Buf<4>;
+
+template<uintptr_t N>
+struct Packet {
+  uint32_t header;
+  Buf<N> payload;
+};
+
+template<typename T, uintptr_t ROWS, uintptr_t COLS>
+struct Matrix {
+  T rows[ROWS][COLS];
+};
+
+extern "C" {
+
+void root(SmallBuf small, const Packet<16> *packet, Matrix<float, 2, 3> matrix);
+
+} // extern "C"
@@ -0,0 +1,37 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Node {
+  int32_t value;
+};
+
+struct Pointers {
+  const uint8_t *const_prim;
+  uint8_t *mut_prim;
+  const Node *const_struct;
+  Node *mut_struct;
+  Node *const *const_to_mut;
+  const Node **mut_to_const;
+  const Node *shared_ref;
+  Node *mut_ref;
+  const Node *nullable;
+};
+
+extern "C" {
+
+const Pointers *pointers(const uint8_t *const_prim,
+                         uint8_t *mut_prim,
+                         const Node *const_struct,
+                         Node *mut_struct,
+                         Node *const *const_to_mut,
+                         const Node **mut_to_const,
+                         const Node *shared_ref,
+                         Node *mut_ref,
+                         const Node *nullable);
+
+Pointers *mut_return();
+
+} // extern "C"
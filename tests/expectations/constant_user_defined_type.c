@@ -4,7 +4,7 @@
 #include <stdlib.h>
 
 typedef enum {
-  V,
+  V
 } E;
 
 typedef struct {
@@ -5,7 +5,7 @@
 #include <new>
 
 enum E {
-  V,
+  V
 };
 
 struct S {
@@ -0,0 +1,86 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Without an integer repr, the enum stays a plain enum.
+ */
+typedef enum {
+  Red,
+  Green
+} Color;
+
+enum Flags
+#ifdef __cplusplus
+  : uint16_t
+#endif // __cplusplus
+ {
+  FlagNone,
+  FlagAll
+};
+#ifndef __cplusplus
+typedef uint16_t Flags;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+enum class Mode : uint8_t
+#else
+enum Mode
+#endif // __cplusplus
+ {
+  Read,
+  Write = 4,
+  Append
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+/**
+ * The tag of an enum with fields stays a plain enum.
+ */
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Shape_Tag tag;
+  float _0;
+} Circle_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  float _0;
+} Square_Body;
+
+typedef union {
+  Shape_Tag tag;
+  Circle_Body circle;
+  Square_Body square;
+} Shape;
+
+typedef struct {
+  Mode mode;
+  Flags flags;
+  Color color;
+  Shape shape;
+} Options;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Mode root(Options options);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
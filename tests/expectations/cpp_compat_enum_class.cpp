@@ -0,0 +1,59 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Without an integer repr, the enum stays a plain enum.
+enum class Color {
+  Red,
+  Green
+};
+
+enum Flags : uint16_t {
+  FlagNone,
+  FlagAll
+};
+
+enum class Mode : uint8_t {
+  Read,
+  Write = 4,
+  Append
+};
+
+/// The tag of an enum with fields stays a plain enum.
+union Shape {
+  enum class Tag : uint8_t {
+    Circle,
+    Square
+  };
+
+  struct Circle_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct Square_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Circle_Body circle;
+  Square_Body square;
+};
+
+struct Options {
+  Mode mode;
+  Flags flags;
+  Color color;
+  Shape shape;
+};
+
+extern "C" {
+
+Mode root(Options options);
+
+} // extern "C"
@@ -0,0 +1,24 @@
+/* Licensed under the MPL 2.0. */
+
+/// Bindings to the frobnicator.
+///
+/// Create a frobnicator with `frob_new`, and release it with `frob_free`.
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A frobnicator.
+struct Frob {
+  uint32_t level;
+};
+
+extern "C" {
+
+Frob *frob_new(uint32_t level);
+
+void frob_free(Frob *frob);
+
+} // extern "C"
@@ -0,0 +1,32 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t LEN = 4;
+
+static const uintptr_t DOUBLE_LEN = (LEN * 2);
+
+enum class Mode : uint8_t {
+  Read,
+  Write
+};
+
+#define MYLIB_Mode_COUNT 2
+
+struct Buffer {
+  uint8_t data[DOUBLE_LEN];
+  Mode mode;
+};
+static const uintptr_t Buffer_CAPACITY = 16;
+
+extern "C" {
+
+int32_t scale_i32(int32_t x, int32_t factor);
+
+float scale_f32(float x, float factor);
+
+void root(Buffer *buffer);
+
+} // extern "C"
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t value;
+} Base;
+
+typedef struct {
+  Base base;
+  Base extra[2];
+} Right;
+
+typedef struct {
+  Base base;
+  const Right *right;
+} Left;
+
+typedef struct {
+  Left left;
+  Right right;
+} Top;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Top top);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
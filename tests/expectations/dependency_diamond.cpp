@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Base {
+  int32_t value;
+};
+
+struct Right {
+  Base base;
+  Base extra[2];
+};
+
+struct Left {
+  Base base;
+  const Right *right;
+};
+
+struct Top {
+  Left left;
+  Right right;
+};
+
+extern "C" {
+
+void root(Top top);
+
+} // extern "C"
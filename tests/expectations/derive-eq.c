@@ -12,7 +12,7 @@ enum Bar_Tag {
   Baz,
   Bazz,
   FooNamed,
-  FooParen,
+  FooParen
 };
 typedef uint8_t Bar_Tag;
 
@@ -23,7 +23,7 @@ union Bar {
     Baz,
     Bazz,
     FooNamed,
-    FooParen,
+    FooParen
   };
 
   struct Bazz_Body {
@@ -5,7 +5,7 @@
 
 enum C {
   X = 2,
-  Y,
+  Y
 };
 typedef uint32_t C;
 
@@ -27,7 +27,7 @@ typedef struct {
 enum F_Tag {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 typedef uint8_t F_Tag;
 
@@ -51,7 +51,7 @@ typedef union {
 enum H_Tag {
   Hello,
   There,
-  Everyone,
+  Everyone
 };
 typedef uint8_t H_Tag;
 
@@ -74,7 +74,7 @@ typedef struct {
 
 enum I_Tag {
   ThereAgain,
-  SomethingElse,
+  SomethingElse
 };
 typedef uint8_t I_Tag;
 
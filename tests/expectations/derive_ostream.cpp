@@ -6,7 +6,7 @@
 
 enum class C : uint32_t {
   X = 2,
-  Y,
+  Y
 };
 
 inline std::ostream& operator<<(std::ostream& stream, const C& instance) {
@@ -51,7 +51,7 @@ union F {
   enum class Tag : uint8_t {
     Foo,
     Bar,
-    Baz,
+    Baz
   };
 
   friend std::ostream& operator<<(std::ostream& stream, const Tag& instance) {
@@ -107,7 +107,7 @@ struct H {
   enum class Tag : uint8_t {
     Hello,
     There,
-    Everyone,
+    Everyone
   };
 
   friend std::ostream& operator<<(std::ostream& stream, const Tag& instance) {
@@ -158,7 +158,7 @@ struct H {
 struct I {
   enum class Tag : uint8_t {
     ThereAgain,
-    SomethingElse,
+    SomethingElse
   };
 
   struct ThereAgain_Body {
@@ -9,7 +9,7 @@
 
 enum FillRule {
   A,
-  B,
+  B
 };
 typedef uint8_t FillRule;
 
@@ -42,7 +42,7 @@ enum Foo_u32_Tag {
   Slice1_u32,
   Slice2_u32,
   Slice3_u32,
-  Slice4_u32,
+  Slice4_u32
 };
 typedef uint8_t Foo_u32_Tag;
 
@@ -90,7 +90,7 @@ enum Baz_i32_Tag {
   Slice21_i32,
   Slice22_i32,
   Slice23_i32,
-  Slice24_i32,
+  Slice24_i32
 };
 typedef uint8_t Baz_i32_Tag;
 
@@ -133,7 +133,7 @@ typedef union {
 enum Taz_Tag {
   Bar3,
   Taz1,
-  Taz3,
+  Taz3
 };
 typedef uint8_t Taz_Tag;
 
@@ -155,7 +155,7 @@ typedef union {
 
 enum Tazz_Tag {
   Bar4,
-  Taz2,
+  Taz2
 };
 typedef uint8_t Tazz_Tag;
 
@@ -171,7 +171,7 @@ typedef union {
 
 enum Tazzz_Tag {
   Bar5,
-  Taz5,
+  Taz5
 };
 typedef uint8_t Tazzz_Tag;
 
@@ -187,7 +187,7 @@ typedef union {
 
 enum Tazzzz_Tag {
   Taz6,
-  Taz7,
+  Taz7
 };
 typedef uint8_t Tazzzz_Tag;
 
@@ -209,7 +209,7 @@ typedef union {
 
 enum Qux_Tag {
   Qux1,
-  Qux2,
+  Qux2
 };
 typedef uint8_t Qux_Tag;
 
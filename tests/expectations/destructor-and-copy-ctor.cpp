@@ -10,7 +10,7 @@
 
 enum class FillRule : uint8_t {
   A,
-  B,
+  B
 };
 
 /// This will have a destructor manually implemented via variant_body, and
@@ -36,7 +36,7 @@ struct Foo {
     Slice1,
     Slice2,
     Slice3,
-    Slice4,
+    Slice4
   };
 
   struct Polygon1_Body {
@@ -185,7 +185,7 @@ union Baz {
     Slice21,
     Slice22,
     Slice23,
-    Slice24,
+    Slice24
   };
 
   struct Polygon21_Body {
@@ -335,7 +335,7 @@ union Taz {
   enum class Tag : uint8_t {
     Bar3,
     Taz1,
-    Taz3,
+    Taz3
   };
 
   struct Taz1_Body {
@@ -421,7 +421,7 @@ union Taz {
 union Tazz {
   enum class Tag : uint8_t {
     Bar4,
-    Taz2,
+    Taz2
   };
 
   struct Taz2_Body {
@@ -466,7 +466,7 @@ union Tazz {
 union Tazzz {
   enum class Tag : uint8_t {
     Bar5,
-    Taz5,
+    Taz5
   };
 
   struct Taz5_Body {
@@ -526,7 +526,7 @@ union Tazzz {
 union Tazzzz {
   enum class Tag : uint8_t {
     Taz6,
-    Taz7,
+    Taz7
   };
 
   struct Taz6_Body {
@@ -602,7 +602,7 @@ union Tazzzz {
 union Qux {
   enum class Tag : uint8_t {
     Qux1,
-    Qux2,
+    Qux2
   };
 
   struct Qux1_Body {
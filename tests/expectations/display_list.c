@@ -20,7 +20,7 @@ typedef struct {
 enum DisplayItem_Tag {
   Fill,
   Image,
-  ClearScreen,
+  ClearScreen
 };
 typedef uint8_t DisplayItem_Tag;
 
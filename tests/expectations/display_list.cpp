@@ -22,7 +22,7 @@ union DisplayItem {
   enum class Tag : uint8_t {
     Fill,
     Image,
-    ClearScreen,
+    ClearScreen
   };
 
   struct Fill_Body {
@@ -0,0 +1,45 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A plain enum.
+enum class Color {
+  Red, ///< Red.
+  Green = 4, ///< Green.
+  Blue
+};
+
+/// A point in space.
+struct Point {
+  float x; ///< The x coordinate.
+  /// The y coordinate.
+  ///
+  /// Spans several lines, so it stays leading.
+  float y;
+  float z;
+};
+
+/// A tagged enum.
+struct Shape {
+  enum class Tag : uint8_t {
+    Circle, ///< A circle.
+    Empty ///< Nothing.
+  };
+
+  struct Circle_Body {
+    float radius; ///< Its radius.
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+  };
+};
+
+extern "C" {
+
+void root(Point p, Color c, Shape s);
+
+} // extern "C"
@@ -7,7 +7,7 @@ enum A {
   a1 = 0,
   a2 = 2,
   a3,
-  a4 = 5,
+  a4 = 5
 };
 typedef uint64_t A;
 
@@ -15,7 +15,7 @@ enum B {
   b1 = 0,
   b2 = 2,
   b3,
-  b4 = 5,
+  b4 = 5
 };
 typedef uint32_t B;
 
@@ -23,7 +23,7 @@ enum C {
   c1 = 0,
   c2 = 2,
   c3,
-  c4 = 5,
+  c4 = 5
 };
 typedef uint16_t C;
 
@@ -31,7 +31,7 @@ enum D {
   d1 = 0,
   d2 = 2,
   d3,
-  d4 = 5,
+  d4 = 5
 };
 typedef uint8_t D;
 
@@ -39,7 +39,7 @@ enum E {
   e1 = 0,
   e2 = 2,
   e3,
-  e4 = 5,
+  e4 = 5
 };
 typedef uintptr_t E;
 
@@ -47,7 +47,7 @@ enum F {
   f1 = 0,
   f2 = 2,
   f3,
-  f4 = 5,
+  f4 = 5
 };
 typedef intptr_t F;
 
@@ -55,13 +55,13 @@ typedef enum {
   l1,
   l2,
   l3,
-  l4,
+  l4
 } L;
 
 enum M {
   m1 = -1,
   m2 = 0,
-  m3 = 1,
+  m3 = 1
 };
 typedef int8_t M;
 
@@ -69,14 +69,14 @@ typedef enum {
   n1,
   n2,
   n3,
-  n4,
+  n4
 } N;
 
 enum O {
   o1,
   o2,
   o3,
-  o4,
+  o4
 };
 typedef int8_t O;
 
@@ -89,7 +89,7 @@ typedef struct Opaque Opaque;
 enum G_Tag {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 typedef uint8_t G_Tag;
 
@@ -113,7 +113,7 @@ typedef union {
 typedef enum {
   H_Foo,
   H_Bar,
-  H_Baz,
+  H_Baz
 } H_Tag;
 
 typedef struct {
@@ -136,7 +136,7 @@ typedef struct {
 enum I_Tag {
   I_Foo,
   I_Bar,
-  I_Baz,
+  I_Baz
 };
 typedef uint8_t I_Tag;
 
@@ -159,7 +159,7 @@ typedef struct {
 
 enum P_Tag {
   P0,
-  P1,
+  P1
 };
 typedef uint8_t P_Tag;
 
@@ -8,69 +8,69 @@ enum class A : uint64_t {
   a1 = 0,
   a2 = 2,
   a3,
-  a4 = 5,
+  a4 = 5
 };
 
 enum class B : uint32_t {
   b1 = 0,
   b2 = 2,
   b3,
-  b4 = 5,
+  b4 = 5
 };
 
 enum class C : uint16_t {
   c1 = 0,
   c2 = 2,
   c3,
-  c4 = 5,
+  c4 = 5
 };
 
 enum class D : uint8_t {
   d1 = 0,
   d2 = 2,
   d3,
-  d4 = 5,
+  d4 = 5
 };
 
 enum class E : uintptr_t {
   e1 = 0,
   e2 = 2,
   e3,
-  e4 = 5,
+  e4 = 5
 };
 
 enum class F : intptr_t {
   f1 = 0,
   f2 = 2,
   f3,
-  f4 = 5,
+  f4 = 5
 };
 
 enum class L {
   l1,
   l2,
   l3,
-  l4,
+  l4
 };
 
 enum class M : int8_t {
   m1 = -1,
   m2 = 0,
-  m3 = 1,
+  m3 = 1
 };
 
 enum N {
   n1,
   n2,
   n3,
-  n4,
+  n4
 };
 
 enum O : int8_t {
   o1,
   o2,
   o3,
-  o4,
+  o4
 };
 
 struct J;
@@ -83,7 +83,7 @@ union G {
   enum class Tag : uint8_t {
     Foo,
     Bar,
-    Baz,
+    Baz
   };
 
   struct Foo_Body {
@@ -108,7 +108,7 @@ struct H {
   enum class Tag {
     H_Foo,
     H_Bar,
-    H_Baz,
+    H_Baz
   };
 
   struct H_Foo_Body {
@@ -131,7 +131,7 @@ struct I {
   enum class Tag : uint8_t {
     I_Foo,
     I_Bar,
-    I_Baz,
+    I_Baz
   };
 
   struct I_Foo_Body {
@@ -153,7 +153,7 @@ struct I {
 struct P {
   enum class Tag : uint8_t {
     P0,
-    P1,
+    P1
   };
 
   struct P0_Body {
@@ -0,0 +1,34 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Color : uint8_t {
+  /// The default color.
+  Color_Red,
+  Color_Green = 4,
+  Color_Blue
+};
+
+enum class Offset : int32_t {
+  Offset_Before = -2,
+  Offset_Here,
+  Offset_After
+};
+
+enum class Plain {
+  Plain_First,
+  Plain_Second
+};
+
+enum class Wide : int64_t {
+  Wide_Small = 1,
+  Wide_Max = 9223372036854775807
+};
+
+extern "C" {
+
+void root(Color c, Offset o, Plain p, Wide w);
+
+} // extern "C"
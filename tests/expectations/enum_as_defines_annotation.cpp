@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A strong typedef of an integer, written as a typedef and defines.
+enum class Handle : uint32_t {
+  Handle_Invalid = 0,
+  Handle_Stdin = 1,
+  Handle_Stdout,
+  Handle_Stderr
+};
+
+/// Written as an `enum`, since the option is disabled.
+enum class Mode : uint8_t {
+  Read,
+  Write
+};
+
+extern "C" {
+
+void root(Handle h, Mode m);
+
+} // extern "C"
@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Color = uint8_t;
+/// The default color.
+static const Color Color_Red = 0;
+static const Color Color_Green = 4;
+static const Color Color_Blue = 5;
+
+using Offset = int32_t;
+static const Offset Offset_Before = -2;
+static const Offset Offset_Here = -1;
+static const Offset Offset_After = 0;
+
+enum class Plain {
+  Plain_First,
+  Plain_Second
+};
+
+extern "C" {
+
+void root(Color c, Offset o, Plain p);
+
+} // extern "C"
@@ -0,0 +1,43 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Fieldless : uint8_t {
+  FieldlessA,
+  FieldlessB
+};
+
+enum class Signed : int16_t {
+  SignedA = -1,
+  SignedB = 1
+};
+
+struct Tagged {
+  enum class Tag : uint32_t {
+    TaggedA,
+    TaggedB,
+    TaggedC
+  };
+
+  struct TaggedA_Body {
+    uint8_t _0;
+  };
+
+  struct TaggedB_Body {
+    int16_t x;
+  };
+
+  Tag tag;
+  union {
+    TaggedA_Body tagged_a;
+    TaggedB_Body tagged_b;
+  };
+};
+
+extern "C" {
+
+void root(Fieldless a, Signed b, Tagged c);
+
+} // extern "C"
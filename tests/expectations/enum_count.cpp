@@ -0,0 +1,63 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Contiguous : uint8_t {
+  A,
+  B,
+  C
+};
+
+#define Contiguous_VARIANT_COUNT 3
+
+enum class Explicit {
+  X = 0,
+  Y = 1,
+  Z = 2
+};
+
+#define Explicit_VARIANT_COUNT 3
+
+enum class Gaps : uint32_t {
+  First = 1,
+  Second = 5
+};
+
+enum class NotCounted {
+  P,
+  Q
+};
+
+union Tagged {
+  enum class Tag : uint8_t {
+    Foo,
+    Bar,
+    Baz
+  };
+
+  struct Foo_Body {
+    Tag tag;
+    int32_t _0;
+  };
+
+  struct Bar_Body {
+    Tag tag;
+    uint8_t x;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Foo_Body foo;
+  Bar_Body bar;
+};
+
+#define Tagged_VARIANT_COUNT 3
+
+extern "C" {
+
+void root(Contiguous a, Explicit b, NotCounted c, Gaps d, Tagged e);
+
+} // extern "C"
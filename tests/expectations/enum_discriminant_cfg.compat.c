@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define UNIX_BASE 4
+
+#if defined(DEFINED)
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Read = 4,
+  Write
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+#endif
+
+#if defined(NOT_DEFINED)
+/**
+ * Opaque, since `windows_base()` can't be evaluated.
+ */
+typedef struct Mode Mode;
+#endif
@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uint8_t UNIX_BASE = 4;
+
+#if defined(DEFINED)
+enum class Mode : uint8_t {
+  Read = 4,
+  Write
+};
+#endif
+
+#if defined(NOT_DEFINED)
+/// Opaque, since `windows_base()` can't be evaluated.
+struct Mode;
+#endif
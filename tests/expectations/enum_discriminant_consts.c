@@ -0,0 +1,58 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define BASE 16
+
+#define SHIFT 2
+
+#define DERIVED (BASE * 2)
+
+#define NOT_AN_INTEGER 1.5
+
+#define LOW 2
+
+#define SIGNED 5
+
+enum Complement {
+  AllOnes = 255,
+  Parenthesized = 254,
+  OfConstant = 253
+};
+typedef uint8_t Complement;
+
+enum Flags {
+  None = 0,
+  First = 4,
+  Second = 8,
+  Both = 12,
+  Derived = 33,
+  Next
+};
+typedef uint32_t Flags;
+
+enum Offsets {
+  Low = -16,
+  Mid = -8,
+  High = 15
+};
+typedef int32_t Offsets;
+
+enum SignedComplement {
+  MinusOne = -1,
+  MinusTwo = -2,
+  OfSigned = -6
+};
+typedef int8_t SignedComplement;
+
+typedef struct FloatConstant FloatConstant;
+
+typedef struct UnknownConstant UnknownConstant;
+
+void root(Flags f,
+          Offsets o,
+          Complement c,
+          SignedComplement s,
+          const UnknownConstant *u,
+          const FloatConstant *x);
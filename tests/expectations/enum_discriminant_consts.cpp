@@ -0,0 +1,59 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uint32_t BASE = 16;
+
+static const uint32_t SHIFT = 2;
+
+static const uint32_t DERIVED = (BASE * 2);
+
+static const float NOT_AN_INTEGER = 1.5;
+
+static const uint8_t LOW = 2;
+
+static const int8_t SIGNED = 5;
+
+enum class Complement : uint8_t {
+  AllOnes = 255,
+  Parenthesized = 254,
+  OfConstant = 253
+};
+
+enum class Flags : uint32_t {
+  None = 0,
+  First = 4,
+  Second = 8,
+  Both = 12,
+  Derived = 33,
+  Next
+};
+
+enum class Offsets : int32_t {
+  Low = -16,
+  Mid = -8,
+  High = 15
+};
+
+enum class SignedComplement : int8_t {
+  MinusOne = -1,
+  MinusTwo = -2,
+  OfSigned = -6
+};
+
+struct FloatConstant;
+
+struct UnknownConstant;
+
+extern "C" {
+
+void root(Flags f,
+          Offsets o,
+          Complement c,
+          SignedComplement s,
+          const UnknownConstant *u,
+          const FloatConstant *x);
+
+} // extern "C"
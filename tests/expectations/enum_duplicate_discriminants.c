@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Level {
+  Low = 3,
+#if defined(DEFINED)
+  Medium = 4,
+#endif
+#if !defined(DEFINED)
+  Middle = 4,
+#endif
+  High
+};
+typedef uint8_t Level;
+
+/**
+ * Variants behind different cfgs may share a value, and are written as they
+ * are, like C allows.
+ */
+typedef enum {
+#if defined(DEFINED)
+  A = 1,
+#endif
+#if !defined(DEFINED)
+  Alias = 1,
+#endif
+  B,
+  C = 3
+} Status;
+
+void root(Status s, Level l);
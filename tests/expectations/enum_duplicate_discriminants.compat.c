@@ -0,0 +1,47 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Level
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Low = 3,
+#if defined(DEFINED)
+  Medium = 4,
+#endif
+#if !defined(DEFINED)
+  Middle = 4,
+#endif
+  High
+};
+#ifndef __cplusplus
+typedef uint8_t Level;
+#endif // __cplusplus
+
+/**
+ * Variants behind different cfgs may share a value, and are written as they
+ * are, like C allows.
+ */
+typedef enum {
+#if defined(DEFINED)
+  A = 1,
+#endif
+#if !defined(DEFINED)
+  Alias = 1,
+#endif
+  B,
+  C = 3
+} Status;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Status s, Level l);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,63 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Level : uint8_t {
+  Low = 3,
+#if defined(DEFINED)
+  Medium = 4,
+#endif
+#if !defined(DEFINED)
+  Middle = 4,
+#endif
+  High
+};
+
+inline std::ostream& operator<<(std::ostream& stream, const Level& instance) {
+  switch (instance) {
+    case Level::Low: stream << "Low"; break;
+#if defined(DEFINED)
+    case Level::Medium: stream << "Medium"; break;
+#endif
+#if !defined(DEFINED)
+    case Level::Middle: stream << "Middle"; break;
+#endif
+    case Level::High: stream << "High"; break;
+  }
+  return stream;
+}
+
+/// Variants behind different cfgs may share a value, and are written as they
+/// are, like C allows.
+enum class Status {
+#if defined(DEFINED)
+  A = 1,
+#endif
+#if !defined(DEFINED)
+  Alias = 1,
+#endif
+  B,
+  C = 3
+};
+
+inline std::ostream& operator<<(std::ostream& stream, const Status& instance) {
+  switch (instance) {
+#if defined(DEFINED)
+    case Status::A: stream << "A"; break;
+#endif
+#if !defined(DEFINED)
+    case Status::Alias: stream << "Alias"; break;
+#endif
+    case Status::B: stream << "B"; break;
+    case Status::C: stream << "C"; break;
+  }
+  return stream;
+}
+
+extern "C" {
+
+void root(Status s, Level l);
+
+} // extern "C"
@@ -13,7 +13,7 @@ union Bar {
   enum class Tag : uint8_t {
     Min,
     Max,
-    Other,
+    Other
   };
 
   struct Min_Body {
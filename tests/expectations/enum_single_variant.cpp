@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A type-level tag.
+enum class Marker {
+  Marker_Only
+};
+
+/// Still written as an `enum`, since it has two variants.
+enum class Pair {
+  Pair_First,
+  Pair_Second
+};
+
+enum class Version : uint8_t {
+  Version_V1 = 1
+};
+
+extern "C" {
+
+void root(Marker m, Version v, Pair p);
+
+} // extern "C"
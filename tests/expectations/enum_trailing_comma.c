@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  First,
+#if defined(DEFINE_SECOND)
+  Second,
+#endif
+} Conditional;
+
+typedef enum {
+  A,
+  /**
+   * The last variant.
+   */
+  B = 5,
+} Plain;
+
+enum Tagged_Tag {
+  Foo,
+  Bar,
+};
+typedef uint8_t Tagged_Tag;
+
+typedef struct {
+  Tagged_Tag tag;
+  int32_t _0;
+} Foo_Body;
+
+typedef struct {
+  Tagged_Tag tag;
+  float x;
+} Bar_Body;
+
+typedef union {
+  Tagged_Tag tag;
+  Foo_Body foo;
+  Bar_Body bar;
+} Tagged;
+
+void root(Plain plain, Tagged tagged, Conditional conditional);
@@ -0,0 +1,47 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Conditional {
+  First,
+#if defined(DEFINE_SECOND)
+  Second,
+#endif
+};
+
+enum class Plain {
+  A,
+  /// The last variant.
+  B = 5,
+};
+
+union Tagged {
+  enum class Tag : uint8_t {
+    Foo,
+    Bar,
+  };
+
+  struct Foo_Body {
+    Tag tag;
+    int32_t _0;
+  };
+
+  struct Bar_Body {
+    Tag tag;
+    float x;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Foo_Body foo;
+  Bar_Body bar;
+};
+
+extern "C" {
+
+void root(Plain plain, Tagged tagged, Conditional conditional);
+
+} // extern "C"
@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum Color {
+  Red,
+  Green,
+  Blue
+} Color;
+
+enum Small
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  One,
+  Two
+};
+#ifndef __cplusplus
+typedef uint8_t Small;
+#endif // __cplusplus
+
+typedef enum Shape_Tag {
+  Circle,
+  Square
+} Shape_Tag;
+
+typedef struct {
+  float _0;
+} Circle_Body;
+
+typedef struct {
+  float _0;
+} Square_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+} Shape;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Color color, Small small, Shape shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,43 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Color {
+  Red,
+  Green,
+  Blue
+};
+
+enum class Small : uint8_t {
+  One,
+  Two
+};
+
+struct Shape {
+  enum class Tag {
+    Circle,
+    Square
+  };
+
+  struct Circle_Body {
+    float _0;
+  };
+
+  struct Square_Body {
+    float _0;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Square_Body square;
+  };
+};
+
+extern "C" {
+
+void root(Color color, Small small, Shape shape);
+
+} // extern "C"
@@ -0,0 +1,37 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum Large : uint64_t {
+  LargeA,
+  LargeB = 1099511627776
+};
+
+enum Medium : int16_t {
+  MediumA = -1,
+  MediumB = 1
+};
+
+enum Small : uint8_t {
+  SmallA,
+  SmallB
+};
+
+/// No width is specified, so the C++ enum is left to the compiler.
+enum Unsized {
+  UnsizedA,
+  UnsizedB
+};
+
+extern "C" {
+
+void root(Small s, Medium m, Large l, Unsized u);
+
+} // extern "C"
+
+#include "testing-helpers.h"
+static_assert(sizeof(Small) == 1, "unexpected size for Small");
+static_assert(sizeof(Medium) == 2, "unexpected size for Medium");
+static_assert(sizeof(Large) == 8, "unexpected size for Large");
@@ -0,0 +1,32 @@
+#define CFG_TARGET_FEATURE_AVX 1
+#define CFG_FEATURE_EXTRA 0
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if !defined(CFG_TARGET_FEATURE_AVX)
+#error "Define CFG_TARGET_FEATURE_AVX to 0 or 1, for `cfg(target_feature = \"avx\")`"
+#elif (defined(DEFINE_UNIX) && CFG_TARGET_FEATURE_AVX)
+typedef struct {
+  float lanes[8];
+} Wide;
+#endif
+
+#if !defined(CFG_TARGET_FEATURE_AVX)
+#error "Define CFG_TARGET_FEATURE_AVX to 0 or 1, for `cfg(target_feature = \"avx\")`"
+#elif !(defined(DEFINE_UNIX) && CFG_TARGET_FEATURE_AVX)
+typedef struct {
+  float lanes[4];
+} Wide;
+#endif
+
+#if !defined(CFG_FEATURE_EXTRA)
+#error "Define CFG_FEATURE_EXTRA to 0 or 1, for `cfg(feature = \"extra\")`"
+#elif CFG_FEATURE_EXTRA
+void extra(const Wide *wide);
+#endif
+
+void root(const Wide *wide);
@@ -0,0 +1,37 @@
+#define CFG_TARGET_FEATURE_AVX 1
+#define CFG_FEATURE_EXTRA 0
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if !defined(CFG_TARGET_FEATURE_AVX)
+#error "Define CFG_TARGET_FEATURE_AVX to 0 or 1, for `cfg(target_feature = \"avx\")`"
+#elif (defined(DEFINE_UNIX) && CFG_TARGET_FEATURE_AVX)
+struct Wide {
+  float lanes[8];
+};
+#endif
+
+#if !defined(CFG_TARGET_FEATURE_AVX)
+#error "Define CFG_TARGET_FEATURE_AVX to 0 or 1, for `cfg(target_feature = \"avx\")`"
+#elif !(defined(DEFINE_UNIX) && CFG_TARGET_FEATURE_AVX)
+struct Wide {
+  float lanes[4];
+};
+#endif
+
+extern "C" {
+
+#if !defined(CFG_FEATURE_EXTRA)
+#error "Define CFG_FEATURE_EXTRA to 0 or 1, for `cfg(feature = \"extra\")`"
+#elif CFG_FEATURE_EXTRA
+void extra(const Wide *wide);
+#endif
+
+void root(const Wide *wide);
+
+} // extern "C"
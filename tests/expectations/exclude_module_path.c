@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  bool verbose;
+} Config;
+
+void root_function(void);
+
+void api_configure(const Config *config);
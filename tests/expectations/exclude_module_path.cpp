@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Config {
+  bool verbose;
+};
+
+extern "C" {
+
+void root_function();
+
+void api_configure(const Config *config);
+
+} // extern "C"
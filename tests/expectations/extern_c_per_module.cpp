@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+extern const uint32_t VERSION;
+
+void init();
+
+} // extern "C"
+
+extern "C" {
+
+extern uintptr_t BUFFER_SIZE;
+
+void flush();
+
+} // extern "C"
+
+extern "C" {
+
+int32_t add(int32_t a, int32_t b);
+
+int32_t sub(int32_t a, int32_t b);
+
+} // extern "C"
@@ -0,0 +1,51 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Header {
+  uint8_t zeta;
+  uint32_t alpha;
+  uint16_t mu;
+  uint64_t beta;
+};
+
+struct Pair {
+  uint64_t _0;
+  uint8_t _1;
+};
+
+union Payload {
+  uint32_t zz;
+  float aa;
+};
+
+struct Message {
+  enum class Tag {
+    Ping,
+    Data
+  };
+
+  struct Ping_Body {
+    uint32_t seq;
+    uint64_t at;
+  };
+
+  struct Data_Body {
+    Header _0;
+    Payload _1;
+  };
+
+  Tag tag;
+  union {
+    Ping_Body ping;
+    Data_Body data;
+  };
+};
+
+extern "C" {
+
+void root(Header h, Pair p, Message m);
+
+} // extern "C"
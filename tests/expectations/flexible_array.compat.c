@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct NotLast NotLast;
+
+typedef struct NotZeroLength NotZeroLength;
+
+typedef struct {
+  uintptr_t len;
+  uint8_t data[];
+} ZeroLength;
+
+typedef struct {
+  uint32_t len;
+  int32_t items[];
+} Marked;
+
+typedef struct {
+  uint32_t len;
+  uint16_t data[0];
+} Unmarked;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(const ZeroLength *a,
+          const Marked *b,
+          const NotLast *c,
+          const Unmarked *d,
+          const NotZeroLength *e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
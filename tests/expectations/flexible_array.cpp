@@ -0,0 +1,34 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct NotLast;
+
+struct NotZeroLength;
+
+struct ZeroLength {
+  uintptr_t len;
+  uint8_t data[0];
+};
+
+struct Marked {
+  uint32_t len;
+  int32_t items[0];
+};
+
+struct Unmarked {
+  uint32_t len;
+  uint16_t data[0];
+};
+
+extern "C" {
+
+void root(const ZeroLength *a,
+          const Marked *b,
+          const NotLast *c,
+          const Unmarked *d,
+          const NotZeroLength *e);
+
+} // extern "C"
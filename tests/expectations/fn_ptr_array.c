@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  void (*handlers[4])(void);
+  uint8_t (*callbacks[2])(int32_t);
+  int32_t *(*nested[3][2])(const uint8_t*);
+  void (*const (*ptr)[4])(void);
+} Vtable;
+
+void root(Vtable v);
@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Vtable {
+  void (*handlers[4])();
+  uint8_t (*callbacks[2])(int32_t);
+  int32_t *(*nested[3][2])(const uint8_t*);
+  void (*const (*ptr)[4])();
+};
+
+extern "C" {
+
+void root(Vtable v);
+
+} // extern "C"
@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uintptr_t len;
+} Buffer;
+
+void set_flags(uint32_t, bool);
+
+int32_t buffer_fill(Buffer*, const uint8_t*, void(*)(uintptr_t written));
+
+void no_args(void);
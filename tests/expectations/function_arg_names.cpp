@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffer {
+  uintptr_t len;
+};
+
+extern "C" {
+
+void set_flags(uint32_t, bool);
+
+int32_t buffer_fill(Buffer*, const uint8_t*, void(*)(uintptr_t written));
+
+void no_args();
+
+} // extern "C"
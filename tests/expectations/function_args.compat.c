@@ -3,16 +3,25 @@
 #include <stdint.h>
 #include <stdlib.h>
 
+typedef struct {
+  uint32_t first;
+  uint32_t second;
+} Pair;
+
 #ifdef __cplusplus
 extern "C" {
 #endif // __cplusplus
 
-void unnamed(const uint64_t*);
+void unnamed(const uint64_t *arg0);
 
 void pointer_test(const uint64_t *a);
 
 void print_from_rust(void);
 
+void f(uint32_t arg0, uint32_t x);
+
+void destructured(Pair arg0, uint32_t arg1_, uint32_t arg1);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
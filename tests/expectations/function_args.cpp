@@ -4,12 +4,21 @@
 #include <ostream>
 #include <new>
 
+struct Pair {
+  uint32_t first;
+  uint32_t second;
+};
+
 extern "C" {
 
-void unnamed(const uint64_t*);
+void unnamed(const uint64_t *arg0);
 
 void pointer_test(const uint64_t *a);
 
 void print_from_rust();
 
+void f(uint32_t arg0, uint32_t x);
+
+void destructured(Pair arg0, uint32_t arg1_, uint32_t arg1);
+
 } // extern "C"
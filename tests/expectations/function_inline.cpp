@@ -0,0 +1,18 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+/// `#[inline]` doesn't change the declaration.
+int32_t hinted(int32_t x);
+
+int32_t always(int32_t x);
+
+int32_t never(int32_t x);
+
+int32_t plain(int32_t x);
+
+} // extern "C"
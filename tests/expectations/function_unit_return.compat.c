@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef void (*Callback)(int32_t);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void implicit_unit(int32_t x);
+
+void explicit_unit(int32_t x);
+
+void no_args(void);
+
+void with_callback(Callback callback, void (*fallback)(void));
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
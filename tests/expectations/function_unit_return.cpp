@@ -0,0 +1,19 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Callback = void(*)(int32_t);
+
+extern "C" {
+
+void implicit_unit(int32_t x);
+
+void explicit_unit(int32_t x);
+
+void no_args();
+
+void with_callback(Callback callback, void (*fallback)());
+
+} // extern "C"
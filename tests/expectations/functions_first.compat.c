@@ -0,0 +1,66 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Solid,
+  Dashed
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef enum {
+  A,
+  B
+} Plain;
+
+typedef struct Handle Handle;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
+typedef const Point *Points;
+
+typedef enum {
+  Dot,
+  Line
+} Shape_Tag;
+
+typedef struct {
+  Point _0;
+} Dot_Body;
+
+typedef struct {
+  Point _0;
+  Point _1;
+} Line_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    Dot_Body dot;
+    Line_Body line;
+  };
+} Shape;
+
+typedef float Meters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const Point ORIGIN;
+
+Point draw(Handle *h, Points p, Kind k, Shape s, Meters m, Plain plain);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,62 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Kind : uint8_t;
+
+enum class Plain {
+  A,
+  B
+};
+
+struct Handle;
+
+struct Point;
+
+using Points = const Point*;
+
+struct Shape;
+
+using Meters = float;
+
+extern "C" {
+
+extern const Point ORIGIN;
+
+Point draw(Handle *h, Points p, Kind k, Shape s, Meters m, Plain plain);
+
+} // extern "C"
+
+enum class Kind : uint8_t {
+  Solid,
+  Dashed
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+struct Shape {
+  enum class Tag {
+    Dot,
+    Line
+  };
+
+  struct Dot_Body {
+    Point _0;
+  };
+
+  struct Line_Body {
+    Point _0;
+    Point _1;
+  };
+
+  Tag tag;
+  union {
+    Dot_Body dot;
+    Line_Body line;
+  };
+};
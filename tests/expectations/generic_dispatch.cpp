@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Vec2 {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+int32_t add_i32(int32_t a, int32_t b);
+
+float add_f32(float a, float b);
+
+float length_Vec2(const Vec2 *v);
+
+double length_f64(double x);
+
+void scale_vector(Vec2 *v, float factor);
+
+void scale_number(double *x, float factor);
+
+/// The only function named after its first argument type with this name, so
+/// it isn't grouped.
+int64_t negate_i64(int64_t x);
+
+} // extern "C"
@@ -0,0 +1,77 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  None_i32,
+  Some_i32
+} Opt_i32_Tag;
+
+typedef struct {
+  int32_t _0;
+} Some_Body_i32;
+
+typedef struct {
+  Opt_i32_Tag tag;
+  union {
+    Some_Body_i32 some;
+  };
+} Opt_i32;
+
+typedef Opt_i32 IntOption;
+
+enum Tagged_f32_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A_f32,
+  B_f32
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_f32_Tag;
+#endif // __cplusplus
+
+typedef struct {
+  Tagged_f32_Tag tag;
+  float _0;
+} A_Body_f32;
+
+typedef struct {
+  Tagged_f32_Tag tag;
+  float x;
+  uint8_t y;
+} B_Body_f32;
+
+typedef union {
+  Tagged_f32_Tag tag;
+  A_Body_f32 a;
+  B_Body_f32 b;
+} Tagged_f32;
+
+typedef enum {
+  None______u8,
+  Some______u8
+} Opt______u8_Tag;
+
+typedef struct {
+  const uint8_t *_0;
+} Some_Body______u8;
+
+typedef struct {
+  Opt______u8_Tag tag;
+  union {
+    Some_Body______u8 some;
+  };
+} Opt______u8;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(IntOption a, Tagged_f32 b, Opt______u8 c);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,55 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Opt {
+  enum class Tag {
+    None,
+    Some
+  };
+
+  struct Some_Body {
+    T _0;
+  };
+
+  Tag tag;
+  union {
+    Some_Body some;
+  };
+};
+
+using IntOption = Opt<int32_t>;
+
+template<typename T>
+union Tagged {
+  enum class Tag : uint8_t {
+    A,
+    B
+  };
+
+  struct A_Body {
+    Tag tag;
+    T _0;
+  };
+
+  struct B_Body {
+    Tag tag;
+    T x;
+    uint8_t y;
+  };
+
+  struct {
+    Tag tag;
+  };
+  A_Body a;
+  B_Body b;
+};
+
+extern "C" {
+
+void root(IntOption a, Tagged<float> b, Opt<const uint8_t*> c);
+
+} // extern "C"
@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Registry Registry;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+/**
+ * Needs `Point` by value, so `Point` is moved up among the typedefs.
+ */
+typedef Point Position;
+
+/**
+ * Only uses `Registry` behind a pointer, so it stays with the typedefs, and
+ * `Registry` is forward-declared.
+ */
+typedef Registry *RegistryPtr;
+
+enum Shape {
+  Circle,
+  Square
+};
+typedef uint8_t Shape;
+
+typedef struct {
+  Position position;
+  Shape shape;
+} Node;
+
+struct Registry {
+  Node *first;
+  uintptr_t len;
+};
+
+typedef struct Handle Handle;
+
+Node *registry_first(RegistryPtr registry, Handle *handle);
@@ -0,0 +1,42 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Registry;
+
+struct Point {
+  float x;
+  float y;
+};
+
+/// Needs `Point` by value, so `Point` is moved up among the typedefs.
+using Position = Point;
+
+/// Only uses `Registry` behind a pointer, so it stays with the typedefs, and
+/// `Registry` is forward-declared.
+using RegistryPtr = Registry*;
+
+enum class Shape : uint8_t {
+  Circle,
+  Square
+};
+
+struct Node {
+  Position position;
+  Shape shape;
+};
+
+struct Registry {
+  Node *first;
+  uintptr_t len;
+};
+
+struct Handle;
+
+extern "C" {
+
+Node *registry_first(RegistryPtr registry, Handle *handle);
+
+} // extern "C"
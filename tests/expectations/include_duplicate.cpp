@@ -0,0 +1,13 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <stdint.h>
+#include <math.h>
+
+extern "C" {
+
+double root(uint32_t x);
+
+} // extern "C"
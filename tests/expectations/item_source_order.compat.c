@@ -0,0 +1,62 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  int32_t value;
+} Zebra;
+
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Fast,
+  Slow
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+typedef struct {
+  Zebra zebra;
+} Inner;
+
+typedef struct {
+  float first;
+  float second;
+} Pair_f32;
+
+/**
+ * Uses a type which is defined after it.
+ */
+typedef struct {
+  Inner inner;
+  Mode mode;
+  Pair_f32 pair;
+} Outer;
+
+typedef struct Opaque Opaque;
+
+typedef void (*Callback)(const Opaque*);
+
+typedef union {
+  int32_t as_int;
+  float as_float;
+} Value;
+
+typedef enum {
+  A,
+  B
+} Alpha;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Outer outer, Callback callback, Value value, Alpha alpha);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,51 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Zebra {
+  int32_t value;
+};
+
+enum class Mode : uint8_t {
+  Fast,
+  Slow
+};
+
+struct Inner {
+  Zebra zebra;
+};
+
+template<typename T>
+struct Pair {
+  T first;
+  T second;
+};
+
+/// Uses a type which is defined after it.
+struct Outer {
+  Inner inner;
+  Mode mode;
+  Pair<float> pair;
+};
+
+struct Opaque;
+
+using Callback = void(*)(const Opaque*);
+
+union Value {
+  int32_t as_int;
+  float as_float;
+};
+
+enum class Alpha {
+  A,
+  B
+};
+
+extern "C" {
+
+void root(Outer outer, Callback callback, Value value, Alpha alpha);
+
+} // extern "C"
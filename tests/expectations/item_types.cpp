@@ -6,5 +6,5 @@
 
 enum class OnlyThisShouldBeGenerated : uint8_t {
   Foo,
-  Bar,
+  Bar
 };
@@ -6,5 +6,5 @@
 
 enum class StyleOnlyThisShouldBeGenerated : uint8_t {
   Foo,
-  Bar,
+  Bar
 };
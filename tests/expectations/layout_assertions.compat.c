@@ -0,0 +1,149 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LEN 3
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+typedef struct {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+} Fixed;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Fixed) == 12, "unexpected size of Fixed");
+_Static_assert(_Alignof(Fixed) == 4, "unexpected alignment of Fixed");
+#endif // __cplusplus
+
+typedef struct {
+  Kind kind;
+  Fixed fixed;
+  uint8_t bytes[LEN];
+} Nested;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Nested) == 20, "unexpected size of Nested");
+_Static_assert(_Alignof(Nested) == 4, "unexpected alignment of Nested");
+#endif // __cplusplus
+
+typedef union {
+  int32_t i;
+  float f;
+  uint8_t b[6];
+} Number;
+
+typedef struct {
+  uint8_t tag;
+  Number number;
+} WithUnion;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(WithUnion) == 12, "unexpected size of WithUnion");
+_Static_assert(_Alignof(WithUnion) == 4, "unexpected alignment of WithUnion");
+#endif // __cplusplus
+
+typedef struct CBINDGEN_PACKED {
+  uint8_t a;
+  uint32_t b;
+} Packed;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Packed) == 5, "unexpected size of Packed");
+_Static_assert(_Alignof(Packed) == 1, "unexpected alignment of Packed");
+#endif // __cplusplus
+
+typedef struct CBINDGEN_ALIGNED(16) {
+  uint8_t a;
+} Aligned;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Aligned) == 16, "unexpected size of Aligned");
+_Static_assert(_Alignof(Aligned) == 16, "unexpected alignment of Aligned");
+#endif // __cplusplus
+
+typedef struct {
+  uint16_t a;
+  uint8_t _pad[3];
+} Padded;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Padded) == 6, "unexpected size of Padded");
+_Static_assert(_Alignof(Padded) == 2, "unexpected alignment of Padded");
+#endif // __cplusplus
+
+typedef struct {
+  uint16_t len;
+  uint32_t data[];
+} Flexible;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Flexible) == 4, "unexpected size of Flexible");
+_Static_assert(_Alignof(Flexible) == 4, "unexpected alignment of Flexible");
+#endif // __cplusplus
+
+typedef struct {
+  uint8_t a;
+  const uint8_t *ptr;
+} WithPointer;
+
+#ifndef __cplusplus
+#if (UINTPTR_MAX == 0xFFFFFFFF)
+_Static_assert(sizeof(WithPointer) == 8, "unexpected size of WithPointer");
+_Static_assert(_Alignof(WithPointer) == 4, "unexpected alignment of WithPointer");
+#endif
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+_Static_assert(sizeof(WithPointer) == 16, "unexpected size of WithPointer");
+_Static_assert(_Alignof(WithPointer) == 8, "unexpected alignment of WithPointer");
+#endif
+#endif // __cplusplus
+
+typedef struct {
+  uint8_t a;
+  uint64_t b;
+} WithU64;
+
+#ifndef __cplusplus
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+_Static_assert(sizeof(WithU64) == 16, "unexpected size of WithU64");
+_Static_assert(_Alignof(WithU64) == 8, "unexpected alignment of WithU64");
+#endif
+#endif // __cplusplus
+
+typedef struct {
+  long a;
+} WithLong;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Nested a,
+          WithUnion b,
+          Packed c,
+          Aligned d,
+          Padded e,
+          const Flexible *f,
+          WithPointer g,
+          WithU64 h,
+          WithLong i);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
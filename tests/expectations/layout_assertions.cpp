@@ -0,0 +1,121 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t LEN = 3;
+
+enum class Kind : uint8_t {
+  A,
+  B
+};
+
+struct Fixed {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+};
+
+static_assert(sizeof(Fixed) == 12, "unexpected size of Fixed");
+static_assert(alignof(Fixed) == 4, "unexpected alignment of Fixed");
+
+struct Nested {
+  Kind kind;
+  Fixed fixed;
+  uint8_t bytes[LEN];
+};
+
+static_assert(sizeof(Nested) == 20, "unexpected size of Nested");
+static_assert(alignof(Nested) == 4, "unexpected alignment of Nested");
+
+union Number {
+  int32_t i;
+  float f;
+  uint8_t b[6];
+};
+
+struct WithUnion {
+  uint8_t tag;
+  Number number;
+};
+
+static_assert(sizeof(WithUnion) == 12, "unexpected size of WithUnion");
+static_assert(alignof(WithUnion) == 4, "unexpected alignment of WithUnion");
+
+struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint32_t b;
+};
+
+static_assert(sizeof(Packed) == 5, "unexpected size of Packed");
+static_assert(alignof(Packed) == 1, "unexpected alignment of Packed");
+
+struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+};
+
+static_assert(sizeof(Aligned) == 16, "unexpected size of Aligned");
+static_assert(alignof(Aligned) == 16, "unexpected alignment of Aligned");
+
+struct Padded {
+  uint16_t a;
+  uint8_t _pad[3];
+};
+
+static_assert(sizeof(Padded) == 6, "unexpected size of Padded");
+static_assert(alignof(Padded) == 2, "unexpected alignment of Padded");
+
+struct Flexible {
+  uint16_t len;
+  uint32_t data[0];
+};
+
+static_assert(sizeof(Flexible) == 4, "unexpected size of Flexible");
+static_assert(alignof(Flexible) == 4, "unexpected alignment of Flexible");
+
+struct WithPointer {
+  uint8_t a;
+  const uint8_t *ptr;
+};
+
+#if (UINTPTR_MAX == 0xFFFFFFFF)
+static_assert(sizeof(WithPointer) == 8, "unexpected size of WithPointer");
+static_assert(alignof(WithPointer) == 4, "unexpected alignment of WithPointer");
+#endif
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+static_assert(sizeof(WithPointer) == 16, "unexpected size of WithPointer");
+static_assert(alignof(WithPointer) == 8, "unexpected alignment of WithPointer");
+#endif
+
+struct WithU64 {
+  uint8_t a;
+  uint64_t b;
+};
+
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+static_assert(sizeof(WithU64) == 16, "unexpected size of WithU64");
+static_assert(alignof(WithU64) == 8, "unexpected alignment of WithU64");
+#endif
+
+struct WithLong {
+  long a;
+};
+
+extern "C" {
+
+void root(Nested a,
+          WithUnion b,
+          Packed c,
+          Aligned d,
+          Padded e,
+          const Flexible *f,
+          WithPointer g,
+          WithU64 h,
+          WithLong i);
+
+} // extern "C"
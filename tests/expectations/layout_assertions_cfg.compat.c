@@ -0,0 +1,37 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if defined(SAMPLE_WIDE)
+typedef struct {
+  uint32_t a;
+  uint32_t b;
+} Sample;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Sample) == 8, "unexpected size of Sample");
+_Static_assert(_Alignof(Sample) == 4, "unexpected alignment of Sample");
+#endif // __cplusplus
+#endif
+
+#if !defined(SAMPLE_WIDE)
+typedef struct {
+  uint8_t a;
+} Sample;
+
+#ifndef __cplusplus
+_Static_assert(sizeof(Sample) == 1, "unexpected size of Sample");
+_Static_assert(_Alignof(Sample) == 1, "unexpected alignment of Sample");
+#endif // __cplusplus
+#endif
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void sample_read(const Sample *sample);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
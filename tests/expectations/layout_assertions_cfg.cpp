@@ -0,0 +1,30 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if defined(SAMPLE_WIDE)
+struct Sample {
+  uint32_t a;
+  uint32_t b;
+};
+
+static_assert(sizeof(Sample) == 8, "unexpected size of Sample");
+static_assert(alignof(Sample) == 4, "unexpected alignment of Sample");
+#endif
+
+#if !defined(SAMPLE_WIDE)
+struct Sample {
+  uint8_t a;
+};
+
+static_assert(sizeof(Sample) == 1, "unexpected size of Sample");
+static_assert(alignof(Sample) == 1, "unexpected alignment of Sample");
+#endif
+
+extern "C" {
+
+void sample_read(const Sample *sample);
+
+} // extern "C"
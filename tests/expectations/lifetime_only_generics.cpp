@@ -0,0 +1,44 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Only has a lifetime parameter, so it's written like a non-generic struct.
+struct Ref {
+  const uint32_t *ptr;
+  uintptr_t len;
+};
+
+struct Pair {
+  Ref first;
+  Ref *second;
+};
+
+struct Borrowed {
+  enum class Tag : uint8_t {
+    Nothing,
+    Value,
+    Slice
+  };
+
+  struct Value_Body {
+    const uint32_t *_0;
+  };
+
+  struct Slice_Body {
+    Ref _0;
+  };
+
+  Tag tag;
+  union {
+    Value_Body value;
+    Slice_Body slice;
+  };
+};
+
+extern "C" {
+
+void root(Pair pair, Borrowed borrowed);
+
+} // extern "C"
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define CAPI_ORIGIN_X 0
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} CAPI_Point;
+
+extern uint32_t COUNT;
+
+extern const uint32_t point_count;
+
+CAPI_Point point_new(int32_t x, int32_t y);
+
+CAPI_Point point_origin(void);
@@ -0,0 +1,24 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const int32_t CAPI_ORIGIN_X = 0;
+
+struct CAPI_Point {
+  int32_t x;
+  int32_t y;
+};
+
+extern "C" {
+
+extern uint32_t COUNT;
+
+extern const uint32_t point_count;
+
+CAPI_Point point_new(int32_t x, int32_t y);
+
+CAPI_Point point_origin();
+
+} // extern "C"
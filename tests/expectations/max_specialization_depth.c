@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint8_t value;
+} Inner_u8;
+
+typedef struct {
+  const Inner_u8 *inner;
+} Middle_u8;
+
+typedef struct {
+  Middle_u8 inner;
+} Outer_u8;
+
+void root(Outer_u8 x);
@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Inner {
+  T value;
+};
+
+template<typename T>
+struct Middle {
+  const Inner<T> *inner;
+};
+
+template<typename T>
+struct Outer {
+  Middle<T> inner;
+};
+
+extern "C" {
+
+void root(Outer<uint8_t> x);
+
+} // extern "C"
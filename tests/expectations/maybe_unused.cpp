@@ -0,0 +1,15 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+extern "C" {
+
+[[maybe_unused]] extern const uint32_t DEBUG_LEVEL;
+
+[[maybe_unused]] void debug_hook(uint32_t level);
+
+void always_used();
+
+} // extern "C"
@@ -13,8 +13,14 @@ typedef struct NotReprC_MaybeUninit______i32 NotReprC_MaybeUninit______i32;
 
 typedef NotReprC_MaybeUninit______i32 Foo;
 
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+
 typedef struct {
   const int32_t *number;
+  Point point;
 } MyStruct;
 
 #ifdef __cplusplus
@@ -23,6 +29,8 @@ extern "C" {
 
 void root(const Foo *a, const MyStruct *with_maybe_uninit);
 
+void take(Point with_maybe_uninit);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
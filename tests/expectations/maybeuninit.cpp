@@ -15,12 +15,20 @@ struct NotReprC;
 
 using Foo = NotReprC<MaybeUninit<const int32_t*>>;
 
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
 struct MyStruct {
   MaybeUninit<const int32_t*> number;
+  MaybeUninit<Point> point;
 };
 
 extern "C" {
 
 void root(const Foo *a, const MyStruct *with_maybe_uninit);
 
+void take(MaybeUninit<Point> with_maybe_uninit);
+
 } // extern "C"
@@ -0,0 +1,14 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Conflicts with the `Point` and `first` of `merge_first.rs`.
+ */
+typedef enum {
+  A,
+  B
+} Point;
+
+void first(Point p);
@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Conflicts with the `Point` and `first` of `merge_first.rs`.
+enum class Point {
+  A,
+  B
+};
+
+extern "C" {
+
+void first(Point p);
+
+} // extern "C"
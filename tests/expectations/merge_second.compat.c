@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t w;
+  uint32_t h;
+} Size;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void second(Size s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
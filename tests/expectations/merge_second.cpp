@@ -0,0 +1,16 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Size {
+  uint32_t w;
+  uint32_t h;
+};
+
+extern "C" {
+
+void second(Size s);
+
+} // extern "C"
@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Tree Tree;
+
+typedef struct {
+  uint32_t color;
+} Style;
+
+typedef struct {
+  const Style *style;
+  uint32_t width;
+} Widget;
+
+/**
+ * Typedefs come first with `group_by_kind`, but the structs they point to are
+ * moved up instead of being forward-declared.
+ */
+typedef Widget *WidgetHandle;
+
+typedef void (*VisitCallback)(const Widget *widget, const Style *style);
+
+typedef struct {
+  uint32_t value;
+} Leaf;
+
+typedef void (*Callback)(const Leaf *leaf);
+
+/**
+ * Points to `Tree`, which points back to it, so only one of the two is
+ * forward-declared.
+ */
+typedef struct {
+  const Tree *parent;
+  const Leaf *leaf;
+} Node;
+
+struct Tree {
+  Node *root;
+  Callback callback;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(WidgetHandle handle, VisitCallback visit, const Tree *tree);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
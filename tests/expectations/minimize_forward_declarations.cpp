@@ -0,0 +1,46 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Tree;
+
+struct Style {
+  uint32_t color;
+};
+
+struct Widget {
+  const Style *style;
+  uint32_t width;
+};
+
+/// Typedefs come first with `group_by_kind`, but the structs they point to are
+/// moved up instead of being forward-declared.
+using WidgetHandle = Widget*;
+
+using VisitCallback = void(*)(const Widget *widget, const Style *style);
+
+struct Leaf {
+  uint32_t value;
+};
+
+using Callback = void(*)(const Leaf *leaf);
+
+/// Points to `Tree`, which points back to it, so only one of the two is
+/// forward-declared.
+struct Node {
+  const Tree *parent;
+  const Leaf *leaf;
+};
+
+struct Tree {
+  Node *root;
+  Callback callback;
+};
+
+extern "C" {
+
+void root(WidgetHandle handle, VisitCallback visit, const Tree *tree);
+
+} // extern "C"
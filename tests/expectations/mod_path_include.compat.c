@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t id;
+} Generated;
+
+typedef struct {
+  int32_t fd;
+} Handle;
+
+typedef struct {
+  Handle handle;
+} Device;
+
+typedef struct {
+  float value;
+} Real;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void generated_fn(const Generated *g);
+
+void root(Device a, Generated b);
+
+void real(Real r);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
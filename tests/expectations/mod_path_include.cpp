@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Generated {
+  uint32_t id;
+};
+
+struct Handle {
+  int32_t fd;
+};
+
+struct Device {
+  Handle handle;
+};
+
+struct Real {
+  float value;
+};
+
+extern "C" {
+
+void generated_fn(const Generated *g);
+
+void root(Device a, Generated b);
+
+void real(Real r);
+
+} // extern "C"
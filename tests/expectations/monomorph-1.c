@@ -35,9 +35,9 @@ typedef Tuple_f32__f32 Indirection_f32;
 
 void root(Foo_i32 a,
           Foo_f32 b,
-          Bar_f32 c,
+          const Bar_f32 *c,
           Foo_Bar_f32 d,
-          Bar_Foo_f32 e,
-          Bar_Bar_f32 f,
+          const Bar_Foo_f32 *e,
+          const Bar_Bar_f32 *f,
           Tuple_Foo_f32_____f32 g,
           Indirection_f32 h);
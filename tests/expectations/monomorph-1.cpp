@@ -25,10 +25,10 @@ extern "C" {
 
 void root(Foo<int32_t> a,
           Foo<float> b,
-          Bar<float> c,
+          const Bar<float> *c,
           Foo<Bar<float>> d,
-          Bar<Foo<float>> e,
-          Bar<Bar<float>> f,
+          const Bar<Foo<float>> *e,
+          const Bar<Bar<float>> *f,
           Tuple<Foo<float>, float> g,
           Indirection<float> h);
 
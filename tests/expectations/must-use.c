@@ -10,7 +10,7 @@
 
 enum MaybeOwnedPtr_i32_Tag {
   Owned_i32,
-  None_i32,
+  None_i32
 };
 typedef uint8_t MaybeOwnedPtr_i32_Tag;
 
@@ -14,7 +14,7 @@ enum MaybeOwnedPtr_i32_Tag
 #endif // __cplusplus
  {
   Owned_i32,
-  None_i32,
+  None_i32
 };
 #ifndef __cplusplus
 typedef uint8_t MaybeOwnedPtr_i32_Tag;
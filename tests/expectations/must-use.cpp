@@ -13,7 +13,7 @@ template<typename T>
 struct MUST_USE_STRUCT MaybeOwnedPtr {
   enum class MUST_USE_ENUM Tag : uint8_t {
     Owned,
-    None,
+    None
   };
 
   struct Owned_Body {
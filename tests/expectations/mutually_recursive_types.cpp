@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Node;
+
+struct Graph {
+  Node *root;
+  void (*walk)(const Graph*);
+};
+
+struct Edge {
+  Node *to;
+  const Edge *next;
+  const Graph *graph;
+};
+
+struct Node {
+  Node *parent;
+  const Edge *edges;
+};
+
+extern "C" {
+
+void visit(Node *node);
+
+} // extern "C"
@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float *data;
+  uintptr_t len;
+} Array_f32;
+
+typedef struct {
+  Array_f32 *data;
+  uintptr_t len;
+} Array_Array_f32;
+
+typedef Array_Array_f32 Matrix;
+
+typedef Array_f32 Row;
+
+typedef struct {
+  int32_t inner;
+} Wrap_i32;
+
+typedef struct {
+  Wrap_i32 inner;
+} Wrap_Wrap_i32;
+
+typedef struct {
+  Wrap_Wrap_i32 inner;
+} Wrap_Wrap_Wrap_i32;
+
+typedef Wrap_Wrap_Wrap_i32 Deep;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Matrix m, Row r, Deep d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
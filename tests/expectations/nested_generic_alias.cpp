@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+template<typename T>
+struct Array {
+  T *data;
+  uintptr_t len;
+};
+
+using Matrix = Array<Array<float>>;
+
+using Row = Array<float>;
+
+template<typename T>
+struct Wrap {
+  T inner;
+};
+
+using Deep = Wrap<Wrap<Wrap<int32_t>>>;
+
+extern "C" {
+
+void root(Matrix m, Row r, Deep d);
+
+} // extern "C"
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Event {
+  uint32_t kind;
+};
+
+struct Listener {
+  /// May be null, in which case events are dropped.
+  bool (*on_event)(const Event *event, uint8_t *user_data);
+  void (*on_close)(uint8_t*);
+  void (*on_error)(int32_t code);
+  uint8_t *user_data;
+};
+
+extern "C" {
+
+void listener_attach(Listener *listener);
+
+} // extern "C"
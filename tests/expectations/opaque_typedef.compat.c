@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+typedef struct {
+  Handle *handle;
+  uint32_t flags;
+} Options;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Handle *handle_new(const Options *options);
+
+void handle_free(Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
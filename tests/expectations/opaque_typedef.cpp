@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Handle;
+
+struct Options {
+  Handle *handle;
+  uint32_t flags;
+};
+
+extern "C" {
+
+Handle *handle_new(const Options *options);
+
+void handle_free(Handle *handle);
+
+} // extern "C"
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct BarHandle BarHandle;
+
+typedef struct Baz Baz;
+
+typedef struct Foo Foo;
+
+typedef struct Qux Qux;
+
+Foo *foo_new(void);
+
+void foo_free(Foo *foo);
+
+void bar_release(BarHandle *bar);
+
+/**
+ * Takes the handle by const pointer, so it isn't a destructor.
+ */
+void baz_free(const Baz *baz);
+
+void qux_free(Qux *qux);
@@ -0,0 +1,43 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <memory>
+
+struct BarHandle;
+
+struct Baz;
+
+struct Foo;
+
+struct Qux;
+
+extern "C" {
+
+Foo *foo_new();
+
+void foo_free(Foo *foo);
+
+void bar_release(BarHandle *bar);
+
+/// Takes the handle by const pointer, so it isn't a destructor.
+void baz_free(const Baz *baz);
+
+void qux_free(Qux *qux);
+
+} // extern "C"
+
+struct BarHandleDeleter {
+  void operator()(BarHandle *ptr) const {
+    bar_release(ptr);
+  }
+};
+using BarHandlePtr = std::unique_ptr<BarHandle, BarHandleDeleter>;
+
+struct FooDeleter {
+  void operator()(Foo *ptr) const {
+    foo_free(ptr);
+  }
+};
+using FooPtr = std::unique_ptr<Foo, FooDeleter>;
@@ -0,0 +1,29 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Raw {
+  char c_char;
+  signed char c_schar;
+  unsigned char c_uchar;
+  short c_short;
+  unsigned short c_ushort;
+  int c_int;
+  unsigned int c_uint;
+  long c_long;
+  unsigned long c_ulong;
+  long long c_longlong;
+  unsigned long long c_ulonglong;
+  float c_float;
+  double c_double;
+  signed char core_c_schar;
+  unsigned char libc_c_uchar;
+};
+
+extern "C" {
+
+void root(Raw raw);
+
+} // extern "C"
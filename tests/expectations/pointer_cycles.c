@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Node Node;
+
+typedef struct Owner Owner;
+
+typedef struct {
+  Node *target;
+  float weight;
+} Link;
+
+/**
+ * Holds a `Link` by value, so it's defined after it, and the structs the
+ * link points to are declared before it.
+ */
+struct Node {
+  const Owner *owner;
+  Link link;
+  Node *next;
+};
+
+struct Owner {
+  Node *first;
+  uint32_t count;
+};
+
+void root(Owner owner, Node node);
@@ -0,0 +1,33 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Node;
+
+struct Owner;
+
+struct Link {
+  Node *target;
+  float weight;
+};
+
+/// Holds a `Link` by value, so it's defined after it, and the structs the
+/// link points to are declared before it.
+struct Node {
+  const Owner *owner;
+  Link link;
+  Node *next;
+};
+
+struct Owner {
+  Node *first;
+  uint32_t count;
+};
+
+extern "C" {
+
+void root(Owner owner, Node node);
+
+} // extern "C"
@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffers {
+  const uint8_t (*block)[4];
+  uint16_t (*rows)[3][2];
+  void (*callback)(const uint8_t(*)[4]);
+};
+
+extern "C" {
+
+void by_ptr(const uint8_t (*block)[4]);
+
+void by_ref(const uint8_t (*block)[4], uint16_t (*rows)[3][2]);
+
+uint32_t (*returns_ptr(const Buffers *buffers))[2];
+
+} // extern "C"
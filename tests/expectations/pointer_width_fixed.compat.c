@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint32_t Word;
+
+typedef struct {
+  Word len;
+  uint32_t flags;
+} Header;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+#if defined(DEFINE_NARROW)
+/**
+ * Has a [defines] entry, which takes precedence over `target_pointer_width`.
+ */
+void narrow_only(const Header *header);
+#endif
+
+Word root(const Header *header);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Word = uint32_t;
+
+struct Header {
+  Word len;
+  uint32_t flags;
+};
+
+extern "C" {
+
+#if defined(DEFINE_NARROW)
+/// Has a [defines] entry, which takes precedence over `target_pointer_width`.
+void narrow_only(const Header *header);
+#endif
+
+Word root(const Header *header);
+
+} // extern "C"
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#if (UINTPTR_MAX == 0xFFFFFFFF)
+typedef uint32_t Word;
+#endif
+
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+typedef uint64_t Word;
+#endif
+
+typedef struct {
+  Word len;
+  uint32_t flags;
+} Header;
+
+#if ((UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF) && defined(DEFINE_WIDE))
+void wide_only(const Header *header);
+#endif
+
+Word root(const Header *header);
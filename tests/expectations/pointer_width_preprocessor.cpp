@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+#if (UINTPTR_MAX == 0xFFFFFFFF)
+using Word = uint32_t;
+#endif
+
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+using Word = uint64_t;
+#endif
+
+struct Header {
+  Word len;
+  uint32_t flags;
+};
+
+extern "C" {
+
+#if ((UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF) && defined(DEFINE_WIDE))
+void wide_only(const Header *header);
+#endif
+
+Word root(const Header *header);
+
+} // extern "C"
@@ -20,7 +20,7 @@ enum PREFIX_AbsoluteFontWeight_Tag
  {
   Weight,
   Normal,
-  Bold,
+  Bold
 };
 #ifndef __cplusplus
 typedef uint8_t PREFIX_AbsoluteFontWeight_Tag;
@@ -18,7 +18,7 @@ union PREFIX_AbsoluteFontWeight {
   enum class Tag : uint8_t {
     Weight,
     Normal,
-    Bold,
+    Bold
   };
 
   struct Weight_Body {
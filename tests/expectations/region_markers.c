@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// region: Constants
+
+#define LIMIT 16
+
+// endregion
+
+// region: Types
+
+enum Kind {
+  Small,
+  Large
+};
+typedef uint8_t Kind;
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Point;
+#define Point_ORIGIN (Point){ .x = 0, .y = 0 }
+
+typedef Point Points[4];
+
+// endregion
+
+// region: Globals
+
+extern uint32_t COUNTER;
+
+// endregion
+
+// region: Functions
+
+void root(Point p, Kind k, const Points *ps);
+
+// endregion
@@ -0,0 +1,44 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+// region: Constants
+
+static const uint32_t LIMIT = 16;
+
+// endregion
+
+// region: Types
+
+enum class Kind : uint8_t {
+  Small,
+  Large
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+static const Point Point_ORIGIN = Point{ /* .x = */ 0, /* .y = */ 0 };
+
+using Points = Point[4];
+
+// endregion
+
+extern "C" {
+
+// region: Globals
+
+extern uint32_t COUNTER;
+
+// endregion
+
+// region: Functions
+
+void root(Point p, Kind k, const Points *ps);
+
+// endregion
+
+} // extern "C"
@@ -8,7 +8,7 @@ static const int32_t C_H = 10;
 
 enum class C_E : uint8_t {
   x = 0,
-  y = 1,
+  y = 1
 };
 
 struct C_A;
@@ -33,6 +33,6 @@ extern "C" {
 
 extern const int32_t G;
 
-void root(const C_A *a, C_AwesomeB b, C_C c, C_D d, C_E e, C_F f);
+void root(const C_A *a, C_AwesomeB b, const C_C *c, C_D d, C_E e, const C_F *f);
 
 } // extern "C"
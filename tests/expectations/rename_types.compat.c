@@ -0,0 +1,61 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum gfx_draw_mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Fill,
+  Stroke
+};
+#ifndef __cplusplus
+typedef uint8_t gfx_draw_mode;
+#endif // __cplusplus
+
+typedef struct gfx_opaque_canvas gfx_opaque_canvas;
+
+typedef struct {
+  float x;
+  float y;
+} gfx_point_f;
+
+typedef struct {
+  gfx_point_f start;
+  gfx_point_f end;
+} gfx_line_segment;
+
+typedef struct {
+  gfx_point_f first;
+  gfx_point_f second;
+} gfx_pair_point_f;
+
+typedef gfx_pair_point_f gfx_point_pair;
+
+typedef union {
+  int32_t i;
+  float f;
+} gfx_int_or_float;
+
+/**
+ * Explicitly renamed, so not converted.
+ */
+typedef struct {
+  gfx_draw_mode mode;
+} gfx_KeepName;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw_line(gfx_opaque_canvas *canvas,
+               gfx_line_segment line,
+               gfx_point_pair points,
+               gfx_int_or_float value,
+               gfx_KeepName keep);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,50 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class gfx_draw_mode : uint8_t {
+  Fill,
+  Stroke
+};
+
+struct gfx_opaque_canvas;
+
+struct gfx_point_f {
+  float x;
+  float y;
+};
+
+struct gfx_line_segment {
+  gfx_point_f start;
+  gfx_point_f end;
+};
+
+template<typename T>
+struct gfx_pair {
+  T first;
+  T second;
+};
+
+using gfx_point_pair = gfx_pair<gfx_point_f>;
+
+union gfx_int_or_float {
+  int32_t i;
+  float f;
+};
+
+/// Explicitly renamed, so not converted.
+struct gfx_KeepName {
+  gfx_draw_mode mode;
+};
+
+extern "C" {
+
+void draw_line(gfx_opaque_canvas *canvas,
+               gfx_line_segment line,
+               gfx_point_pair points,
+               gfx_int_or_float value,
+               gfx_KeepName keep);
+
+} // extern "C"
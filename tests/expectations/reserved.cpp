@@ -16,7 +16,7 @@ struct B {
 
 struct C {
   enum class Tag : uint8_t {
-    D,
+    D
   };
 
   struct D_Body {
@@ -33,7 +33,7 @@ struct C {
 struct E {
   enum class Tag : uint8_t {
     Double,
-    Float,
+    Float
   };
 
   struct Double_Body {
@@ -54,7 +54,7 @@ struct E {
 struct F {
   enum class Tag : uint8_t {
     double_,
-    float_,
+    float_
   };
 
   struct double_Body {
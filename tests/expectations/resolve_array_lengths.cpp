@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t MAX_LEN = 32;
+
+static const uintptr_t ALIAS_LEN = MAX_LEN;
+
+static const uintptr_t SHIFTED_LEN = (1 << 2);
+
+struct Buffer {
+  uint8_t data[32];
+  uint8_t aliased[32];
+  uint16_t nested[2][32];
+  uint8_t unresolved[SHIFTED_LEN];
+};
+
+using Name = uint8_t[32];
+
+extern "C" {
+
+void root(Buffer buffer, const Name *name);
+
+} // extern "C"
@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef enum {
+  Ok,
+  InvalidArgument,
+  OutOfMemory
+} ErrorCode;
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+ErrorCode reset_ffi(void);
+
+ErrorCode parse_count_ffi(const uint8_t *text, uintptr_t len, uint32_t *out);
+
+int32_t make_point_ffi(float x, float y, Point *out);
+
+ErrorCode find_point_ffi(uint32_t id, const Point **out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,28 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class ErrorCode {
+  Ok,
+  InvalidArgument,
+  OutOfMemory
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+ErrorCode reset_ffi();
+
+ErrorCode parse_count_ffi(const uint8_t *text, uintptr_t len, uint32_t *out);
+
+int32_t make_point_ffi(float x, float y, Point *out);
+
+ErrorCode find_point_ffi(uint32_t id, const Point **out);
+
+} // extern "C"
@@ -9,7 +9,7 @@ enum class A : uint8_t {
   A_A2,
   A_A3,
   /// Must be last for serialization purposes
-  A_Sentinel,
+  A_Sentinel
 };
 
 enum class B : uint8_t {
@@ -17,7 +17,7 @@ enum class B : uint8_t {
   B_B2,
   B_B3,
   /// Must be last for serialization purposes
-  B_Sentinel,
+  B_Sentinel
 };
 
 union C {
@@ -26,7 +26,7 @@ union C {
     C_C2,
     C_C3,
     /// Must be last for serialization purposes
-    C_Sentinel,
+    C_Sentinel
   };
 
   struct C_C1_Body {
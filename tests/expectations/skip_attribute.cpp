@@ -0,0 +1,17 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Exported {
+  int32_t x;
+};
+
+extern "C" {
+
+void root(Exported e);
+
+int32_t handle_new();
+
+} // extern "C"
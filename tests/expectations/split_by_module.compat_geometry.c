@@ -0,0 +1,21 @@
+#ifndef SPLIT_BY_MODULE_H_GEOMETRY
+#define SPLIT_BY_MODULE_H_GEOMETRY
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point origin;
+  Point size;
+} Rect;
+
+#endif /* SPLIT_BY_MODULE_H_GEOMETRY */
@@ -0,0 +1,31 @@
+#ifndef SPLIT_BY_MODULE_H
+#define SPLIT_BY_MODULE_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include "split_by_module_geometry.cpp"
+#include "split_by_module_limits.cpp"
+#include "split_by_module_render.cpp"
+
+struct Context {
+  Canvas canvas;
+  float scale;
+};
+
+/// Only uses `limits` for the length of its array.
+struct Name {
+  uint8_t chars[NAME_LEN];
+};
+
+extern "C" {
+
+Context context_new(uint32_t width, uint32_t height);
+
+void name_init(Name *name);
+
+} // extern "C"
+
+#endif // SPLIT_BY_MODULE_H
@@ -0,0 +1,18 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H
+#define SPLIT_BY_MODULE_CRATE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module_crate_geometry.c"
+#include "split_by_module_crate_render.c"
+
+typedef struct {
+  Canvas canvas;
+  float scale;
+} Context;
+
+Context context_new(uint32_t width, uint32_t height);
+
+#endif /* SPLIT_BY_MODULE_CRATE_H */
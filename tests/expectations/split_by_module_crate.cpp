@@ -0,0 +1,23 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H
+#define SPLIT_BY_MODULE_CRATE_H
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include "split_by_module_crate_geometry.cpp"
+#include "split_by_module_crate_render.cpp"
+
+struct Context {
+  Canvas canvas;
+  float scale;
+};
+
+extern "C" {
+
+Context context_new(uint32_t width, uint32_t height);
+
+} // extern "C"
+
+#endif // SPLIT_BY_MODULE_CRATE_H
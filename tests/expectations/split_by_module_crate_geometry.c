@@ -0,0 +1,19 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H_GEOMETRY
+#define SPLIT_BY_MODULE_CRATE_H_GEOMETRY
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point origin;
+  Point size;
+} Rect;
+
+#endif /* SPLIT_BY_MODULE_CRATE_H_GEOMETRY */
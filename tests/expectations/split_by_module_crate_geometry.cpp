@@ -0,0 +1,20 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H_GEOMETRY
+#define SPLIT_BY_MODULE_CRATE_H_GEOMETRY
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Rect {
+  Point origin;
+  Point size;
+};
+
+#endif // SPLIT_BY_MODULE_CRATE_H_GEOMETRY
@@ -0,0 +1,22 @@
+#ifndef SPLIT_BY_MODULE_H_GEOMETRY
+#define SPLIT_BY_MODULE_H_GEOMETRY
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t MAX_POINTS = 16;
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Rect {
+  Point origin;
+  Point size;
+};
+
+#endif // SPLIT_BY_MODULE_H_GEOMETRY
@@ -0,0 +1,11 @@
+#ifndef SPLIT_BY_MODULE_H_LIMITS
+#define SPLIT_BY_MODULE_H_LIMITS
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define NAME_LEN 16
+
+#endif /* SPLIT_BY_MODULE_H_LIMITS */
@@ -0,0 +1,12 @@
+#ifndef SPLIT_BY_MODULE_H_LIMITS
+#define SPLIT_BY_MODULE_H_LIMITS
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t NAME_LEN = 16;
+
+#endif // SPLIT_BY_MODULE_H_LIMITS
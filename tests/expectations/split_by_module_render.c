@@ -0,0 +1,23 @@
+#ifndef SPLIT_BY_MODULE_H_RENDER
+#define SPLIT_BY_MODULE_H_RENDER
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module_geometry.c"
+
+typedef struct {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+} Color;
+
+typedef struct {
+  Rect bounds;
+  Color color;
+} Canvas;
+
+void canvas_draw_point(Canvas *canvas, Point point);
+
+#endif /* SPLIT_BY_MODULE_H_RENDER */
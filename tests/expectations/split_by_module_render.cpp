@@ -0,0 +1,28 @@
+#ifndef SPLIT_BY_MODULE_H_RENDER
+#define SPLIT_BY_MODULE_H_RENDER
+
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include "split_by_module_geometry.cpp"
+
+struct Color {
+  uint8_t r;
+  uint8_t g;
+  uint8_t b;
+};
+
+struct Canvas {
+  Rect bounds;
+  Color color;
+};
+
+extern "C" {
+
+void canvas_draw_point(Canvas *canvas, Point point);
+
+} // extern "C"
+
+#endif // SPLIT_BY_MODULE_H_RENDER
@@ -0,0 +1,9 @@
+#ifndef SPLIT_BY_MODULE_H_UMBRELLA
+#define SPLIT_BY_MODULE_H_UMBRELLA
+
+#include "split_by_module_geometry.cpp"
+#include "split_by_module_limits.cpp"
+#include "split_by_module_render.cpp"
+#include "split_by_module.cpp"
+
+#endif // SPLIT_BY_MODULE_H_UMBRELLA
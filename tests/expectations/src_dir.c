@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef struct {
+  Point points[16];
+  uintptr_t len;
+} Points;
+
+float point_length(Point p);
+
+uintptr_t points_count(const Points *points);
+
+void internal(void);
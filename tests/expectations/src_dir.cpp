@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t MAX_POINTS = 16;
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Points {
+  Point points[16];
+  uintptr_t len;
+};
+
+extern "C" {
+
+float point_length(Point p);
+
+uintptr_t points_count(const Points *points);
+
+void internal();
+
+} // extern "C"
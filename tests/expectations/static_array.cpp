@@ -0,0 +1,22 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+static const uintptr_t LEN = 4;
+
+struct Entry {
+  uint32_t key;
+  float value;
+};
+
+extern "C" {
+
+extern const uint32_t TABLE[4];
+
+extern const Entry ENTRIES[LEN];
+
+extern uint8_t GRID[3][2];
+
+} // extern "C"
@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Counters {
+  uint32_t hits;
+  uint32_t misses;
+};
+
+extern "C" {
+
+extern Counters custom_counters;
+
+extern const uint32_t library_version;
+
+extern const uint8_t KEEP_ME;
+
+} // extern "C"
@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const Point ORIGIN;
+
+extern Point CURSOR;
+
+extern const uint32_t VERSION;
+
+extern uint32_t COUNTER;
+
+extern const uint8_t *const NAME;
+
+extern const uint8_t *NAME_MUT;
+
+extern uint8_t *const SCRATCH;
+
+extern uint8_t *SCRATCH_MUT;
+
+extern void (*const CALLBACK)(void);
+
+extern void (*CALLBACK_MUT)(void);
+
+extern const uint8_t TABLE[4];
+
+extern uint8_t BUFFER[4];
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
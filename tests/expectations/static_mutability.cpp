@@ -0,0 +1,38 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+extern "C" {
+
+extern const Point ORIGIN;
+
+extern Point CURSOR;
+
+extern const uint32_t VERSION;
+
+extern uint32_t COUNTER;
+
+extern const uint8_t *const NAME;
+
+extern const uint8_t *NAME_MUT;
+
+extern uint8_t *const SCRATCH;
+
+extern uint8_t *SCRATCH_MUT;
+
+extern void (*const CALLBACK)();
+
+extern void (*CALLBACK_MUT)();
+
+extern const uint8_t TABLE[4];
+
+extern uint8_t BUFFER[4];
+
+} // extern "C"
@@ -22,7 +22,7 @@ typedef struct {
 extern "C" {
 #endif // __cplusplus
 
-void root(Foo x, Bar bar);
+void root(Foo x, const Bar *bar);
 
 #ifdef __cplusplus
 } // extern "C"
@@ -21,6 +21,6 @@ static const Foo BAR = Foo{ /* .a = */ 42, /* .b = */ 1337 };
 
 extern "C" {
 
-void root(Foo x, Bar bar);
+void root(Foo x, const Bar *bar);
 
 } // extern "C"
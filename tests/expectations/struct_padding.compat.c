@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct InvalidPadding InvalidPadding;
+
+typedef struct ZeroPadding ZeroPadding;
+
+typedef struct {
+  uint32_t a;
+  uint8_t b;
+  uint8_t _pad[4];
+} Padded;
+
+typedef struct {
+  uint8_t _pad[16];
+} OnlyPadding;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Padded a, OnlyPadding b, const ZeroPadding *c, const InvalidPadding *d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,25 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct InvalidPadding;
+
+struct ZeroPadding;
+
+struct Padded {
+  uint32_t a;
+  uint8_t b;
+  uint8_t _pad[4];
+};
+
+struct OnlyPadding {
+  uint8_t _pad[16];
+};
+
+extern "C" {
+
+void root(Padded a, OnlyPadding b, const ZeroPadding *c, const InvalidPadding *d);
+
+} // extern "C"
@@ -45,7 +45,7 @@ void free_function_should_exist_ref(const SelfTypeTestStruct *test_struct) CF_SW
 
 void free_function_should_exist_ref_mut(SelfTypeTestStruct *test_struct) CF_SWIFT_NAME(free_function_should_exist_ref_mut(test_struct:));
 
-void unnamed_argument(SelfTypeTestStruct*);
+void unnamed_argument(SelfTypeTestStruct *arg0);
 
 void free_function_should_not_exist_box(SelfTypeTestStruct *boxed) CF_SWIFT_NAME(free_function_should_not_exist_box(boxed:));
 
@@ -9,7 +9,7 @@ enum C
 #endif // __cplusplus
  {
   X = 2,
-  Y,
+  Y
 };
 #ifndef __cplusplus
 typedef uint32_t C;
@@ -31,7 +31,7 @@ enum F_Tag
  {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 #ifndef __cplusplus
 typedef uint8_t F_Tag;
@@ -61,7 +61,7 @@ enum H_Tag
  {
   Hello,
   There,
-  Everyone,
+  Everyone
 };
 #ifndef __cplusplus
 typedef uint8_t H_Tag;
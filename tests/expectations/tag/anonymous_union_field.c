@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+union Value {
+  /**
+   * The value as an integer.
+   */
+  int32_t as_int;
+  float as_float;
+  const uint8_t *as_ptr;
+};
+
+struct Variant {
+  uint8_t kind;
+  union {
+    /**
+     * The value as an integer.
+     */
+    int32_t as_int;
+    float as_float;
+    const uint8_t *as_ptr;
+  };
+  /**
+   * Still a named field.
+   */
+  union Value other;
+};
+
+struct Tuple {
+  uint32_t _0;
+  union {
+    /**
+     * The value as an integer.
+     */
+    int32_t as_int;
+    float as_float;
+    const uint8_t *as_ptr;
+  };
+};
+
+void root(struct Variant v, struct Tuple t);
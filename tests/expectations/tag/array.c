@@ -4,7 +4,7 @@
 #include <stdlib.h>
 
 enum Foo_Tag {
-  A,
+  A
 };
 
 struct A_Body {
@@ -0,0 +1,13 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct NotAssumed;
+
+struct Assumed {
+  int32_t x;
+  float y;
+};
+
+void root(struct Assumed a, const struct NotAssumed *b);
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct NotAssumed;
+
+struct Assumed {
+  int32_t x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Assumed a, const struct NotAssumed *b);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
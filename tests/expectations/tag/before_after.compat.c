@@ -0,0 +1,31 @@
+/* Warning, this file is autogenerated. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// The maximum number of items.
+#define LIMIT 10
+
+#pragma pack(push, 1)
+struct Packed {
+  uint8_t a;
+  uint32_t b;
+};
+#pragma pack(pop)
+
+struct Plain {
+  uint8_t a;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// Takes a packed struct.
+void root(struct Packed p, struct Plain q);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Flags {
+  uint32_t visible : 1;
+  /**
+   * Whether the item can be resized.
+   */
+  uint32_t resizable : 1;
+  uint32_t layer : 6;
+  uint8_t kind : 4;
+  uint32_t id;
+};
+
+struct Packed {
+  uint16_t _0 : 3;
+  uint16_t _1 : 13;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Flags flags, struct Packed packed);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
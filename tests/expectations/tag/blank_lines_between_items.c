@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+
+#define ONE 1
+
+
+#define TWO 2
+
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+
+extern const struct Point ORIGIN;
+
+
+void first(struct Point p);
+
+
+void second(struct Point p);
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+
+#define ONE 1
+
+
+#define TWO 2
+
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+
+extern const struct Point ORIGIN;
+
+
+void first(struct Point p);
+
+
+void second(struct Point p);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -6,13 +6,13 @@
 enum MyCLikeEnum {
   Foo1,
   Bar1,
-  Baz1,
+  Baz1
 };
 
 enum MyCLikeEnum_Prepended {
   Foo1_Prepended,
   Bar1_Prepended,
-  Baz1_Prepended,
+  Baz1_Prepended
 };
 
 struct MyFancyStruct {
@@ -25,7 +25,7 @@ struct MyFancyStruct {
 enum MyFancyEnum_Tag {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 
 struct Bar_Body {
@@ -63,7 +63,7 @@ struct MyFancyStruct_Prepended {
 enum MyFancyEnum_Prepended_Tag {
   Foo_Prepended,
   Bar_Prepended,
-  Baz_Prepended,
+  Baz_Prepended
 };
 
 struct Bar_Prepended_Body {
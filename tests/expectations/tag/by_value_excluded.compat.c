@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Has no `#[repr]`, so it can't be passed by value.
+ */
+struct Handle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+uint32_t handle_id(const struct Handle *h);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint64_t Alias;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void handle_close(Alias h);
+
+uint64_t handle_open(void);
+
+void bits_set(uint32_t bits);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const char *name(void);
+
+char *name_copy(void);
+
+void set_name(const char *name, uint32_t initial);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -21,7 +21,7 @@ typedef int32_t *const *H;
 
 typedef const int32_t (*I)[16];
 
-typedef double (**J)(float);
+typedef double (*const *J)(float);
 
 typedef int32_t K[16];
 
@@ -0,0 +1,35 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buf_4 {
+  uint8_t data[4];
+  uintptr_t len;
+};
+
+typedef struct Buf_4 SmallBuf;
+
+struct Buf_16 {
+  uint8_t data[16];
+  uintptr_t len;
+};
+
+struct Packet_16 {
+  uint32_t header;
+  struct Buf_16 payload;
+};
+
+struct Matrix_f32__2__3 {
+  float rows[2][3];
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(SmallBuf small, const struct Packet_16 *packet, struct Matrix_f32__2__3 matrix);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node {
+  int32_t value;
+};
+
+struct Pointers {
+  const uint8_t *const_prim;
+  uint8_t *mut_prim;
+  const struct Node *const_struct;
+  struct Node *mut_struct;
+  struct Node *const *const_to_mut;
+  const struct Node **mut_to_const;
+  const struct Node *shared_ref;
+  struct Node *mut_ref;
+  const struct Node *nullable;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+const struct Pointers *pointers(const uint8_t *const_prim,
+                                uint8_t *mut_prim,
+                                const struct Node *const_struct,
+                                struct Node *mut_struct,
+                                struct Node *const *const_to_mut,
+                                const struct Node **mut_to_const,
+                                const struct Node *shared_ref,
+                                struct Node *mut_ref,
+                                const struct Node *nullable);
+
+struct Pointers *mut_return(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -4,7 +4,7 @@
 #include <stdlib.h>
 
 enum E {
-  V,
+  V
 };
 
 struct S {
@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Without an integer repr, the enum stays a plain enum.
+ */
+enum Color {
+  Red,
+  Green
+};
+
+enum Flags {
+  FlagNone,
+  FlagAll
+};
+typedef uint16_t Flags;
+
+enum Mode {
+  Read,
+  Write = 4,
+  Append
+};
+typedef uint8_t Mode;
+
+/**
+ * The tag of an enum with fields stays a plain enum.
+ */
+enum Shape_Tag {
+  Circle,
+  Square
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  Shape_Tag tag;
+  float _0;
+};
+
+struct Square_Body {
+  Shape_Tag tag;
+  float _0;
+};
+
+union Shape {
+  Shape_Tag tag;
+  struct Circle_Body circle;
+  struct Square_Body square;
+};
+
+struct Options {
+  Mode mode;
+  Flags flags;
+  enum Color color;
+  union Shape shape;
+};
+
+Mode root(struct Options options);
@@ -0,0 +1,86 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Without an integer repr, the enum stays a plain enum.
+ */
+enum Color {
+  Red,
+  Green
+};
+
+enum Flags
+#ifdef __cplusplus
+  : uint16_t
+#endif // __cplusplus
+ {
+  FlagNone,
+  FlagAll
+};
+#ifndef __cplusplus
+typedef uint16_t Flags;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+enum class Mode : uint8_t
+#else
+enum Mode
+#endif // __cplusplus
+ {
+  Read,
+  Write = 4,
+  Append
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+/**
+ * The tag of an enum with fields stays a plain enum.
+ */
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+struct Circle_Body {
+  Shape_Tag tag;
+  float _0;
+};
+
+struct Square_Body {
+  Shape_Tag tag;
+  float _0;
+};
+
+union Shape {
+  Shape_Tag tag;
+  struct Circle_Body circle;
+  struct Square_Body square;
+};
+
+struct Options {
+  Mode mode;
+  Flags flags;
+  enum Color color;
+  union Shape shape;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+Mode root(struct Options options);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
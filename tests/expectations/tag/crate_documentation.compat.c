@@ -0,0 +1,31 @@
+/* Licensed under the MPL 2.0. */
+
+/**
+ * Bindings to the frobnicator.
+ *
+ * Create a frobnicator with `frob_new`, and release it with `frob_free`.
+ */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A frobnicator.
+ */
+struct Frob {
+  uint32_t level;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Frob *frob_new(uint32_t level);
+
+void frob_free(struct Frob *frob);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Base {
+  int32_t value;
+};
+
+struct Right {
+  struct Base base;
+  struct Base extra[2];
+};
+
+struct Left {
+  struct Base base;
+  const struct Right *right;
+};
+
+struct Top {
+  struct Left left;
+  struct Right right;
+};
+
+void root(struct Top top);
@@ -16,7 +16,7 @@ enum Bar_Tag
   Baz,
   Bazz,
   FooNamed,
-  FooParen,
+  FooParen
 };
 #ifndef __cplusplus
 typedef uint8_t Bar_Tag;
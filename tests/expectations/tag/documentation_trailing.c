@@ -0,0 +1,49 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A plain enum.
+ */
+enum Color {
+  Red, /**< Red. */
+  Green = 4, /**< Green. */
+  Blue
+};
+
+/**
+ * A point in space.
+ */
+struct Point {
+  float x; /**< The x coordinate. */
+  /**
+   * The y coordinate.
+   *
+   * Spans several lines, so it stays leading.
+   */
+  float y;
+  float z;
+};
+
+/**
+ * A tagged enum.
+ */
+enum Shape_Tag {
+  Circle, /**< A circle. */
+  Empty /**< Nothing. */
+};
+typedef uint8_t Shape_Tag;
+
+struct Circle_Body {
+  float radius; /**< Its radius. */
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+  };
+};
+
+void root(struct Point p, enum Color c, struct Shape s);
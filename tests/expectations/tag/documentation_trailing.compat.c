@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A plain enum.
+ */
+enum Color {
+  Red, /**< Red. */
+  Green = 4, /**< Green. */
+  Blue
+};
+
+/**
+ * A point in space.
+ */
+struct Point {
+  float x; /**< The x coordinate. */
+  /**
+   * The y coordinate.
+   *
+   * Spans several lines, so it stays leading.
+   */
+  float y;
+  float z;
+};
+
+/**
+ * A tagged enum.
+ */
+enum Shape_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle, /**< A circle. */
+  Empty /**< Nothing. */
+};
+#ifndef __cplusplus
+typedef uint8_t Shape_Tag;
+#endif // __cplusplus
+
+struct Circle_Body {
+  float radius; /**< Its radius. */
+};
+
+struct Shape {
+  Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Point p, enum Color c, struct Shape s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -11,7 +11,7 @@ enum A
   a1 = 0,
   a2 = 2,
   a3,
-  a4 = 5,
+  a4 = 5
 };
 #ifndef __cplusplus
 typedef uint64_t A;
@@ -25,7 +25,7 @@ enum B
   b1 = 0,
   b2 = 2,
   b3,
-  b4 = 5,
+  b4 = 5
 };
 #ifndef __cplusplus
 typedef uint32_t B;
@@ -39,7 +39,7 @@ enum C
   c1 = 0,
   c2 = 2,
   c3,
-  c4 = 5,
+  c4 = 5
 };
 #ifndef __cplusplus
 typedef uint16_t C;
@@ -53,7 +53,7 @@ enum D
   d1 = 0,
   d2 = 2,
   d3,
-  d4 = 5,
+  d4 = 5
 };
 #ifndef __cplusplus
 typedef uint8_t D;
@@ -67,7 +67,7 @@ enum E
   e1 = 0,
   e2 = 2,
   e3,
-  e4 = 5,
+  e4 = 5
 };
 #ifndef __cplusplus
 typedef uintptr_t E;
@@ -81,7 +81,7 @@ enum F
   f1 = 0,
   f2 = 2,
   f3,
-  f4 = 5,
+  f4 = 5
 };
 #ifndef __cplusplus
 typedef intptr_t F;
@@ -91,7 +91,7 @@ enum L {
   l1,
   l2,
   l3,
-  l4,
+  l4
 };
 
 enum M
@@ -101,7 +101,7 @@ enum M
  {
   m1 = -1,
   m2 = 0,
-  m3 = 1,
+  m3 = 1
 };
 #ifndef __cplusplus
 typedef int8_t M;
@@ -111,7 +111,7 @@ enum N {
   n1,
   n2,
   n3,
-  n4,
+  n4
 };
 
 enum O
@@ -122,7 +122,7 @@ enum O
   o1,
   o2,
   o3,
-  o4,
+  o4
 };
 #ifndef __cplusplus
 typedef int8_t O;
@@ -141,7 +141,7 @@ enum G_Tag
  {
   Foo,
   Bar,
-  Baz,
+  Baz
 };
 #ifndef __cplusplus
 typedef uint8_t G_Tag;
@@ -167,7 +167,7 @@ union G {
 enum H_Tag {
   H_Foo,
   H_Bar,
-  H_Baz,
+  H_Baz
 };
 
 struct H_Foo_Body {
@@ -194,7 +194,7 @@ enum I_Tag
  {
   I_Foo,
   I_Bar,
-  I_Baz,
+  I_Baz
 };
 #ifndef __cplusplus
 typedef uint8_t I_Tag;
@@ -223,7 +223,7 @@ enum P_Tag
 #endif // __cplusplus
  {
   P0,
-  P1,
+  P1
 };
 #ifndef __cplusplus
 typedef uint8_t P_Tag;
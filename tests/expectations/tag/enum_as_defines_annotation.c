@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A strong typedef of an integer, written as a typedef and defines.
+ */
+typedef uint32_t Handle;
+#define Handle_Invalid 0
+#define Handle_Stdin 1
+#define Handle_Stdout 2
+#define Handle_Stderr 3
+
+/**
+ * Written as an `enum`, since the option is disabled.
+ */
+enum Mode {
+  Read,
+  Write
+};
+typedef uint8_t Mode;
+
+void root(Handle h, Mode m);
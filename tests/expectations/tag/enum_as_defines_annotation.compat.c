@@ -0,0 +1,38 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A strong typedef of an integer, written as a typedef and defines.
+ */
+typedef uint32_t Handle;
+#define Handle_Invalid 0
+#define Handle_Stdin 1
+#define Handle_Stdout 2
+#define Handle_Stderr 3
+
+/**
+ * Written as an `enum`, since the option is disabled.
+ */
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Read,
+  Write
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Handle h, Mode m);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
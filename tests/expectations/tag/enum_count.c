@@ -0,0 +1,59 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Contiguous {
+  A,
+  B,
+  C
+};
+typedef uint8_t Contiguous;
+
+#define Contiguous_VARIANT_COUNT 3
+
+enum Explicit {
+  X = 0,
+  Y = 1,
+  Z = 2
+};
+
+#define Explicit_VARIANT_COUNT 3
+
+enum Gaps {
+  First = 1,
+  Second = 5
+};
+typedef uint32_t Gaps;
+
+enum NotCounted {
+  P,
+  Q
+};
+
+enum Tagged_Tag {
+  Foo,
+  Bar,
+  Baz
+};
+typedef uint8_t Tagged_Tag;
+
+struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+};
+
+struct Bar_Body {
+  Tagged_Tag tag;
+  uint8_t x;
+};
+
+union Tagged {
+  Tagged_Tag tag;
+  struct Foo_Body foo;
+  struct Bar_Body bar;
+};
+
+#define Tagged_VARIANT_COUNT 3
+
+void root(Contiguous a, enum Explicit b, enum NotCounted c, Gaps d, union Tagged e);
@@ -0,0 +1,85 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Contiguous
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B,
+  C
+};
+#ifndef __cplusplus
+typedef uint8_t Contiguous;
+#endif // __cplusplus
+
+#define Contiguous_VARIANT_COUNT 3
+
+enum Explicit {
+  X = 0,
+  Y = 1,
+  Z = 2
+};
+
+#define Explicit_VARIANT_COUNT 3
+
+enum Gaps
+#ifdef __cplusplus
+  : uint32_t
+#endif // __cplusplus
+ {
+  First = 1,
+  Second = 5
+};
+#ifndef __cplusplus
+typedef uint32_t Gaps;
+#endif // __cplusplus
+
+enum NotCounted {
+  P,
+  Q
+};
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Foo,
+  Bar,
+  Baz
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_Tag;
+#endif // __cplusplus
+
+struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+};
+
+struct Bar_Body {
+  Tagged_Tag tag;
+  uint8_t x;
+};
+
+union Tagged {
+  Tagged_Tag tag;
+  struct Foo_Body foo;
+  struct Bar_Body bar;
+};
+
+#define Tagged_VARIANT_COUNT 3
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Contiguous a, enum Explicit b, enum NotCounted c, Gaps d, union Tagged e);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define UNIX_BASE 4
+
+#if defined(DEFINED)
+enum Mode {
+  Read = 4,
+  Write
+};
+typedef uint8_t Mode;
+#endif
+
+#if defined(NOT_DEFINED)
+/**
+ * Opaque, since `windows_base()` can't be evaluated.
+ */
+struct Mode;
+#endif
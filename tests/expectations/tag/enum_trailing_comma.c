@@ -0,0 +1,43 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Conditional {
+  First,
+#if defined(DEFINE_SECOND)
+  Second,
+#endif
+};
+
+enum Plain {
+  A,
+  /**
+   * The last variant.
+   */
+  B = 5,
+};
+
+enum Tagged_Tag {
+  Foo,
+  Bar,
+};
+typedef uint8_t Tagged_Tag;
+
+struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+};
+
+struct Bar_Body {
+  Tagged_Tag tag;
+  float x;
+};
+
+union Tagged {
+  Tagged_Tag tag;
+  struct Foo_Body foo;
+  struct Bar_Body bar;
+};
+
+void root(enum Plain plain, union Tagged tagged, enum Conditional conditional);
@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Conditional {
+  First,
+#if defined(DEFINE_SECOND)
+  Second,
+#endif
+};
+
+enum Plain {
+  A,
+  /**
+   * The last variant.
+   */
+  B = 5,
+};
+
+enum Tagged_Tag
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Foo,
+  Bar,
+};
+#ifndef __cplusplus
+typedef uint8_t Tagged_Tag;
+#endif // __cplusplus
+
+struct Foo_Body {
+  Tagged_Tag tag;
+  int32_t _0;
+};
+
+struct Bar_Body {
+  Tagged_Tag tag;
+  float x;
+};
+
+union Tagged {
+  Tagged_Tag tag;
+  struct Foo_Body foo;
+  struct Bar_Body bar;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(enum Plain plain, union Tagged tagged, enum Conditional conditional);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color {
+  Red,
+  Green,
+  Blue
+};
+
+enum Small {
+  One,
+  Two
+};
+typedef uint8_t Small;
+
+enum Shape_Tag {
+  Circle,
+  Square
+};
+
+struct Circle_Body {
+  float _0;
+};
+
+struct Square_Body {
+  float _0;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Square_Body square;
+  };
+};
+
+void root(enum Color color, Small small, struct Shape shape);
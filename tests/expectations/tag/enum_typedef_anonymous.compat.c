@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Color {
+  Red,
+  Green,
+  Blue
+};
+
+enum Small
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  One,
+  Two
+};
+#ifndef __cplusplus
+typedef uint8_t Small;
+#endif // __cplusplus
+
+enum Shape_Tag {
+  Circle,
+  Square
+};
+
+struct Circle_Body {
+  float _0;
+};
+
+struct Square_Body {
+  float _0;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Square_Body square;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(enum Color color, Small small, struct Shape shape);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
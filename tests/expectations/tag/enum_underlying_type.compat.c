@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Large
+#ifdef __cplusplus
+  : uint64_t
+#endif // __cplusplus
+ {
+  LargeA,
+  LargeB = 1099511627776
+};
+#ifndef __cplusplus
+typedef uint64_t Large;
+#endif // __cplusplus
+
+enum Medium
+#ifdef __cplusplus
+  : int16_t
+#endif // __cplusplus
+ {
+  MediumA = -1,
+  MediumB = 1
+};
+#ifndef __cplusplus
+typedef int16_t Medium;
+#endif // __cplusplus
+
+enum Small
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  SmallA,
+  SmallB
+};
+#ifndef __cplusplus
+typedef uint8_t Small;
+#endif // __cplusplus
+
+/**
+ * No width is specified, so the C++ enum is left to the compiler.
+ */
+enum Unsized {
+  UnsizedA,
+  UnsizedB
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Small s, Medium m, Large l, enum Unsized u);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#include "testing-helpers.h"
+static_assert(sizeof(Small) == 1, "unexpected size for Small");
+static_assert(sizeof(Medium) == 2, "unexpected size for Medium");
+static_assert(sizeof(Large) == 8, "unexpected size for Large");
@@ -0,0 +1,12 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Config {
+  bool verbose;
+};
+
+void root_function(void);
+
+void api_configure(const struct Config *config);
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Config {
+  bool verbose;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root_function(void);
+
+void api_configure(const struct Config *config);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,54 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Header {
+  uint8_t zeta;
+  uint32_t alpha;
+  uint16_t mu;
+  uint64_t beta;
+};
+
+struct Pair {
+  uint64_t _0;
+  uint8_t _1;
+};
+
+union Payload {
+  uint32_t zz;
+  float aa;
+};
+
+enum Message_Tag {
+  Ping,
+  Data
+};
+
+struct Ping_Body {
+  uint32_t seq;
+  uint64_t at;
+};
+
+struct Data_Body {
+  struct Header _0;
+  union Payload _1;
+};
+
+struct Message {
+  enum Message_Tag tag;
+  union {
+    struct Ping_Body ping;
+    struct Data_Body data;
+  };
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Header h, struct Pair p, struct Message m);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
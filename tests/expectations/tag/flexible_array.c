@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct NotLast;
+
+struct NotZeroLength;
+
+struct ZeroLength {
+  uintptr_t len;
+  uint8_t data[];
+};
+
+struct Marked {
+  uint32_t len;
+  int32_t items[];
+};
+
+struct Unmarked {
+  uint32_t len;
+  uint16_t data[0];
+};
+
+void root(const struct ZeroLength *a,
+          const struct Marked *b,
+          const struct NotLast *c,
+          const struct Unmarked *d,
+          const struct NotZeroLength *e);
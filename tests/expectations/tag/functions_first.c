@@ -0,0 +1,57 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint8_t Kind;
+
+enum Plain {
+  A,
+  B
+};
+
+struct Handle;
+
+struct Point;
+
+typedef const struct Point *Points;
+
+struct Shape;
+
+typedef float Meters;
+
+extern const struct Point ORIGIN;
+
+struct Point draw(struct Handle *h, Points p, Kind k, struct Shape s, Meters m, enum Plain plain);
+
+enum Kind {
+  Solid,
+  Dashed
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+enum Shape_Tag {
+  Dot,
+  Line
+};
+
+struct Dot_Body {
+  struct Point _0;
+};
+
+struct Line_Body {
+  struct Point _0;
+  struct Point _1;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Dot_Body dot;
+    struct Line_Body line;
+  };
+};
@@ -0,0 +1,73 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+enum Kind : uint8_t;
+#else
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+enum Plain {
+  A,
+  B
+};
+
+struct Handle;
+
+struct Point;
+
+typedef const struct Point *Points;
+
+struct Shape;
+
+typedef float Meters;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const struct Point ORIGIN;
+
+struct Point draw(struct Handle *h, Points p, Kind k, struct Shape s, Meters m, enum Plain plain);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Solid,
+  Dashed
+};
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
+enum Shape_Tag {
+  Dot,
+  Line
+};
+
+struct Dot_Body {
+  struct Point _0;
+};
+
+struct Line_Body {
+  struct Point _0;
+  struct Point _1;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Dot_Body dot;
+    struct Line_Body line;
+  };
+};
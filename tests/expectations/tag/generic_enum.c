@@ -0,0 +1,63 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Opt_i32_Tag {
+  None_i32,
+  Some_i32
+};
+
+struct Some_Body_i32 {
+  int32_t _0;
+};
+
+struct Opt_i32 {
+  enum Opt_i32_Tag tag;
+  union {
+    struct Some_Body_i32 some;
+  };
+};
+
+typedef struct Opt_i32 IntOption;
+
+enum Tagged_f32_Tag {
+  A_f32,
+  B_f32
+};
+typedef uint8_t Tagged_f32_Tag;
+
+struct A_Body_f32 {
+  Tagged_f32_Tag tag;
+  float _0;
+};
+
+struct B_Body_f32 {
+  Tagged_f32_Tag tag;
+  float x;
+  uint8_t y;
+};
+
+union Tagged_f32 {
+  Tagged_f32_Tag tag;
+  struct A_Body_f32 a;
+  struct B_Body_f32 b;
+};
+
+enum Opt______u8_Tag {
+  None______u8,
+  Some______u8
+};
+
+struct Some_Body______u8 {
+  const uint8_t *_0;
+};
+
+struct Opt______u8 {
+  enum Opt______u8_Tag tag;
+  union {
+    struct Some_Body______u8 some;
+  };
+};
+
+void root(IntOption a, union Tagged_f32 b, struct Opt______u8 c);
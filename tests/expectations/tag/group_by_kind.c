@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Registry;
+
+struct Point {
+  float x;
+  float y;
+};
+
+/**
+ * Needs `Point` by value, so `Point` is moved up among the typedefs.
+ */
+typedef struct Point Position;
+
+/**
+ * Only uses `Registry` behind a pointer, so it stays with the typedefs, and
+ * `Registry` is forward-declared.
+ */
+typedef struct Registry *RegistryPtr;
+
+enum Shape {
+  Circle,
+  Square
+};
+typedef uint8_t Shape;
+
+struct Node {
+  Position position;
+  Shape shape;
+};
+
+struct Registry {
+  struct Node *first;
+  uintptr_t len;
+};
+
+struct Handle;
+
+struct Node *registry_first(RegistryPtr registry, struct Handle *handle);
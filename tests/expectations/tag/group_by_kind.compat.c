@@ -0,0 +1,56 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Registry;
+
+struct Point {
+  float x;
+  float y;
+};
+
+/**
+ * Needs `Point` by value, so `Point` is moved up among the typedefs.
+ */
+typedef struct Point Position;
+
+/**
+ * Only uses `Registry` behind a pointer, so it stays with the typedefs, and
+ * `Registry` is forward-declared.
+ */
+typedef struct Registry *RegistryPtr;
+
+enum Shape
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Circle,
+  Square
+};
+#ifndef __cplusplus
+typedef uint8_t Shape;
+#endif // __cplusplus
+
+struct Node {
+  Position position;
+  Shape shape;
+};
+
+struct Registry {
+  struct Node *first;
+  uintptr_t len;
+};
+
+struct Handle;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Node *registry_first(RegistryPtr registry, struct Handle *handle);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
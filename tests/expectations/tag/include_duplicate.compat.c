@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <math.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+double root(uint32_t x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
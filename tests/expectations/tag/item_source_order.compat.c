@@ -0,0 +1,62 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Zebra {
+  int32_t value;
+};
+
+enum Mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Fast,
+  Slow
+};
+#ifndef __cplusplus
+typedef uint8_t Mode;
+#endif // __cplusplus
+
+struct Inner {
+  struct Zebra zebra;
+};
+
+struct Pair_f32 {
+  float first;
+  float second;
+};
+
+/**
+ * Uses a type which is defined after it.
+ */
+struct Outer {
+  struct Inner inner;
+  Mode mode;
+  struct Pair_f32 pair;
+};
+
+struct Opaque;
+
+typedef void (*Callback)(const struct Opaque*);
+
+union Value {
+  int32_t as_int;
+  float as_float;
+};
+
+enum Alpha {
+  A,
+  B
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Outer outer, Callback callback, union Value value, enum Alpha alpha);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
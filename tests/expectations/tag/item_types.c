@@ -5,6 +5,6 @@
 
 enum OnlyThisShouldBeGenerated {
   Foo,
-  Bar,
+  Bar
 };
 typedef uint8_t OnlyThisShouldBeGenerated;
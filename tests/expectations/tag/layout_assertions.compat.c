@@ -0,0 +1,149 @@
+#define CBINDGEN_PACKED     __attribute__ ((packed))
+#define CBINDGEN_ALIGNED(n) __attribute__ ((aligned(n)))
+
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LEN 3
+
+enum Kind
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  A,
+  B
+};
+#ifndef __cplusplus
+typedef uint8_t Kind;
+#endif // __cplusplus
+
+struct Fixed {
+  uint8_t a;
+  uint32_t b;
+  uint16_t c;
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct Fixed) == 12, "unexpected size of Fixed");
+_Static_assert(_Alignof(struct Fixed) == 4, "unexpected alignment of Fixed");
+#endif // __cplusplus
+
+struct Nested {
+  Kind kind;
+  struct Fixed fixed;
+  uint8_t bytes[LEN];
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct Nested) == 20, "unexpected size of Nested");
+_Static_assert(_Alignof(struct Nested) == 4, "unexpected alignment of Nested");
+#endif // __cplusplus
+
+union Number {
+  int32_t i;
+  float f;
+  uint8_t b[6];
+};
+
+struct WithUnion {
+  uint8_t tag;
+  union Number number;
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct WithUnion) == 12, "unexpected size of WithUnion");
+_Static_assert(_Alignof(struct WithUnion) == 4, "unexpected alignment of WithUnion");
+#endif // __cplusplus
+
+struct CBINDGEN_PACKED Packed {
+  uint8_t a;
+  uint32_t b;
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct Packed) == 5, "unexpected size of Packed");
+_Static_assert(_Alignof(struct Packed) == 1, "unexpected alignment of Packed");
+#endif // __cplusplus
+
+struct CBINDGEN_ALIGNED(16) Aligned {
+  uint8_t a;
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct Aligned) == 16, "unexpected size of Aligned");
+_Static_assert(_Alignof(struct Aligned) == 16, "unexpected alignment of Aligned");
+#endif // __cplusplus
+
+struct Padded {
+  uint16_t a;
+  uint8_t _pad[3];
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct Padded) == 6, "unexpected size of Padded");
+_Static_assert(_Alignof(struct Padded) == 2, "unexpected alignment of Padded");
+#endif // __cplusplus
+
+struct Flexible {
+  uint16_t len;
+  uint32_t data[];
+};
+
+#ifndef __cplusplus
+_Static_assert(sizeof(struct Flexible) == 4, "unexpected size of Flexible");
+_Static_assert(_Alignof(struct Flexible) == 4, "unexpected alignment of Flexible");
+#endif // __cplusplus
+
+struct WithPointer {
+  uint8_t a;
+  const uint8_t *ptr;
+};
+
+#ifndef __cplusplus
+#if (UINTPTR_MAX == 0xFFFFFFFF)
+_Static_assert(sizeof(struct WithPointer) == 8, "unexpected size of WithPointer");
+_Static_assert(_Alignof(struct WithPointer) == 4, "unexpected alignment of WithPointer");
+#endif
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+_Static_assert(sizeof(struct WithPointer) == 16, "unexpected size of WithPointer");
+_Static_assert(_Alignof(struct WithPointer) == 8, "unexpected alignment of WithPointer");
+#endif
+#endif // __cplusplus
+
+struct WithU64 {
+  uint8_t a;
+  uint64_t b;
+};
+
+#ifndef __cplusplus
+#if (UINTPTR_MAX == 0xFFFFFFFFFFFFFFFF)
+_Static_assert(sizeof(struct WithU64) == 16, "unexpected size of WithU64");
+_Static_assert(_Alignof(struct WithU64) == 8, "unexpected alignment of WithU64");
+#endif
+#endif // __cplusplus
+
+struct WithLong {
+  long a;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Nested a,
+          struct WithUnion b,
+          struct Packed c,
+          struct Aligned d,
+          struct Padded e,
+          const struct Flexible *f,
+          struct WithPointer g,
+          struct WithU64 h,
+          struct WithLong i);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
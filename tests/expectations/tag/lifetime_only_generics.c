@@ -0,0 +1,42 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Only has a lifetime parameter, so it's written like a non-generic struct.
+ */
+struct Ref {
+  const uint32_t *ptr;
+  uintptr_t len;
+};
+
+struct Pair {
+  struct Ref first;
+  struct Ref *second;
+};
+
+enum Borrowed_Tag {
+  Nothing,
+  Value,
+  Slice
+};
+typedef uint8_t Borrowed_Tag;
+
+struct Value_Body {
+  const uint32_t *_0;
+};
+
+struct Slice_Body {
+  struct Ref _0;
+};
+
+struct Borrowed {
+  Borrowed_Tag tag;
+  union {
+    struct Value_Body value;
+    struct Slice_Body slice;
+  };
+};
+
+void root(struct Pair pair, struct Borrowed borrowed);
@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Inner_u8 {
+  uint8_t value;
+};
+
+struct Middle_u8 {
+  const struct Inner_u8 *inner;
+};
+
+struct Outer_u8 {
+  struct Middle_u8 inner;
+};
+
+void root(struct Outer_u8 x);
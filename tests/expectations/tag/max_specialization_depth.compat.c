@@ -0,0 +1,26 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Inner_u8 {
+  uint8_t value;
+};
+
+struct Middle_u8 {
+  const struct Inner_u8 *inner;
+};
+
+struct Outer_u8 {
+  struct Middle_u8 inner;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Outer_u8 x);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
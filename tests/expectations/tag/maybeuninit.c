@@ -13,8 +13,16 @@ struct NotReprC_MaybeUninit______i32;
 
 typedef struct NotReprC_MaybeUninit______i32 Foo;
 
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
 struct MyStruct {
   const int32_t *number;
+  struct Point point;
 };
 
 void root(const Foo *a, const struct MyStruct *with_maybe_uninit);
+
+void take(struct Point with_maybe_uninit);
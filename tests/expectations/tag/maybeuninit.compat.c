@@ -13,8 +13,14 @@ struct NotReprC_MaybeUninit______i32;
 
 typedef struct NotReprC_MaybeUninit______i32 Foo;
 
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+
 struct MyStruct {
   const int32_t *number;
+  struct Point point;
 };
 
 #ifdef __cplusplus
@@ -23,6 +29,8 @@ extern "C" {
 
 void root(const Foo *a, const struct MyStruct *with_maybe_uninit);
 
+void take(struct Point with_maybe_uninit);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
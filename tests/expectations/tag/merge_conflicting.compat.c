@@ -0,0 +1,22 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Conflicts with the `Point` and `first` of `merge_first.rs`.
+ */
+enum Point {
+  A,
+  B
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void first(enum Point p);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,19 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Size {
+  uint32_t w;
+  uint32_t h;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void second(struct Size s);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,53 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Tree;
+
+struct Style {
+  uint32_t color;
+};
+
+struct Widget {
+  const struct Style *style;
+  uint32_t width;
+};
+
+/**
+ * Typedefs come first with `group_by_kind`, but the structs they point to are
+ * moved up instead of being forward-declared.
+ */
+typedef struct Widget *WidgetHandle;
+
+typedef void (*VisitCallback)(const struct Widget *widget, const struct Style *style);
+
+struct Leaf {
+  uint32_t value;
+};
+
+typedef void (*Callback)(const struct Leaf *leaf);
+
+/**
+ * Points to `Tree`, which points back to it, so only one of the two is
+ * forward-declared.
+ */
+struct Node {
+  const struct Tree *parent;
+  const struct Leaf *leaf;
+};
+
+struct Tree {
+  struct Node *root;
+  Callback callback;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(WidgetHandle handle, VisitCallback visit, const struct Tree *tree);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Generated {
+  uint32_t id;
+};
+
+struct Handle {
+  int32_t fd;
+};
+
+struct Device {
+  struct Handle handle;
+};
+
+struct Real {
+  float value;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void generated_fn(const struct Generated *g);
+
+void root(struct Device a, struct Generated b);
+
+void real(struct Real r);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -35,9 +35,9 @@ typedef struct Tuple_f32__f32 Indirection_f32;
 
 void root(struct Foo_i32 a,
           struct Foo_f32 b,
-          struct Bar_f32 c,
+          const struct Bar_f32 *c,
           struct Foo_Bar_f32 d,
-          struct Bar_Foo_f32 e,
-          struct Bar_Bar_f32 f,
+          const struct Bar_Foo_f32 *e,
+          const struct Bar_Bar_f32 *f,
           struct Tuple_Foo_f32_____f32 g,
           Indirection_f32 h);
@@ -39,10 +39,10 @@ extern "C" {
 
 void root(union Foo_i32 a,
           union Foo_f32 b,
-          struct Bar_f32 c,
+          const struct Bar_f32 *c,
           union Foo_Bar_f32 d,
-          struct Bar_Foo_f32 e,
-          struct Bar_Bar_f32 f,
+          const struct Bar_Foo_f32 *e,
+          const struct Bar_Bar_f32 *f,
           union Tuple_Foo_f32_____f32 g,
           Indirection_f32 h);
 
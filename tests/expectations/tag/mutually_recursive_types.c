@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node;
+
+struct Graph {
+  struct Node *root;
+  void (*walk)(const struct Graph*);
+};
+
+struct Edge {
+  struct Node *to;
+  const struct Edge *next;
+  const struct Graph *graph;
+};
+
+struct Node {
+  struct Node *parent;
+  const struct Edge *edges;
+};
+
+void visit(struct Node *node);
@@ -0,0 +1,34 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Array_f32 {
+  float *data;
+  uintptr_t len;
+};
+
+struct Array_Array_f32 {
+  struct Array_f32 *data;
+  uintptr_t len;
+};
+
+typedef struct Array_Array_f32 Matrix;
+
+typedef struct Array_f32 Row;
+
+struct Wrap_i32 {
+  int32_t inner;
+};
+
+struct Wrap_Wrap_i32 {
+  struct Wrap_i32 inner;
+};
+
+struct Wrap_Wrap_Wrap_i32 {
+  struct Wrap_Wrap_i32 inner;
+};
+
+typedef struct Wrap_Wrap_Wrap_i32 Deep;
+
+void root(Matrix m, Row r, Deep d);
@@ -0,0 +1,28 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Event {
+  uint32_t kind;
+};
+
+struct Listener {
+  /**
+   * May be null, in which case events are dropped.
+   */
+  bool (*on_event)(const struct Event *event, uint8_t *user_data);
+  void (*on_close)(uint8_t*);
+  void (*on_error)(int32_t code);
+  uint8_t *user_data;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void listener_attach(struct Listener *listener);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
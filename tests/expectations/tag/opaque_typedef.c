@@ -0,0 +1,15 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct Handle Handle;
+
+struct Options {
+  struct Handle *handle;
+  uint32_t flags;
+};
+
+struct Handle *handle_new(const struct Options *options);
+
+void handle_free(struct Handle *handle);
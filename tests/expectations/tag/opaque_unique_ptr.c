@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct BarHandle;
+
+struct Baz;
+
+struct Foo;
+
+struct Qux;
+
+struct Foo *foo_new(void);
+
+void foo_free(struct Foo *foo);
+
+void bar_release(struct BarHandle *bar);
+
+/**
+ * Takes the handle by const pointer, so it isn't a destructor.
+ */
+void baz_free(const struct Baz *baz);
+
+void qux_free(struct Qux *qux);
@@ -0,0 +1,33 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct BarHandle;
+
+struct Baz;
+
+struct Foo;
+
+struct Qux;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Foo *foo_new(void);
+
+void foo_free(struct Foo *foo);
+
+void bar_release(struct BarHandle *bar);
+
+/**
+ * Takes the handle by const pointer, so it isn't a destructor.
+ */
+void baz_free(const struct Baz *baz);
+
+void qux_free(struct Qux *qux);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Node;
+
+struct Owner;
+
+struct Link {
+  struct Node *target;
+  float weight;
+};
+
+/**
+ * Holds a `Link` by value, so it's defined after it, and the structs the
+ * link points to are declared before it.
+ */
+struct Node {
+  const struct Owner *owner;
+  struct Link link;
+  struct Node *next;
+};
+
+struct Owner {
+  struct Node *first;
+  uint32_t count;
+};
+
+void root(struct Owner owner, struct Node node);
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint32_t Word;
+
+struct Header {
+  Word len;
+  uint32_t flags;
+};
+
+#if defined(DEFINE_NARROW)
+/**
+ * Has a [defines] entry, which takes precedence over `target_pointer_width`.
+ */
+void narrow_only(const struct Header *header);
+#endif
+
+Word root(const struct Header *header);
@@ -16,7 +16,7 @@ typedef int32_t PREFIX_ValuedLenArray[22];
 enum PREFIX_AbsoluteFontWeight_Tag {
   Weight,
   Normal,
-  Bold,
+  Bold
 };
 typedef uint8_t PREFIX_AbsoluteFontWeight_Tag;
 
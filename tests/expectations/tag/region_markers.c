@@ -0,0 +1,40 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+// region: Constants
+
+#define LIMIT 16
+
+// endregion
+
+// region: Types
+
+enum Kind {
+  Small,
+  Large
+};
+typedef uint8_t Kind;
+
+struct Point {
+  int32_t x;
+  int32_t y;
+};
+#define Point_ORIGIN (Point){ .x = 0, .y = 0 }
+
+typedef struct Point Points[4];
+
+// endregion
+
+// region: Globals
+
+extern uint32_t COUNTER;
+
+// endregion
+
+// region: Functions
+
+void root(struct Point p, Kind k, const Points *ps);
+
+// endregion
@@ -7,7 +7,7 @@
 
 enum C_E {
   x = 0,
-  y = 1,
+  y = 1
 };
 typedef uint8_t C_E;
 
@@ -31,4 +31,9 @@ typedef struct C_A C_F;
 
 extern const int32_t G;
 
-void root(const struct C_A *a, struct C_AwesomeB b, struct C_C c, union C_D d, C_E e, C_F f);
+void root(const struct C_A *a,
+          struct C_AwesomeB b,
+          const struct C_C *c,
+          union C_D d,
+          C_E e,
+          const C_F *f);
@@ -11,7 +11,7 @@ enum C_E
 #endif // __cplusplus
  {
   x = 0,
-  y = 1,
+  y = 1
 };
 #ifndef __cplusplus
 typedef uint8_t C_E;
@@ -41,7 +41,12 @@ extern "C" {
 
 extern const int32_t G;
 
-void root(const struct C_A *a, struct C_AwesomeB b, struct C_C c, union C_D d, C_E e, C_F f);
+void root(const struct C_A *a,
+          struct C_AwesomeB b,
+          const struct C_C *c,
+          union C_D d,
+          C_E e,
+          const C_F *f);
 
 #ifdef __cplusplus
 } // extern "C"
@@ -0,0 +1,61 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum gfx_draw_mode
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Fill,
+  Stroke
+};
+#ifndef __cplusplus
+typedef uint8_t gfx_draw_mode;
+#endif // __cplusplus
+
+struct gfx_opaque_canvas;
+
+struct gfx_point_f {
+  float x;
+  float y;
+};
+
+struct gfx_line_segment {
+  struct gfx_point_f start;
+  struct gfx_point_f end;
+};
+
+struct gfx_pair_point_f {
+  struct gfx_point_f first;
+  struct gfx_point_f second;
+};
+
+typedef struct gfx_pair_point_f gfx_point_pair;
+
+union gfx_int_or_float {
+  int32_t i;
+  float f;
+};
+
+/**
+ * Explicitly renamed, so not converted.
+ */
+struct gfx_KeepName {
+  gfx_draw_mode mode;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void draw_line(struct gfx_opaque_canvas *canvas,
+               struct gfx_line_segment line,
+               gfx_point_pair points,
+               union gfx_int_or_float value,
+               struct gfx_KeepName keep);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -14,7 +14,7 @@ struct B {
 };
 
 enum C_Tag {
-  D,
+  D
 };
 typedef uint8_t C_Tag;
 
@@ -32,7 +32,7 @@ struct C {
 
 enum E_Tag {
   Double,
-  Float,
+  Float
 };
 typedef uint8_t E_Tag;
 
@@ -54,7 +54,7 @@ struct E {
 
 enum F_Tag {
   double_,
-  float_,
+  float_
 };
 typedef uint8_t F_Tag;
 
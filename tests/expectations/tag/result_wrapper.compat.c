@@ -0,0 +1,31 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum ErrorCode {
+  Ok,
+  InvalidArgument,
+  OutOfMemory
+};
+
+struct Point {
+  float x;
+  float y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+enum ErrorCode reset_ffi(void);
+
+enum ErrorCode parse_count_ffi(const uint8_t *text, uintptr_t len, uint32_t *out);
+
+int32_t make_point_ffi(float x, float y, struct Point *out);
+
+enum ErrorCode find_point_ffi(uint32_t id, const struct Point **out);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
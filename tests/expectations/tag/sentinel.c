@@ -10,7 +10,7 @@ enum A {
   /**
    * Must be last for serialization purposes
    */
-  A_Sentinel,
+  A_Sentinel
 };
 typedef uint8_t A;
 
@@ -21,7 +21,7 @@ enum B {
   /**
    * Must be last for serialization purposes
    */
-  B_Sentinel,
+  B_Sentinel
 };
 typedef uint8_t B;
 
@@ -32,7 +32,7 @@ enum C_Tag {
   /**
    * Must be last for serialization purposes
    */
-  C_Sentinel,
+  C_Sentinel
 };
 typedef uint8_t C_Tag;
 
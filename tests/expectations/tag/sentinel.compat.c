@@ -14,7 +14,7 @@ enum A
   /**
    * Must be last for serialization purposes
    */
-  A_Sentinel,
+  A_Sentinel
 };
 #ifndef __cplusplus
 typedef uint8_t A;
@@ -31,7 +31,7 @@ enum B
   /**
    * Must be last for serialization purposes
    */
-  B_Sentinel,
+  B_Sentinel
 };
 #ifndef __cplusplus
 typedef uint8_t B;
@@ -48,7 +48,7 @@ enum C_Tag
   /**
    * Must be last for serialization purposes
    */
-  C_Sentinel,
+  C_Sentinel
 };
 #ifndef __cplusplus
 typedef uint8_t C_Tag;
@@ -0,0 +1,20 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Exported {
+  int32_t x;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Exported e);
+
+int32_t handle_new(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,36 @@
+#ifndef SPLIT_BY_MODULE_H
+#define SPLIT_BY_MODULE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include "split_by_module.compat_geometry.c"
+#include "split_by_module.compat_limits.c"
+#include "split_by_module.compat_render.c"
+
+struct Context {
+  struct Canvas canvas;
+  float scale;
+};
+
+/**
+ * Only uses `limits` for the length of its array.
+ */
+struct Name {
+  uint8_t chars[NAME_LEN];
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct Context context_new(uint32_t width, uint32_t height);
+
+void name_init(struct Name *name);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#endif /* SPLIT_BY_MODULE_H */
@@ -0,0 +1,19 @@
+#ifndef SPLIT_BY_MODULE_CRATE_H_GEOMETRY
+#define SPLIT_BY_MODULE_CRATE_H_GEOMETRY
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Rect {
+  struct Point origin;
+  struct Point size;
+};
+
+#endif /* SPLIT_BY_MODULE_CRATE_H_GEOMETRY */
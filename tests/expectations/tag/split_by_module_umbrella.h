@@ -0,0 +1,9 @@
+#ifndef SPLIT_BY_MODULE_H_UMBRELLA
+#define SPLIT_BY_MODULE_H_UMBRELLA
+
+#include "split_by_module_geometry.c"
+#include "split_by_module_limits.c"
+#include "split_by_module_render.c"
+#include "split_by_module.c"
+
+#endif /* SPLIT_BY_MODULE_H_UMBRELLA */
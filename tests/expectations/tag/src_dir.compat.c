@@ -0,0 +1,30 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define MAX_POINTS 16
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Points {
+  struct Point points[16];
+  uintptr_t len;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+float point_length(struct Point p);
+
+uintptr_t points_count(const struct Points *points);
+
+void internal(void);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
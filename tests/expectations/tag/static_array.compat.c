@@ -0,0 +1,25 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define LEN 4
+
+struct Entry {
+  uint32_t key;
+  float value;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+extern const uint32_t TABLE[4];
+
+extern const struct Entry ENTRIES[LEN];
+
+extern uint8_t GRID[3][2];
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -18,4 +18,4 @@ struct Foo {
 
 
 
-void root(struct Foo x, struct Bar bar);
+void root(struct Foo x, const struct Bar *bar);
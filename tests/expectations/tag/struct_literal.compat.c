@@ -22,7 +22,7 @@ struct Foo {
 extern "C" {
 #endif // __cplusplus
 
-void root(struct Foo x, struct Bar bar);
+void root(struct Foo x, const struct Bar *bar);
 
 #ifdef __cplusplus
 } // extern "C"
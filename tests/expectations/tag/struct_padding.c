@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct InvalidPadding;
+
+struct ZeroPadding;
+
+struct Padded {
+  uint32_t a;
+  uint8_t b;
+  uint8_t _pad[4];
+};
+
+struct OnlyPadding {
+  uint8_t _pad[16];
+};
+
+void root(struct Padded a,
+          struct OnlyPadding b,
+          const struct ZeroPadding *c,
+          const struct InvalidPadding *d);
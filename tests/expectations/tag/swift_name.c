@@ -41,7 +41,7 @@ void free_function_should_exist_ref(const struct SelfTypeTestStruct *test_struct
 
 void free_function_should_exist_ref_mut(struct SelfTypeTestStruct *test_struct) CF_SWIFT_NAME(free_function_should_exist_ref_mut(test_struct:));
 
-void unnamed_argument(struct SelfTypeTestStruct*);
+void unnamed_argument(struct SelfTypeTestStruct *arg0);
 
 void free_function_should_not_exist_box(struct SelfTypeTestStruct *boxed) CF_SWIFT_NAME(free_function_should_not_exist_box(boxed:));
 
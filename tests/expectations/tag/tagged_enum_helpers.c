@@ -0,0 +1,114 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Point {
+  float x;
+  float y;
+};
+
+enum Shape_Tag {
+  Circle,
+  Rect,
+  Empty
+};
+
+struct Circle_Body {
+  struct Point _0;
+  float _1;
+};
+
+struct Rect_Body {
+  struct Point min;
+  struct Point max;
+};
+
+struct Shape {
+  enum Shape_Tag tag;
+  union {
+    struct Circle_Body circle;
+    struct Rect_Body rect;
+  };
+};
+
+static inline bool Shape_is_Circle(const struct Shape *self) {
+  return self->tag == Circle;
+}
+
+static inline const struct Circle_Body *Shape_get_Circle(const struct Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+static inline bool Shape_is_Rect(const struct Shape *self) {
+  return self->tag == Rect;
+}
+
+static inline const struct Rect_Body *Shape_get_Rect(const struct Shape *self) {
+  return self->tag == Rect ? &self->rect : NULL;
+}
+
+static inline bool Shape_is_Empty(const struct Shape *self) {
+  return self->tag == Empty;
+}
+
+enum Value_Tag {
+  Int,
+  Float,
+  Nothing
+};
+typedef uint8_t Value_Tag;
+
+struct Int_Body {
+  Value_Tag tag;
+  int32_t _0;
+};
+
+struct Float_Body {
+  Value_Tag tag;
+  float _0;
+};
+
+union Value {
+  Value_Tag tag;
+  struct Int_Body int_;
+  struct Float_Body float_;
+};
+
+static inline bool Value_is_Int(const union Value *self) {
+  return self->tag == Int;
+}
+
+static inline const struct Int_Body *Value_get_Int(const union Value *self) {
+  return self->tag == Int ? &self->int_ : NULL;
+}
+
+static inline bool Value_is_Float(const union Value *self) {
+  return self->tag == Float;
+}
+
+static inline const struct Float_Body *Value_get_Float(const union Value *self) {
+  return self->tag == Float ? &self->float_ : NULL;
+}
+
+static inline bool Value_is_Nothing(const union Value *self) {
+  return self->tag == Nothing;
+}
+
+enum Plain_Tag {
+  A,
+  B
+};
+
+struct A_Body {
+  int32_t _0;
+};
+
+struct Plain {
+  enum Plain_Tag tag;
+  union {
+    struct A_Body a;
+  };
+};
+
+void root(struct Shape s, union Value v, struct Plain p);
@@ -0,0 +1,27 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Backend {
+  Poll,
+  Epoll
+};
+typedef uint8_t Backend;
+
+struct EpollInstance {
+  int32_t fd;
+};
+
+struct Options {
+  uint32_t flags;
+  Backend backend;
+};
+
+struct EpollInstance epoll_create(struct Options options);
+
+void unix_x86_64_only(void);
+
+#if defined(HAS_IO_URING)
+void io_uring_setup(void);
+#endif
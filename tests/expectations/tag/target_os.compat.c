@@ -0,0 +1,41 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+enum Backend
+#ifdef __cplusplus
+  : uint8_t
+#endif // __cplusplus
+ {
+  Poll,
+  Epoll
+};
+#ifndef __cplusplus
+typedef uint8_t Backend;
+#endif // __cplusplus
+
+struct EpollInstance {
+  int32_t fd;
+};
+
+struct Options {
+  uint32_t flags;
+  Backend backend;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+struct EpollInstance epoll_create(struct Options options);
+
+void unix_x86_64_only(void);
+
+#if defined(HAS_IO_URING)
+void io_uring_setup(void);
+#endif
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,32 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Vec2_i32 {
+  int32_t x;
+  int32_t y;
+};
+
+typedef struct Vec2_i32 IntVec;
+
+struct Vec2_f32 {
+  float x;
+  float y;
+};
+
+typedef struct Vec2_f32 FloatVec;
+
+struct Pair_u32__IntVec {
+  uint32_t key;
+  IntVec value;
+};
+
+typedef struct Pair_u32__IntVec Entry;
+
+struct Vec2_u8 {
+  uint8_t x;
+  uint8_t y;
+};
+
+void root(IntVec a, FloatVec b, Entry c, struct Vec2_u8 d);
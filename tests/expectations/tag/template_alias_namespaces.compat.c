@@ -0,0 +1,50 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace engine {
+namespace math {
+#endif // __cplusplus
+
+struct Vec2_i32 {
+  int32_t x;
+  int32_t y;
+};
+
+typedef struct Vec2_i32 IntVec;
+
+struct Vec2_f32 {
+  float x;
+  float y;
+};
+
+typedef struct Vec2_f32 FloatVec;
+
+struct Pair_u32__IntVec {
+  uint32_t key;
+  IntVec value;
+};
+
+typedef struct Pair_u32__IntVec Entry;
+
+struct Vec2_u8 {
+  uint8_t x;
+  uint8_t y;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(IntVec a, FloatVec b, Entry c, struct Vec2_u8 d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace math
+} // namespace engine
+#endif // __cplusplus
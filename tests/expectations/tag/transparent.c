@@ -32,4 +32,4 @@ void root(TransparentComplexWrappingStructTuple a,
           TransparentComplexWrapper_i32 e,
           TransparentPrimitiveWrapper_i32 f,
           TransparentPrimitiveWithAssociatedConstants g,
-          struct EnumWithAssociatedConstantInImpl h);
+          const struct EnumWithAssociatedConstantInImpl *h);
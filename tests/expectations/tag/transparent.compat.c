@@ -36,7 +36,7 @@ void root(TransparentComplexWrappingStructTuple a,
           TransparentComplexWrapper_i32 e,
           TransparentPrimitiveWrapper_i32 f,
           TransparentPrimitiveWithAssociatedConstants g,
-          struct EnumWithAssociatedConstantInImpl h);
+          const struct EnumWithAssociatedConstantInImpl *h);
 
 #ifdef __cplusplus
 } // extern "C"
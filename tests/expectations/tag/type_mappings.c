@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+#include <stdio.h>
+#include <time.h>
+
+struct Entry {
+  time_t modified;
+  FILE *file;
+};
+
+/**
+ * Its mapping to a pointer type is ignored, so it's written as a typedef.
+ */
+typedef uint8_t *Buffer;
+
+void root(struct Entry entry, time_t now, FILE *out, Buffer buffer);
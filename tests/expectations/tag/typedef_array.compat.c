@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef float Mat4[4][4];
+
+typedef float Row[4];
+
+struct Camera {
+  Mat4 view;
+  Mat4 projection;
+  Row origin;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Camera camera, const Mat4 *transform);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * A distance in meters.
+ */
+typedef double Meters;
+
+typedef uint32_t Id;
+#define Id_INVALID 0
+
+struct Handle {
+  uint8_t *_0;
+};
+
+struct Pair {
+  uint32_t a;
+  uint32_t b;
+};
+
+void root(Meters m, Id id, struct Handle h, struct Pair p);
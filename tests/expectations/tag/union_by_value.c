@@ -0,0 +1,21 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+union MyUnion {
+  uint32_t as_int;
+  float as_float;
+  uint8_t as_bytes[4];
+};
+
+union GenericUnion_f64 {
+  double value;
+  uint64_t raw;
+};
+
+typedef union MyUnion Alias;
+
+union MyUnion f(union MyUnion u);
+
+union GenericUnion_f64 g(union GenericUnion_f64 u, Alias alias);
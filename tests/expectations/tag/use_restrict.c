@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+void buffer_clear(struct Buffer *restrict self);
+
+void copy(struct Buffer *restrict dst,
+          const struct Buffer *src,
+          struct Buffer *raw,
+          uintptr_t *restrict len);
+
+void optional(struct Buffer *dst);
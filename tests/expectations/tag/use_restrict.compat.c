@@ -0,0 +1,23 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void buffer_clear(struct Buffer *self);
+
+void copy(struct Buffer *dst, const struct Buffer *src, struct Buffer *raw, uintptr_t *len);
+
+void optional(struct Buffer *dst);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
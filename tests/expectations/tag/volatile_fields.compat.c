@@ -0,0 +1,39 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Function pointers can't be volatile, so this is opaque.
+ */
+struct Callback;
+
+struct Registers {
+  volatile uint32_t status;
+  volatile const uint32_t *data;
+  volatile uint8_t *control;
+  volatile uint16_t fifo[4];
+  const uint32_t *plain;
+};
+
+struct Wrapper_i32 {
+  volatile int32_t value;
+};
+
+struct Flags {
+  volatile uint32_t mode : 3;
+  uint32_t rest : 5;
+};
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(struct Registers *registers,
+          struct Wrapper_i32 wrapper,
+          struct Flags flags,
+          const struct Callback *callback);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+struct Packet {
+  uint32_t len;
+  uint8_t payload[0];
+};
+
+struct Marked {
+  uint32_t count;
+  uint16_t items[];
+};
+
+void packet_send(const struct Packet *packet, const struct Marked *marked);
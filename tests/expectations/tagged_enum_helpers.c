@@ -0,0 +1,114 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  float x;
+  float y;
+} Point;
+
+typedef enum {
+  Circle,
+  Rect,
+  Empty
+} Shape_Tag;
+
+typedef struct {
+  Point _0;
+  float _1;
+} Circle_Body;
+
+typedef struct {
+  Point min;
+  Point max;
+} Rect_Body;
+
+typedef struct {
+  Shape_Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  };
+} Shape;
+
+static inline bool Shape_is_Circle(const Shape *self) {
+  return self->tag == Circle;
+}
+
+static inline const Circle_Body *Shape_get_Circle(const Shape *self) {
+  return self->tag == Circle ? &self->circle : NULL;
+}
+
+static inline bool Shape_is_Rect(const Shape *self) {
+  return self->tag == Rect;
+}
+
+static inline const Rect_Body *Shape_get_Rect(const Shape *self) {
+  return self->tag == Rect ? &self->rect : NULL;
+}
+
+static inline bool Shape_is_Empty(const Shape *self) {
+  return self->tag == Empty;
+}
+
+enum Value_Tag {
+  Int,
+  Float,
+  Nothing
+};
+typedef uint8_t Value_Tag;
+
+typedef struct {
+  Value_Tag tag;
+  int32_t _0;
+} Int_Body;
+
+typedef struct {
+  Value_Tag tag;
+  float _0;
+} Float_Body;
+
+typedef union {
+  Value_Tag tag;
+  Int_Body int_;
+  Float_Body float_;
+} Value;
+
+static inline bool Value_is_Int(const Value *self) {
+  return self->tag == Int;
+}
+
+static inline const Int_Body *Value_get_Int(const Value *self) {
+  return self->tag == Int ? &self->int_ : NULL;
+}
+
+static inline bool Value_is_Float(const Value *self) {
+  return self->tag == Float;
+}
+
+static inline const Float_Body *Value_get_Float(const Value *self) {
+  return self->tag == Float ? &self->float_ : NULL;
+}
+
+static inline bool Value_is_Nothing(const Value *self) {
+  return self->tag == Nothing;
+}
+
+typedef enum {
+  A,
+  B
+} Plain_Tag;
+
+typedef struct {
+  int32_t _0;
+} A_Body;
+
+typedef struct {
+  Plain_Tag tag;
+  union {
+    A_Body a;
+  };
+} Plain;
+
+void root(Shape s, Value v, Plain p);
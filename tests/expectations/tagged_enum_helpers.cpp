@@ -0,0 +1,80 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Point {
+  float x;
+  float y;
+};
+
+struct Shape {
+  enum class Tag {
+    Circle,
+    Rect,
+    Empty
+  };
+
+  struct Circle_Body {
+    Point _0;
+    float _1;
+  };
+
+  struct Rect_Body {
+    Point min;
+    Point max;
+  };
+
+  Tag tag;
+  union {
+    Circle_Body circle;
+    Rect_Body rect;
+  };
+};
+
+union Value {
+  enum class Tag : uint8_t {
+    Int,
+    Float,
+    Nothing
+  };
+
+  struct Int_Body {
+    Tag tag;
+    int32_t _0;
+  };
+
+  struct Float_Body {
+    Tag tag;
+    float _0;
+  };
+
+  struct {
+    Tag tag;
+  };
+  Int_Body int_;
+  Float_Body float_;
+};
+
+struct Plain {
+  enum class Tag {
+    A,
+    B
+  };
+
+  struct A_Body {
+    int32_t _0;
+  };
+
+  Tag tag;
+  union {
+    A_Body a;
+  };
+};
+
+extern "C" {
+
+void root(Shape s, Value v, Plain p);
+
+} // extern "C"
@@ -0,0 +1,31 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+enum class Backend : uint8_t {
+  Poll,
+  Epoll
+};
+
+struct EpollInstance {
+  int32_t fd;
+};
+
+struct Options {
+  uint32_t flags;
+  Backend backend;
+};
+
+extern "C" {
+
+EpollInstance epoll_create(Options options);
+
+void unix_x86_64_only();
+
+#if defined(HAS_IO_URING)
+void io_uring_setup();
+#endif
+
+} // extern "C"
@@ -0,0 +1,50 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#ifdef __cplusplus
+namespace engine {
+namespace math {
+#endif // __cplusplus
+
+typedef struct {
+  int32_t x;
+  int32_t y;
+} Vec2_i32;
+
+typedef Vec2_i32 IntVec;
+
+typedef struct {
+  float x;
+  float y;
+} Vec2_f32;
+
+typedef Vec2_f32 FloatVec;
+
+typedef struct {
+  uint32_t key;
+  IntVec value;
+} Pair_u32__IntVec;
+
+typedef Pair_u32__IntVec Entry;
+
+typedef struct {
+  uint8_t x;
+  uint8_t y;
+} Vec2_u8;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(IntVec a, FloatVec b, Entry c, Vec2_u8 d);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
+
+#ifdef __cplusplus
+} // namespace math
+} // namespace engine
+#endif // __cplusplus
@@ -0,0 +1,35 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+namespace engine {
+namespace math {
+
+template<typename T>
+struct Vec2 {
+  T x;
+  T y;
+};
+
+using IntVec = Vec2<int32_t>;
+
+using FloatVec = Vec2<float>;
+
+template<typename K, typename V>
+struct Pair {
+  K key;
+  V value;
+};
+
+using Entry = Pair<uint32_t, IntVec>;
+
+extern "C" {
+
+void root(IntVec a, FloatVec b, Entry c, Vec2<uint8_t> d);
+
+} // extern "C"
+
+} // namespace math
+} // namespace engine
@@ -17,7 +17,7 @@ union StyleFoo {
     Foo,
     Bar,
     Baz,
-    Bazz,
+    Bazz
   };
 
   struct Foo_Body {
@@ -128,7 +128,7 @@ struct StyleBar {
     Bar1,
     Bar2,
     Bar3,
-    Bar4,
+    Bar4
   };
 
   struct StyleBar1_Body {
@@ -156,7 +156,7 @@ struct StyleBar {
   static StyleBar Bar1(const int32_t &x,
                        const StylePoint<T> &y,
                        const StylePoint<float> &z,
-                       int32_t (*&u)(int32_t)) {
+                       int32_t (*const &u)(int32_t)) {
     StyleBar result;
     ::new (&result.bar1.x) (int32_t)(x);
     ::new (&result.bar1.y) (StylePoint<T>)(y);
@@ -237,7 +237,7 @@ union StyleBaz {
   enum class Tag : uint8_t {
     Baz1,
     Baz2,
-    Baz3,
+    Baz3
   };
 
   struct Baz1_Body {
@@ -313,7 +313,7 @@ struct StyleTaz {
   enum class Tag : uint8_t {
     Taz1,
     Taz2,
-    Taz3,
+    Taz3
   };
 
   struct StyleTaz1_Body {
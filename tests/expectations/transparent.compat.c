@@ -36,7 +36,7 @@ void root(TransparentComplexWrappingStructTuple a,
           TransparentComplexWrapper_i32 e,
           TransparentPrimitiveWrapper_i32 f,
           TransparentPrimitiveWithAssociatedConstants g,
-          EnumWithAssociatedConstantInImpl h);
+          const EnumWithAssociatedConstantInImpl *h);
 
 #ifdef __cplusplus
 } // extern "C"
@@ -37,6 +37,6 @@ void root(TransparentComplexWrappingStructTuple a,
           TransparentComplexWrapper<int32_t> e,
           TransparentPrimitiveWrapper<int32_t> f,
           TransparentPrimitiveWithAssociatedConstants g,
-          EnumWithAssociatedConstantInImpl h);
+          const EnumWithAssociatedConstantInImpl *h);
 
 } // extern "C"
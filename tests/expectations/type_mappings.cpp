@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+#include <stdio.h>
+#include <time.h>
+
+struct Entry {
+  time_t modified;
+  FILE *file;
+};
+
+/// Its mapping to a pointer type is ignored, so it's written as a typedef.
+using Buffer = uint8_t*;
+
+extern "C" {
+
+void root(Entry entry, time_t now, FILE *out, Buffer buffer);
+
+} // extern "C"
@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Mat4 = float[4][4];
+
+using Row = float[4];
+
+struct Camera {
+  Mat4 view;
+  Mat4 projection;
+  Row origin;
+};
+
+extern "C" {
+
+void root(Camera camera, const Mat4 *transform);
+
+} // extern "C"
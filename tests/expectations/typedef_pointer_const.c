@@ -0,0 +1,18 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef uint8_t *Buf;
+
+typedef const uint8_t *ConstBuf;
+
+typedef uint8_t *const *ConstPtrToMut;
+
+typedef const uint8_t **MutPtrToConst;
+
+typedef const uint32_t *ConstRef;
+
+typedef uint32_t *MutRef;
+
+void root(Buf a, ConstBuf b, ConstPtrToMut c, MutPtrToConst d, ConstRef e, MutRef f);
@@ -0,0 +1,23 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+using Buf = uint8_t*;
+
+using ConstBuf = const uint8_t*;
+
+using ConstPtrToMut = uint8_t*const *;
+
+using MutPtrToConst = const uint8_t**;
+
+using ConstRef = const uint32_t*;
+
+using MutRef = uint32_t*;
+
+extern "C" {
+
+void root(Buf a, ConstBuf b, ConstPtrToMut c, MutPtrToConst d, ConstRef e, MutRef f);
+
+} // extern "C"
@@ -0,0 +1,26 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// A distance in meters.
+using Meters = double;
+
+using Id = uint32_t;
+static const Id Id_INVALID = 0;
+
+struct Handle {
+  uint8_t *_0;
+};
+
+struct Pair {
+  uint32_t a;
+  uint32_t b;
+};
+
+extern "C" {
+
+void root(Meters m, Id id, Handle h, Pair p);
+
+} // extern "C"
@@ -0,0 +1,29 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef union {
+  uint32_t as_int;
+  float as_float;
+  uint8_t as_bytes[4];
+} MyUnion;
+
+typedef union {
+  double value;
+  uint64_t raw;
+} GenericUnion_f64;
+
+typedef MyUnion Alias;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+MyUnion f(MyUnion u);
+
+GenericUnion_f64 g(GenericUnion_f64 u, Alias alias);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
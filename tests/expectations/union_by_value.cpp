@@ -0,0 +1,27 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+union MyUnion {
+  uint32_t as_int;
+  float as_float;
+  uint8_t as_bytes[4];
+};
+
+template<typename T>
+union GenericUnion {
+  T value;
+  uint64_t raw;
+};
+
+using Alias = MyUnion;
+
+extern "C" {
+
+MyUnion f(MyUnion u);
+
+GenericUnion<double> g(GenericUnion<double> u, Alias alias);
+
+} // extern "C"
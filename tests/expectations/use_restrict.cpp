@@ -0,0 +1,20 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Buffer {
+  uint8_t *data;
+  uintptr_t len;
+};
+
+extern "C" {
+
+void buffer_clear(Buffer *self);
+
+void copy(Buffer *dst, const Buffer *src, Buffer *raw, uintptr_t *len);
+
+void optional(Buffer *dst);
+
+} // extern "C"
@@ -0,0 +1,36 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Function pointers can't be volatile, so this is opaque.
+ */
+typedef struct Callback Callback;
+
+typedef struct {
+  volatile uint32_t status;
+  volatile const uint32_t *data;
+  volatile uint8_t *control;
+  volatile uint16_t fifo[4];
+  const uint32_t *plain;
+} Registers;
+
+typedef struct {
+  volatile int32_t value;
+} Wrapper_i32;
+
+typedef struct {
+  volatile uint32_t mode : 3;
+  uint32_t rest : 5;
+} Flags;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void root(Registers *registers, Wrapper_i32 wrapper, Flags flags, const Callback *callback);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
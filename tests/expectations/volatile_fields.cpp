@@ -0,0 +1,32 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+/// Function pointers can't be volatile, so this is opaque.
+struct Callback;
+
+struct Registers {
+  volatile uint32_t status;
+  volatile const uint32_t *data;
+  volatile uint8_t *control;
+  volatile uint16_t fifo[4];
+  const uint32_t *plain;
+};
+
+template<typename T>
+struct Wrapper {
+  volatile T value;
+};
+
+struct Flags {
+  volatile uint32_t mode : 3;
+  uint32_t rest : 5;
+};
+
+extern "C" {
+
+void root(Registers *registers, Wrapper<int32_t> wrapper, Flags flags, const Callback *callback);
+
+} // extern "C"
@@ -0,0 +1,16 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t len;
+  uint8_t payload[0];
+} Packet;
+
+typedef struct {
+  uint32_t count;
+  uint16_t items[];
+} Marked;
+
+void packet_send(const Packet *packet, const Marked *marked);
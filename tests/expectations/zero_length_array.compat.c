@@ -0,0 +1,24 @@
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+typedef struct {
+  uint32_t len;
+  uint8_t payload[0];
+} Packet;
+
+typedef struct {
+  uint32_t count;
+  uint16_t items[];
+} Marked;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+void packet_send(const Packet *packet, const Marked *marked);
+
+#ifdef __cplusplus
+} // extern "C"
+#endif // __cplusplus
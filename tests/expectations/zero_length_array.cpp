@@ -0,0 +1,21 @@
+#include <cstdarg>
+#include <cstdint>
+#include <cstdlib>
+#include <ostream>
+#include <new>
+
+struct Packet {
+  uint32_t len;
+  uint8_t payload[0];
+};
+
+struct Marked {
+  uint32_t count;
+  uint16_t items[0];
+};
+
+extern "C" {
+
+void packet_send(const Packet *packet, const Marked *marked);
+
+} // extern "C"
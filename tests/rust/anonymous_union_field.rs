@@ -0,0 +1,26 @@
+#[repr(C)]
+pub union Value {
+    /// The value as an integer.
+    as_int: i32,
+    as_float: f32,
+    as_ptr: *const u8,
+}
+
+#[repr(C)]
+pub struct Variant {
+    kind: u8,
+    /// cbindgen:anonymous-union
+    value: Value,
+    /// Still a named field.
+    other: Value,
+}
+
+#[repr(C)]
+pub struct Tuple(
+    u32,
+    /// cbindgen:anonymous-union
+    Value,
+);
+
+#[no_mangle]
+pub extern "C" fn root(v: Variant, t: Tuple) {}
@@ -0,0 +1,12 @@
+pub const NAME_LEN: usize = 16;
+
+pub const UNUSED: u32 = 7;
+
+#[repr(C)]
+pub struct Record {
+    id: u32,
+    name: [u8; NAME_LEN],
+}
+
+#[no_mangle]
+pub extern "C" fn record_init(record: *mut Record) {}
@@ -0,0 +1,12 @@
+/// cbindgen:assume-repr-c
+pub struct Assumed {
+    x: i32,
+    y: f32,
+}
+
+pub struct NotAssumed {
+    x: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Assumed, b: *const NotAssumed) {}
@@ -0,0 +1,7 @@
+#[repr(C)]
+pub struct Foo {
+    x: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(foo: Foo) {}
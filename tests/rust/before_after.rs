@@ -0,0 +1,15 @@
+#[repr(C)]
+pub struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C)]
+pub struct Plain {
+    a: u8,
+}
+
+pub const LIMIT: u32 = 10;
+
+#[no_mangle]
+pub extern "C" fn root(p: Packed, q: Plain) {}
@@ -0,0 +1,31 @@
+#[repr(C)]
+pub struct Flags {
+    /// cbindgen:bitfield=1
+    visible: u32,
+    /// cbindgen:bitfield=1
+    /// Whether the item can be resized.
+    resizable: u32,
+    /// cbindgen:bitfield=6
+    layer: u32,
+    /// cbindgen:bitfield=4
+    kind: u8,
+    id: u32,
+}
+
+#[repr(C)]
+pub struct Packed(
+    /// cbindgen:bitfield=3
+    u16,
+    /// cbindgen:bitfield=13
+    u16,
+);
+
+/// Signed bit-fields aren't supported, so this struct is skipped.
+#[repr(C)]
+pub struct Signed {
+    /// cbindgen:bitfield=2
+    value: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(flags: Flags, packed: Packed) {}
@@ -0,0 +1,17 @@
+pub const ONE: u32 = 1;
+pub const TWO: u32 = 2;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub static ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[no_mangle]
+pub extern "C" fn first(p: Point) {}
+
+#[no_mangle]
+pub extern "C" fn second(p: Point) {}
@@ -0,0 +1,12 @@
+/// Has no `#[repr]`, so it can't be passed by value.
+pub struct Handle {
+    id: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn legacy(h: Handle) {}
+
+#[no_mangle]
+pub extern "C" fn handle_id(h: *const Handle) -> u32 {
+    0
+}
@@ -0,0 +1,23 @@
+/// Has no `#[repr]`, but is written as the type it's mapped to.
+pub struct Handle {
+    id: u32,
+}
+
+pub type Alias = Handle;
+
+/// Has no `#[repr]` either.
+pub union Bits {
+    int: u32,
+    float: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn handle_close(h: Alias) {}
+
+#[no_mangle]
+pub extern "C" fn handle_open() -> Handle {
+    Handle { id: 0 }
+}
+
+#[no_mangle]
+pub extern "C" fn bits_set(bits: Bits) {}
@@ -0,0 +1,14 @@
+use std::os::raw::c_char;
+
+#[no_mangle]
+pub extern "C" fn name() -> *const c_char {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn name_copy() -> *mut c_char {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn set_name(name: *const c_char, initial: char) {}
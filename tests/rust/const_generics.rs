@@ -0,0 +1,26 @@
+#[repr(C)]
+pub struct Buf<const N: usize> {
+    data: [u8; N],
+    len: usize,
+}
+
+#[repr(C)]
+pub struct Matrix<T, const ROWS: usize, const COLS: usize> {
+    rows: [[T; COLS]; ROWS],
+}
+
+#[repr(C)]
+pub struct Packet<const N: usize> {
+    header: u32,
+    payload: Buf<N>,
+}
+
+pub type SmallBuf = Buf<4>;
+
+#[no_mangle]
+pub extern "C" fn root(
+    small: SmallBuf,
+    packet: *const Packet<16>,
+    matrix: Matrix<f32, 2, 3>,
+) {
+}
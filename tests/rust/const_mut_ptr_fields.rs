@@ -0,0 +1,35 @@
+#[repr(C)]
+pub struct Node {
+    value: i32,
+}
+
+#[repr(C)]
+pub struct Pointers<'a> {
+    const_prim: *const u8,
+    mut_prim: *mut u8,
+    const_struct: *const Node,
+    mut_struct: *mut Node,
+    const_to_mut: *const *mut Node,
+    mut_to_const: *mut *const Node,
+    shared_ref: &'a Node,
+    mut_ref: &'a mut Node,
+    nullable: Option<&'a Node>,
+}
+
+#[no_mangle]
+pub extern "C" fn pointers(
+    const_prim: *const u8,
+    mut_prim: *mut u8,
+    const_struct: *const Node,
+    mut_struct: *mut Node,
+    const_to_mut: *const *mut Node,
+    mut_to_const: *mut *const Node,
+    shared_ref: &Node,
+    mut_ref: &mut Node,
+    nullable: Option<&Node>,
+) -> *const Pointers<'static> {
+}
+
+#[no_mangle]
+pub extern "C" fn mut_return() -> *mut Pointers<'static> {
+}
@@ -0,0 +1,40 @@
+#[repr(u8)]
+pub enum Mode {
+    Read,
+    Write = 4,
+    Append,
+}
+
+/// cbindgen:enum-class=false
+#[repr(u16)]
+pub enum Flags {
+    FlagNone,
+    FlagAll,
+}
+
+/// Without an integer repr, the enum stays a plain enum.
+#[repr(C)]
+pub enum Color {
+    Red,
+    Green,
+}
+
+/// The tag of an enum with fields stays a plain enum.
+#[repr(u8)]
+pub enum Shape {
+    Circle(f32),
+    Square(f32),
+}
+
+#[repr(C)]
+pub struct Options {
+    mode: Mode,
+    flags: Flags,
+    color: Color,
+    shape: Shape,
+}
+
+#[no_mangle]
+pub extern "C" fn root(options: Options) -> Mode {
+    options.mode
+}
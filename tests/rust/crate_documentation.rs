@@ -0,0 +1,17 @@
+//! Bindings to the frobnicator.
+//!
+//! Create a frobnicator with `frob_new`, and release it with `frob_free`.
+
+/// A frobnicator.
+#[repr(C)]
+pub struct Frob {
+    level: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn frob_new(level: u32) -> *mut Frob {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn frob_free(frob: *mut Frob) {}
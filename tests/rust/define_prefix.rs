@@ -0,0 +1,31 @@
+pub const LEN: usize = 4;
+pub const DOUBLE_LEN: usize = LEN * 2;
+
+#[repr(u8)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+#[repr(C)]
+pub struct Buffer {
+    data: [u8; DOUBLE_LEN],
+    mode: Mode,
+}
+
+impl Buffer {
+    pub const CAPACITY: usize = 16;
+}
+
+#[no_mangle]
+pub extern "C" fn scale_i32(x: i32, factor: i32) -> i32 {
+    x * factor
+}
+
+#[no_mangle]
+pub extern "C" fn scale_f32(x: f32, factor: f32) -> f32 {
+    x * factor
+}
+
+#[no_mangle]
+pub extern "C" fn root(buffer: *mut Buffer) {}
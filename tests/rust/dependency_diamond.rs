@@ -0,0 +1,25 @@
+#[repr(C)]
+pub struct Top {
+    left: Left,
+    right: Right,
+}
+
+#[repr(C)]
+pub struct Left {
+    base: Base,
+    right: *const Right,
+}
+
+#[repr(C)]
+pub struct Right {
+    base: Base,
+    extra: [Base; 2],
+}
+
+#[repr(C)]
+pub struct Base {
+    value: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(top: Top) {}
@@ -0,0 +1,40 @@
+/// Copies bytes into a buffer.
+///
+/// # Arguments
+///
+/// * `dest` - Where to copy to.
+/// * `src` - Where to copy from.
+///
+/// # Returns
+///
+/// The number of bytes copied.
+#[no_mangle]
+pub extern "C" fn copy_bytes(dest: *mut u8, src: *const u8, len: usize) -> usize {
+    0
+}
+
+/// Resets the state.
+///
+/// @param flags Which parts of the state to reset.
+#[no_mangle]
+pub extern "C" fn reset(flags: u32, verbose: bool) {}
+
+#[no_mangle]
+pub extern "C" fn version() -> u32 {
+    1
+}
+
+/// Never returns.
+#[no_mangle]
+pub extern "C" fn abort_now(code: i32) -> ! {
+    loop {}
+}
+
+/// Fills a buffer.
+///
+/// \param[out] buffer The buffer to fill.
+/// @return Whether the buffer was filled.
+#[no_mangle]
+pub extern "C" fn fill(buffer: *mut u8, len: usize) -> bool {
+    false
+}
@@ -0,0 +1,36 @@
+/// A point in space.
+#[repr(C)]
+pub struct Point {
+    /// The x coordinate.
+    x: f32,
+    /// The y coordinate.
+    ///
+    /// Spans several lines, so it stays leading.
+    y: f32,
+    z: f32,
+}
+
+/// A plain enum.
+#[repr(C)]
+pub enum Color {
+    /// Red.
+    Red,
+    /// Green.
+    Green = 4,
+    Blue,
+}
+
+/// A tagged enum.
+#[repr(C, u8)]
+pub enum Shape {
+    /// A circle.
+    Circle {
+        /// Its radius.
+        radius: f32,
+    },
+    /// Nothing.
+    Empty,
+}
+
+#[no_mangle]
+pub extern "C" fn root(p: Point, c: Color, s: Shape) {}
@@ -0,0 +1,29 @@
+#[repr(u8)]
+enum Color {
+    /// The default color.
+    Red,
+    Green = 4,
+    Blue,
+}
+
+#[repr(C, i32)]
+enum Offset {
+    Before = -2,
+    Here,
+    After,
+}
+
+#[repr(C)]
+enum Plain {
+    First,
+    Second,
+}
+
+#[repr(i64)]
+enum Wide {
+    Small = 1,
+    Max = 9223372036854775807,
+}
+
+#[no_mangle]
+pub extern "C" fn root(c: Color, o: Offset, p: Plain, w: Wide) {}
@@ -0,0 +1,20 @@
+/// cbindgen:as-defines
+/// cbindgen:prefix-with-name
+/// A strong typedef of an integer, written as a typedef and defines.
+#[repr(u32)]
+pub enum Handle {
+    Invalid = 0,
+    Stdin = 1,
+    Stdout,
+    Stderr,
+}
+
+/// Written as an `enum`, since the option is disabled.
+#[repr(u8)]
+pub enum Mode {
+    Read,
+    Write,
+}
+
+#[no_mangle]
+pub extern "C" fn root(h: Handle, m: Mode) {}
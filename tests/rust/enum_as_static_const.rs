@@ -0,0 +1,23 @@
+#[repr(u8)]
+enum Color {
+    /// The default color.
+    Red,
+    Green = 4,
+    Blue,
+}
+
+#[repr(C, i32)]
+enum Offset {
+    Before = -2,
+    Here,
+    After,
+}
+
+#[repr(C)]
+enum Plain {
+    First,
+    Second,
+}
+
+#[no_mangle]
+pub extern "C" fn root(c: Color, o: Offset, p: Plain) {}
@@ -0,0 +1,22 @@
+#[repr(C, u8)]
+enum Fieldless {
+    FieldlessA,
+    FieldlessB,
+}
+
+#[repr(i16, C)]
+enum Signed {
+    SignedA = -1,
+    SignedB = 1,
+}
+
+#[repr(C)]
+#[repr(u32)]
+enum Tagged {
+    TaggedA(u8),
+    TaggedB { x: i16 },
+    TaggedC,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Fieldless, b: Signed, c: Tagged) {}
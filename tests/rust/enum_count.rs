@@ -0,0 +1,36 @@
+#[repr(u8)]
+enum Contiguous {
+    A,
+    B,
+    C,
+}
+
+#[repr(C)]
+enum Explicit {
+    X = 0,
+    Y = 1,
+    Z = 2,
+}
+
+/// cbindgen:add-count=false
+#[repr(C)]
+enum NotCounted {
+    P,
+    Q,
+}
+
+#[repr(u32)]
+enum Gaps {
+    First = 1,
+    Second = 5,
+}
+
+#[repr(u8)]
+enum Tagged {
+    Foo(i32),
+    Bar { x: u8 },
+    Baz,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Contiguous, b: Explicit, c: NotCounted, d: Gaps, e: Tagged) {}
@@ -0,0 +1,20 @@
+pub const UNIX_BASE: u8 = 4;
+
+pub const fn windows_base() -> u8 {
+    8
+}
+
+#[cfg(unix)]
+#[repr(u8)]
+pub enum Mode {
+    Read = UNIX_BASE,
+    Write,
+}
+
+/// Opaque, since `windows_base()` can't be evaluated.
+#[cfg(windows)]
+#[repr(u8)]
+pub enum Mode {
+    Read = windows_base(),
+    Write,
+}
@@ -0,0 +1,58 @@
+pub const BASE: u32 = 16;
+pub const SHIFT: u32 = 2;
+pub const DERIVED: u32 = BASE * 2;
+pub const NOT_AN_INTEGER: f32 = 1.5;
+pub const LOW: u8 = 2;
+pub const SIGNED: i8 = 5;
+
+#[repr(u32)]
+pub enum Flags {
+    None = 0,
+    First = 1 << SHIFT,
+    Second = (Flags::First as u32) << 1,
+    Both = Self::First as u32 | Self::Second as u32,
+    Derived = DERIVED + 1,
+    Next,
+}
+
+#[repr(i32)]
+pub enum Offsets {
+    Low = -(BASE as i32),
+    Mid = Offsets::Low as i32 + 8,
+    High = !(Self::Low as i32),
+}
+
+#[repr(u8)]
+pub enum Complement {
+    AllOnes = !0,
+    Parenthesized = !(1),
+    OfConstant = !LOW,
+}
+
+#[repr(i8)]
+pub enum SignedComplement {
+    MinusOne = !0,
+    MinusTwo = !(1),
+    OfSigned = !SIGNED,
+}
+
+#[repr(u8)]
+pub enum UnknownConstant {
+    A = MISSING,
+}
+
+#[repr(u8)]
+pub enum FloatConstant {
+    A = NOT_AN_INTEGER,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    f: Flags,
+    o: Offsets,
+    c: Complement,
+    s: SignedComplement,
+    u: *const UnknownConstant,
+    x: *const FloatConstant,
+) {
+}
@@ -0,0 +1,24 @@
+/// Variants behind different cfgs may share a value, and are written as they
+/// are, like C allows.
+#[repr(C)]
+pub enum Status {
+    #[cfg(feature = "legacy")]
+    A = 1,
+    #[cfg(not(feature = "legacy"))]
+    Alias = 1,
+    B,
+    C = 3,
+}
+
+#[repr(u8)]
+pub enum Level {
+    Low = 3,
+    #[cfg(feature = "legacy")]
+    Medium = 4,
+    #[cfg(not(feature = "legacy"))]
+    Middle = 4,
+    High,
+}
+
+#[no_mangle]
+pub extern "C" fn root(s: Status, l: Level) {}
@@ -0,0 +1,20 @@
+/// A type-level tag.
+#[repr(C)]
+pub enum Marker {
+    Only,
+}
+
+#[repr(u8)]
+pub enum Version {
+    V1 = 1,
+}
+
+/// Still written as an `enum`, since it has two variants.
+#[repr(C)]
+pub enum Pair {
+    First,
+    Second,
+}
+
+#[no_mangle]
+pub extern "C" fn root(m: Marker, v: Version, p: Pair) {}
@@ -0,0 +1,22 @@
+#[repr(C)]
+enum Plain {
+    A,
+    /// The last variant.
+    B = 5,
+}
+
+#[repr(u8)]
+enum Tagged {
+    Foo(i32),
+    Bar { x: f32 },
+}
+
+#[repr(C)]
+enum Conditional {
+    First,
+    #[cfg(feature = "second")]
+    Second,
+}
+
+#[no_mangle]
+pub extern "C" fn root(plain: Plain, tagged: Tagged, conditional: Conditional) {}
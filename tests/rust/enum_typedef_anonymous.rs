@@ -0,0 +1,21 @@
+#[repr(C)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+#[repr(u8)]
+enum Small {
+    One,
+    Two,
+}
+
+#[repr(C)]
+enum Shape {
+    Circle(f32),
+    Square(f32),
+}
+
+#[no_mangle]
+pub extern "C" fn root(color: Color, small: Small, shape: Shape) {}
@@ -0,0 +1,27 @@
+#[repr(u8)]
+pub enum Small {
+    SmallA,
+    SmallB,
+}
+
+#[repr(i16)]
+pub enum Medium {
+    MediumA = -1,
+    MediumB = 1,
+}
+
+#[repr(u64)]
+pub enum Large {
+    LargeA,
+    LargeB = 1099511627776,
+}
+
+/// No width is specified, so the C++ enum is left to the compiler.
+#[repr(C)]
+pub enum Unsized {
+    UnsizedA,
+    UnsizedB,
+}
+
+#[no_mangle]
+pub extern "C" fn root(s: Small, m: Medium, l: Large, u: Unsized) {}
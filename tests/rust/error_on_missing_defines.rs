@@ -0,0 +1,18 @@
+#[cfg(all(unix, target_feature = "avx"))]
+#[repr(C)]
+pub struct Wide {
+    lanes: [f32; 8],
+}
+
+#[cfg(not(all(unix, target_feature = "avx")))]
+#[repr(C)]
+pub struct Wide {
+    lanes: [f32; 4],
+}
+
+#[cfg(feature = "extra")]
+#[no_mangle]
+pub extern "C" fn extra(wide: *const Wide) {}
+
+#[no_mangle]
+pub extern "C" fn root(wide: *const Wide) {}
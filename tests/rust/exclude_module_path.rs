@@ -0,0 +1,33 @@
+mod internal {
+    #[repr(C)]
+    pub struct Secret {
+        key: u64,
+    }
+
+    #[no_mangle]
+    pub extern "C" fn internal_reset() {}
+}
+
+mod api {
+    #[repr(C)]
+    pub struct Config {
+        verbose: bool,
+    }
+
+    #[no_mangle]
+    pub extern "C" fn api_configure(config: *const Config) {}
+
+    #[no_mangle]
+    pub extern "C" fn api_debug_dump() {}
+
+    pub mod testing {
+        #[no_mangle]
+        pub extern "C" fn api_testing_hook() {}
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn root_function() {}
+
+#[no_mangle]
+pub extern "C" fn root_debug_dump() {}
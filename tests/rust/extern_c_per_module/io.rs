@@ -0,0 +1,5 @@
+#[no_mangle]
+pub static mut BUFFER_SIZE: usize = 4096;
+
+#[no_mangle]
+pub extern "C" fn flush() {}
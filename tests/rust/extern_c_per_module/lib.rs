@@ -0,0 +1,5 @@
+#[no_mangle]
+pub static VERSION: u32 = 1;
+
+#[no_mangle]
+pub extern "C" fn init() {}
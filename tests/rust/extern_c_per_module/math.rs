@@ -0,0 +1,9 @@
+#[no_mangle]
+pub extern "C" fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[no_mangle]
+pub extern "C" fn sub(a: i32, b: i32) -> i32 {
+    a - b
+}
@@ -0,0 +1,25 @@
+#[repr(C)]
+pub struct Header {
+    zeta: u8,
+    alpha: u32,
+    mu: u16,
+    beta: u64,
+}
+
+#[repr(C)]
+pub struct Pair(u64, u8);
+
+#[repr(C)]
+pub union Payload {
+    zz: u32,
+    aa: f32,
+}
+
+#[repr(C)]
+pub enum Message {
+    Ping { seq: u32, at: u64 },
+    Data(Header, Payload),
+}
+
+#[no_mangle]
+pub extern "C" fn root(h: Header, p: Pair, m: Message) {}
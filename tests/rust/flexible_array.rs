@@ -0,0 +1,43 @@
+#[repr(C)]
+struct ZeroLength {
+    len: usize,
+    data: [u8; 0],
+}
+
+#[repr(C)]
+struct Marked {
+    len: u32,
+    /// cbindgen:flexible-array
+    items: [i32; 0],
+}
+
+#[repr(C)]
+struct NotZeroLength {
+    len: u32,
+    /// cbindgen:flexible-array
+    items: [i32; 1],
+}
+
+#[repr(C)]
+struct NotLast {
+    /// cbindgen:flexible-array
+    data: [u8; 0],
+    len: usize,
+}
+
+#[repr(C)]
+struct Unmarked {
+    len: u32,
+    /// cbindgen:flexible-array=false
+    data: [u16; 0],
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: *const ZeroLength,
+    b: *const Marked,
+    c: *const NotLast,
+    d: *const Unmarked,
+    e: *const NotZeroLength,
+) {
+}
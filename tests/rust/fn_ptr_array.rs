@@ -0,0 +1,9 @@
+#[repr(C)]
+pub struct Vtable {
+    handlers: [extern "C" fn(); 4],
+    callbacks: [Option<extern "C" fn(i32) -> u8>; 2],
+    nested: [[extern "C" fn(*const u8) -> *mut i32; 2]; 3],
+    ptr: *const [extern "C" fn(); 4],
+}
+#[no_mangle]
+pub extern "C" fn root(v: Vtable) {}
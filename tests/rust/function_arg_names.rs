@@ -0,0 +1,19 @@
+#[repr(C)]
+pub struct Buffer {
+    len: usize,
+}
+
+#[no_mangle]
+pub extern "C" fn set_flags(flags: u32, enabled: bool) {}
+
+#[no_mangle]
+pub extern "C" fn buffer_fill(
+    buffer: &mut Buffer,
+    data: *const u8,
+    callback: Option<extern "C" fn(written: usize)>,
+) -> i32 {
+    0
+}
+
+#[no_mangle]
+pub extern "C" fn no_args() {}
@@ -23,3 +23,15 @@ pub unsafe extern fn print_from_rust() {
     let a = [0, 1, 2];
     array_print(&a);
 }
+
+#[no_mangle]
+pub extern "C" fn f(_: u32, x: u32) {}
+
+#[repr(C)]
+pub struct Pair {
+    first: u32,
+    second: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn destructured(Pair { first, second }: Pair, _: u32, arg1: u32) {}
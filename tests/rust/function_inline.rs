@@ -0,0 +1,23 @@
+/// `#[inline]` doesn't change the declaration.
+#[no_mangle]
+#[inline]
+pub extern "C" fn hinted(x: i32) -> i32 {
+    x
+}
+
+#[no_mangle]
+#[inline(always)]
+pub extern "C" fn always(x: i32) -> i32 {
+    x
+}
+
+#[no_mangle]
+#[inline(never)]
+pub extern "C" fn never(x: i32) -> i32 {
+    x
+}
+
+#[no_mangle]
+pub extern "C" fn plain(x: i32) -> i32 {
+    x
+}
@@ -0,0 +1,13 @@
+pub type Callback = extern "C" fn(i32) -> ();
+
+#[no_mangle]
+pub extern "C" fn implicit_unit(x: i32) {}
+
+#[no_mangle]
+pub extern "C" fn explicit_unit(x: i32) -> () {}
+
+#[no_mangle]
+pub extern "C" fn no_args() -> () {}
+
+#[no_mangle]
+pub extern "C" fn with_callback(callback: Callback, fallback: extern "C" fn() -> ()) -> () {}
@@ -0,0 +1,38 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[repr(u8)]
+pub enum Kind {
+    Solid,
+    Dashed,
+}
+
+#[repr(C)]
+pub enum Plain {
+    A,
+    B,
+}
+
+#[repr(C)]
+pub enum Shape {
+    Dot(Point),
+    Line(Point, Point),
+}
+
+#[repr(transparent)]
+pub struct Meters(f32);
+
+pub type Points = *const Point;
+
+pub struct Handle {
+    points: Vec<Point>,
+}
+
+#[no_mangle]
+pub static ORIGIN: Point = Point { x: 0, y: 0 };
+
+#[no_mangle]
+pub extern "C" fn draw(h: *mut Handle, p: Points, k: Kind, s: Shape, m: Meters, plain: Plain) -> Point {}
@@ -0,0 +1,40 @@
+#[repr(C)]
+pub struct Vec2 {
+    x: f32,
+    y: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn add_i32(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+#[no_mangle]
+pub extern "C" fn add_f32(a: f32, b: f32) -> f32 {
+    a + b
+}
+
+#[no_mangle]
+pub extern "C" fn length_Vec2(v: *const Vec2) -> f32 {
+    0.0
+}
+
+#[no_mangle]
+pub extern "C" fn length_f64(x: f64) -> f64 {
+    x
+}
+
+/// cbindgen:generic=scale
+#[no_mangle]
+pub extern "C" fn scale_vector(v: *mut Vec2, factor: f32) {}
+
+/// cbindgen:generic=scale
+#[no_mangle]
+pub extern "C" fn scale_number(x: *mut f64, factor: f32) {}
+
+/// The only function named after its first argument type with this name, so
+/// it isn't grouped.
+#[no_mangle]
+pub extern "C" fn negate_i64(x: i64) -> i64 {
+    -x
+}
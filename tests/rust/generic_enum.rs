@@ -0,0 +1,21 @@
+#[repr(C)]
+pub enum Opt<T> {
+    None,
+    Some(T),
+}
+
+#[repr(u8)]
+pub enum Tagged<T> {
+    A(T),
+    B { x: T, y: u8 },
+}
+
+pub type IntOption = Opt<i32>;
+
+#[repr(C)]
+pub enum Unused<T> {
+    X(T),
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: IntOption, b: Tagged<f32>, c: Opt<*const u8>) {}
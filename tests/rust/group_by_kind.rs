@@ -0,0 +1,39 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// Needs `Point` by value, so `Point` is moved up among the typedefs.
+pub type Position = Point;
+
+pub struct Handle {
+    id: u64,
+}
+
+#[repr(C)]
+pub struct Node {
+    position: Position,
+    shape: Shape,
+}
+
+#[repr(C)]
+pub struct Registry {
+    first: *mut Node,
+    len: usize,
+}
+
+/// Only uses `Registry` behind a pointer, so it stays with the typedefs, and
+/// `Registry` is forward-declared.
+pub type RegistryPtr = *mut Registry;
+
+#[repr(u8)]
+pub enum Shape {
+    Circle,
+    Square,
+}
+
+#[no_mangle]
+pub extern "C" fn registry_first(registry: RegistryPtr, handle: *mut Handle) -> *mut Node {
+    unimplemented!()
+}
@@ -0,0 +1,2 @@
+#[no_mangle]
+pub extern "C" fn root(x: u32) -> f64 {}
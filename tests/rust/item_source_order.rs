@@ -0,0 +1,50 @@
+#[repr(C)]
+pub struct Zebra {
+    value: i32,
+}
+
+#[repr(u8)]
+pub enum Mode {
+    Fast,
+    Slow,
+}
+
+/// Uses a type which is defined after it.
+#[repr(C)]
+pub struct Outer {
+    inner: Inner,
+    mode: Mode,
+    pair: Pair<f32>,
+}
+
+pub struct Opaque {
+    data: Vec<u8>,
+}
+
+#[repr(C)]
+pub struct Inner {
+    zebra: Zebra,
+}
+
+pub type Callback = extern "C" fn(*const Opaque);
+
+#[repr(C)]
+pub union Value {
+    as_int: i32,
+    as_float: f32,
+}
+
+#[repr(C)]
+pub enum Alpha {
+    A,
+    B,
+}
+
+#[repr(C)]
+pub struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+#[no_mangle]
+pub extern "C" fn root(outer: Outer, callback: Callback, value: Value, alpha: Alpha) {}
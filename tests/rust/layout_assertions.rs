@@ -0,0 +1,91 @@
+use std::os::raw::c_long;
+
+pub const LEN: usize = 3;
+
+#[repr(u8)]
+pub enum Kind {
+    A,
+    B,
+}
+
+#[repr(C)]
+pub struct Fixed {
+    a: u8,
+    b: u32,
+    c: u16,
+}
+
+#[repr(C)]
+pub struct Nested {
+    kind: Kind,
+    fixed: Fixed,
+    bytes: [u8; LEN],
+}
+
+#[repr(C)]
+pub union Number {
+    i: i32,
+    f: f32,
+    b: [u8; 6],
+}
+
+#[repr(C)]
+pub struct WithUnion {
+    tag: u8,
+    number: Number,
+}
+
+#[repr(C, packed)]
+pub struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C, align(16))]
+pub struct Aligned {
+    a: u8,
+}
+
+/// cbindgen:padding=3
+#[repr(C)]
+pub struct Padded {
+    a: u16,
+}
+
+#[repr(C)]
+pub struct Flexible {
+    len: u16,
+    /// cbindgen:flexible-array
+    data: [u32; 0],
+}
+
+#[repr(C)]
+pub struct WithPointer {
+    a: u8,
+    ptr: *const u8,
+}
+
+#[repr(C)]
+pub struct WithU64 {
+    a: u8,
+    b: u64,
+}
+
+#[repr(C)]
+pub struct WithLong {
+    a: c_long,
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Nested,
+    b: WithUnion,
+    c: Packed,
+    d: Aligned,
+    e: Padded,
+    f: *const Flexible,
+    g: WithPointer,
+    h: WithU64,
+    i: WithLong,
+) {
+}
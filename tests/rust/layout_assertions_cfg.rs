@@ -0,0 +1,15 @@
+#[cfg(feature = "wide")]
+#[repr(C)]
+pub struct Sample {
+    a: u32,
+    b: u32,
+}
+
+#[cfg(not(feature = "wide"))]
+#[repr(C)]
+pub struct Sample {
+    a: u8,
+}
+
+#[no_mangle]
+pub extern "C" fn sample_read(sample: *const Sample) {}
@@ -0,0 +1,22 @@
+/// Only has a lifetime parameter, so it's written like a non-generic struct.
+#[repr(C)]
+pub struct Ref<'a> {
+    ptr: &'a u32,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct Pair<'a, 'b> {
+    first: Ref<'a>,
+    second: &'b mut Ref<'a>,
+}
+
+#[repr(C, u8)]
+pub enum Borrowed<'a> {
+    Nothing,
+    Value(&'a u32),
+    Slice(Ref<'a>),
+}
+
+#[no_mangle]
+pub extern "C" fn root<'a>(pair: Pair<'a, 'a>, borrowed: Borrowed<'a>) {}
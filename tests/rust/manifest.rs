@@ -0,0 +1,29 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+/// Isn't written, so isn't listed either.
+pub struct Unused {
+    x: i32,
+}
+
+pub const ORIGIN_X: i32 = 0;
+
+#[no_mangle]
+pub static mut COUNT: u32 = 0;
+
+#[no_mangle]
+#[inline]
+pub extern "C" fn point_new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+#[export_name = "point_origin"]
+pub extern "C" fn origin() -> Point {
+    Point { x: 0, y: 0 }
+}
+
+#[export_name = "point_count"]
+pub static POINTS: u32 = 0;
@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct Outer<T> {
+    inner: Middle<T>,
+}
+
+#[repr(C)]
+pub struct Middle<T> {
+    inner: *const Inner<T>,
+}
+
+#[repr(C)]
+pub struct Inner<T> {
+    value: T,
+}
+
+#[no_mangle]
+pub extern "C" fn root(x: Outer<u8>) {}
@@ -0,0 +1,10 @@
+/// cbindgen:maybe-unused
+#[no_mangle]
+pub extern "C" fn debug_hook(level: u32) {}
+
+#[no_mangle]
+pub extern "C" fn always_used() {}
+
+/// cbindgen:maybe-unused
+#[no_mangle]
+pub static DEBUG_LEVEL: u32 = 0;
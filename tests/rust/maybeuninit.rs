@@ -1,6 +1,13 @@
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
 #[repr(C)]
 pub struct MyStruct<'a> {
     number: std::mem::MaybeUninit<&'a i32>,
+    point: std::mem::MaybeUninit<Point>,
 }
 
 pub struct NotReprC<T> {
@@ -11,3 +18,6 @@ pub type Foo<'a> = NotReprC<std::mem::MaybeUninit<&'a i32>>;
 
 #[no_mangle]
 pub extern "C" fn root<'a, 'b>(a: &'a Foo, with_maybe_uninit: &'b MyStruct) {}
+
+#[no_mangle]
+pub extern "C" fn take(with_maybe_uninit: std::mem::MaybeUninit<Point>) {}
@@ -0,0 +1,9 @@
+/// Conflicts with the `Point` and `first` of `merge_first.rs`.
+#[repr(C)]
+pub enum Point {
+    A,
+    B,
+}
+
+#[no_mangle]
+pub extern "C" fn first(p: Point) {}
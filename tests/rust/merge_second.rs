@@ -0,0 +1,8 @@
+#[repr(C)]
+pub struct Size {
+    w: u32,
+    h: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn second(s: Size) {}
@@ -0,0 +1,40 @@
+/// Typedefs come first with `group_by_kind`, but the structs they point to are
+/// moved up instead of being forward-declared.
+pub type WidgetHandle = *mut Widget;
+
+pub type VisitCallback = extern "C" fn(widget: *const Widget, style: *const Style);
+
+#[repr(C)]
+pub struct Widget {
+    style: *const Style,
+    width: u32,
+}
+
+#[repr(C)]
+pub struct Style {
+    color: u32,
+}
+
+pub type Callback = extern "C" fn(leaf: *const Leaf);
+
+#[repr(C)]
+pub struct Leaf {
+    value: u32,
+}
+
+/// Points to `Tree`, which points back to it, so only one of the two is
+/// forward-declared.
+#[repr(C)]
+pub struct Node {
+    parent: *const Tree,
+    leaf: *const Leaf,
+}
+
+#[repr(C)]
+pub struct Tree {
+    root: *mut Node,
+    callback: Callback,
+}
+
+#[no_mangle]
+pub extern "C" fn root(handle: WidgetHandle, visit: VisitCallback, tree: *const Tree) {}
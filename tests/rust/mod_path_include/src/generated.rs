@@ -0,0 +1,7 @@
+#[repr(C)]
+pub struct Generated {
+    id: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn generated_fn(g: *const Generated) {}
@@ -0,0 +1,6 @@
+mod outer;
+
+include!("generated.rs");
+
+#[no_mangle]
+pub extern "C" fn root(a: outer::Device, b: Generated) {}
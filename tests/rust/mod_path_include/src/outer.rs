@@ -0,0 +1,12 @@
+// Relative to the directory of this file, not to `outer/`.
+#[path = "platform/linux.rs"]
+mod platform;
+
+// Takes precedence over `outer/shadowed.rs`.
+#[path = "outer/real.rs"]
+mod shadowed;
+
+pub use self::platform::Device;
+
+#[no_mangle]
+pub extern "C" fn real(r: shadowed::Real) {}
@@ -0,0 +1,4 @@
+#[repr(C)]
+pub struct Real {
+    value: f32,
+}
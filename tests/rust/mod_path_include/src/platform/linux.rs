@@ -0,0 +1,7 @@
+// Modules of files loaded with `#[path]` are in the same directory.
+mod detail;
+
+#[repr(C)]
+pub struct Device {
+    handle: detail::Handle,
+}
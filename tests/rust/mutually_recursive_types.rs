@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Node {
+    parent: *mut Node,
+    edges: *const Edge,
+}
+
+#[repr(C)]
+pub struct Edge {
+    to: *mut Node,
+    next: *const Edge,
+    graph: *const Graph,
+}
+
+#[repr(C)]
+pub struct Graph {
+    root: *mut Node,
+    walk: extern "C" fn(*const Graph),
+}
+
+#[no_mangle]
+pub extern "C" fn visit(node: *mut Node) {}
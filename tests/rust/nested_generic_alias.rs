@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct Array<T> {
+    data: *mut T,
+    len: usize,
+}
+
+#[repr(C)]
+pub struct Wrap<T> {
+    inner: T,
+}
+
+pub type Matrix = Array<Array<f32>>;
+pub type Row = Array<f32>;
+pub type Deep = Wrap<Wrap<Wrap<i32>>>;
+
+#[no_mangle]
+pub extern "C" fn root(m: Matrix, r: Row, d: Deep) {}
@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Event {
+    kind: u32,
+}
+
+#[repr(C)]
+pub struct Listener {
+    /// May be null, in which case events are dropped.
+    on_event: Option<extern "C" fn(event: *const Event, user_data: *mut u8) -> bool>,
+    on_close: Option<unsafe extern "C" fn(*mut u8)>,
+    on_error: extern "C" fn(code: i32),
+    user_data: *mut u8,
+}
+
+#[no_mangle]
+pub extern "C" fn listener_attach(listener: *mut Listener) {}
@@ -0,0 +1,17 @@
+pub struct Handle {
+    data: Vec<u8>,
+}
+
+#[repr(C)]
+pub struct Options {
+    handle: *mut Handle,
+    flags: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn handle_new(options: *const Options) -> *mut Handle {
+    unimplemented!()
+}
+
+#[no_mangle]
+pub extern "C" fn handle_free(handle: *mut Handle) {}
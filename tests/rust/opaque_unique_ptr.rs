@@ -0,0 +1,35 @@
+pub struct Foo {
+    value: i32,
+}
+
+/// cbindgen:destructor=bar_release
+pub struct BarHandle {
+    value: i32,
+}
+
+pub struct Baz {
+    value: i32,
+}
+
+/// cbindgen:derive-unique-ptr=false
+pub struct Qux {
+    value: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn foo_new() -> *mut Foo {
+    Box::into_raw(Box::new(Foo { value: 0 }))
+}
+
+#[no_mangle]
+pub extern "C" fn foo_free(foo: *mut Foo) {}
+
+#[no_mangle]
+pub extern "C" fn bar_release(bar: *mut BarHandle) {}
+
+/// Takes the handle by const pointer, so it isn't a destructor.
+#[no_mangle]
+pub extern "C" fn baz_free(baz: *const Baz) {}
+
+#[no_mangle]
+pub extern "C" fn qux_free(qux: *mut Qux) {}
@@ -0,0 +1,21 @@
+#[repr(C)]
+pub struct Raw {
+    c_char: std::os::raw::c_char,
+    c_schar: std::os::raw::c_schar,
+    c_uchar: std::os::raw::c_uchar,
+    c_short: std::os::raw::c_short,
+    c_ushort: std::os::raw::c_ushort,
+    c_int: std::os::raw::c_int,
+    c_uint: std::os::raw::c_uint,
+    c_long: std::os::raw::c_long,
+    c_ulong: std::os::raw::c_ulong,
+    c_longlong: std::os::raw::c_longlong,
+    c_ulonglong: std::os::raw::c_ulonglong,
+    c_float: std::os::raw::c_float,
+    c_double: std::os::raw::c_double,
+    core_c_schar: core::ffi::c_schar,
+    libc_c_uchar: libc::c_uchar,
+}
+
+#[no_mangle]
+pub extern "C" fn root(raw: Raw) {}
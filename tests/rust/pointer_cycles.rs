@@ -0,0 +1,23 @@
+#[repr(C)]
+pub struct Owner {
+    first: *mut Node,
+    count: u32,
+}
+
+/// Holds a `Link` by value, so it's defined after it, and the structs the
+/// link points to are declared before it.
+#[repr(C)]
+pub struct Node {
+    owner: *const Owner,
+    link: Link,
+    next: *mut Node,
+}
+
+#[repr(C)]
+pub struct Link {
+    target: *mut Node,
+    weight: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(owner: Owner, node: Node) {}
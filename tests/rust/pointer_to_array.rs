@@ -0,0 +1,17 @@
+#[repr(C)]
+pub struct Buffers {
+    block: *const [u8; 4],
+    rows: *mut [[u16; 2]; 3],
+    callback: Option<extern "C" fn(&[u8; 4])>,
+}
+
+#[no_mangle]
+pub extern "C" fn by_ptr(block: *const [u8; 4]) {}
+
+#[no_mangle]
+pub extern "C" fn by_ref(block: &[u8; 4], rows: &mut [[u16; 2]; 3]) {}
+
+#[no_mangle]
+pub extern "C" fn returns_ptr(buffers: &Buffers) -> *mut [u32; 2] {
+    unimplemented!()
+}
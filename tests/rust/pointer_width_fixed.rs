@@ -0,0 +1,25 @@
+#[cfg(target_pointer_width = "32")]
+pub type Word = u32;
+
+#[cfg(target_pointer_width = "64")]
+pub type Word = u64;
+
+#[repr(C)]
+pub struct Header {
+    len: Word,
+    flags: u32,
+}
+
+#[cfg(all(target_pointer_width = "64", feature = "wide"))]
+#[no_mangle]
+pub extern "C" fn wide_only(header: &Header) {}
+
+/// Has a [defines] entry, which takes precedence over `target_pointer_width`.
+#[cfg(target_pointer_width = "16")]
+#[no_mangle]
+pub extern "C" fn narrow_only(header: &Header) {}
+
+#[no_mangle]
+pub extern "C" fn root(header: &Header) -> Word {
+    0
+}
@@ -0,0 +1,20 @@
+#[cfg(target_pointer_width = "32")]
+pub type Word = u32;
+
+#[cfg(target_pointer_width = "64")]
+pub type Word = u64;
+
+#[repr(C)]
+pub struct Header {
+    len: Word,
+    flags: u32,
+}
+
+#[cfg(all(target_pointer_width = "64", feature = "wide"))]
+#[no_mangle]
+pub extern "C" fn wide_only(header: &Header) {}
+
+#[no_mangle]
+pub extern "C" fn root(header: &Header) -> Word {
+    0
+}
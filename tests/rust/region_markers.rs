@@ -0,0 +1,25 @@
+pub const LIMIT: u32 = 16;
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point { x: 0, y: 0 };
+}
+
+#[repr(u8)]
+pub enum Kind {
+    Small,
+    Large,
+}
+
+pub type Points = [Point; 4];
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub extern "C" fn root(p: Point, k: Kind, ps: *const Points) {}
@@ -39,9 +39,9 @@ pub const I: isize = 10 as *mut F as isize;
 pub extern "C" fn root(
     a: *const A,
     b: B,
-    c: C,
+    c: *const C,
     d: D,
     e: E,
-    f: F,
+    f: *const F,
 ) { }
 
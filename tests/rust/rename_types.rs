@@ -0,0 +1,49 @@
+#[repr(C)]
+pub struct PointF {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct LineSegment {
+    start: PointF,
+    end: PointF,
+}
+
+#[repr(C)]
+pub struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+#[repr(u8)]
+pub enum DrawMode {
+    Fill,
+    Stroke,
+}
+
+#[repr(C)]
+pub union IntOrFloat {
+    i: i32,
+    f: f32,
+}
+
+pub struct OpaqueCanvas;
+
+pub type PointPair = Pair<PointF>;
+
+/// Explicitly renamed, so not converted.
+#[repr(C)]
+pub struct KeepName {
+    mode: DrawMode,
+}
+
+#[no_mangle]
+pub extern "C" fn draw_line(
+    canvas: *mut OpaqueCanvas,
+    line: LineSegment,
+    points: PointPair,
+    value: IntOrFloat,
+    keep: KeepName,
+) {
+}
@@ -0,0 +1,16 @@
+pub const MAX_LEN: usize = 32;
+pub const ALIAS_LEN: usize = MAX_LEN;
+pub const SHIFTED_LEN: usize = 1 << 2;
+
+#[repr(C)]
+pub struct Buffer {
+    data: [u8; MAX_LEN],
+    aliased: [u8; ALIAS_LEN],
+    nested: [[u16; MAX_LEN]; 2],
+    unresolved: [u8; SHIFTED_LEN],
+}
+
+pub type Name = [u8; MAX_LEN];
+
+#[no_mangle]
+pub extern "C" fn root(buffer: Buffer, name: *const Name) {}
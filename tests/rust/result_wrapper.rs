@@ -0,0 +1,59 @@
+#[repr(C)]
+pub enum ErrorCode {
+    Ok,
+    InvalidArgument,
+    OutOfMemory,
+}
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+/// Has no `#[repr]`, so it can't be returned through a wrapper.
+pub struct Name {
+    text: String,
+}
+
+/// cbindgen:result-wrapper=reset_ffi
+pub fn reset() -> Result<(), ErrorCode> {
+    Ok(())
+}
+
+/// cbindgen:result-wrapper=parse_count_ffi
+pub fn parse_count(text: *const u8, len: usize) -> Result<u32, ErrorCode> {
+    Ok(0)
+}
+
+/// cbindgen:result-wrapper=make_point_ffi
+pub fn make_point(x: f32, y: f32) -> Result<Point, i32> {
+    Ok(Point { x, y })
+}
+
+/// cbindgen:result-wrapper=find_point_ffi
+pub fn find_point(id: u32) -> Result<*const Point, ErrorCode> {
+    Err(ErrorCode::InvalidArgument)
+}
+
+/// cbindgen:result-wrapper=read_string_ffi
+pub fn read_string() -> Result<String, ErrorCode> {
+    Ok(String::new())
+}
+
+/// cbindgen:result-wrapper=read_name_ffi
+pub fn read_name() -> Result<Name, ErrorCode> {
+    Ok(Name {
+        text: String::new(),
+    })
+}
+
+/// cbindgen:result-wrapper=clashing_ffi
+pub fn clashing(out: *mut u32) -> Result<u32, ErrorCode> {
+    Ok(0)
+}
+
+/// Not declared, since it has no wrapper.
+pub fn unwrapped() -> Result<u32, ErrorCode> {
+    Ok(0)
+}
@@ -0,0 +1,32 @@
+#[repr(C)]
+pub struct Exported {
+    x: i32,
+}
+
+#[cfg_attr(cbindgen, ffi::skip)]
+#[repr(C)]
+pub struct Internal {
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(e: Exported) {}
+
+#[ffi::skip]
+#[no_mangle]
+pub extern "C" fn internal_fn(i: Internal) {}
+
+pub struct Handle;
+
+impl Handle {
+    #[no_mangle]
+    pub extern "C" fn handle_new() -> i32 {
+        0
+    }
+
+    #[allow(ffi::skip)]
+    #[no_mangle]
+    pub extern "C" fn handle_internal() -> i32 {
+        0
+    }
+}
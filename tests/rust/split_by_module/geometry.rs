@@ -0,0 +1,13 @@
+pub const MAX_POINTS: usize = 16;
+
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Rect {
+    origin: Point,
+    size: Point,
+}
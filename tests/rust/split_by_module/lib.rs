@@ -0,0 +1,19 @@
+#[repr(C)]
+pub struct Context {
+    canvas: render::Canvas,
+    scale: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn context_new(width: u32, height: u32) -> Context {
+    unimplemented!()
+}
+
+/// Only uses `limits` for the length of its array.
+#[repr(C)]
+pub struct Name {
+    chars: [u8; limits::NAME_LEN],
+}
+
+#[no_mangle]
+pub extern "C" fn name_init(name: *mut Name) {}
@@ -0,0 +1 @@
+pub const NAME_LEN: usize = 16;
@@ -0,0 +1,8 @@
+#[repr(C)]
+pub struct Canvas {
+    bounds: geometry::Rect,
+    color: Color,
+}
+
+#[no_mangle]
+pub extern "C" fn canvas_draw_point(canvas: *mut Canvas, point: geometry::Point) {}
@@ -0,0 +1,11 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub struct Rect {
+    origin: Point,
+    size: Point,
+}
@@ -0,0 +1,13 @@
+mod geometry;
+mod render;
+
+#[repr(C)]
+pub struct Context {
+    canvas: render::Canvas,
+    scale: f32,
+}
+
+#[no_mangle]
+pub extern "C" fn context_new(width: u32, height: u32) -> Context {
+    unimplemented!()
+}
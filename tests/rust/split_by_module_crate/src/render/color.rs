@@ -0,0 +1,7 @@
+/// In a nested module, so it's written with its top-level module, `render`.
+#[repr(C)]
+pub struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+}
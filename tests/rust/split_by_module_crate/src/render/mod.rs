@@ -0,0 +1,13 @@
+mod color;
+
+use crate::geometry;
+pub use self::color::Color;
+
+#[repr(C)]
+pub struct Canvas {
+    bounds: geometry::Rect,
+    color: Color,
+}
+
+#[no_mangle]
+pub extern "C" fn canvas_draw_point(canvas: *mut Canvas, point: geometry::Point) {}
@@ -0,0 +1,4 @@
+#[no_mangle]
+pub extern "C" fn point_length(p: Point) -> f32 {
+    (p.x * p.x + p.y * p.y).sqrt()
+}
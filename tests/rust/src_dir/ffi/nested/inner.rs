@@ -0,0 +1,5 @@
+#[repr(C)]
+pub struct Points {
+    points: [Point; 16],
+    len: usize,
+}
@@ -0,0 +1,6 @@
+mod inner;
+
+#[no_mangle]
+pub extern "C" fn points_count(points: *const Points) -> usize {
+    0
+}
@@ -0,0 +1,7 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+pub const MAX_POINTS: usize = 16;
@@ -0,0 +1,16 @@
+pub const LEN: usize = 4;
+
+#[repr(C)]
+pub struct Entry {
+    key: u32,
+    value: f32,
+}
+
+#[no_mangle]
+pub static TABLE: [u32; 4] = [1, 2, 3, 4];
+
+#[no_mangle]
+pub static ENTRIES: [Entry; LEN] = [Entry { key: 0, value: 0.0 }; LEN];
+
+#[no_mangle]
+pub static mut GRID: [[u8; 2]; 3] = [[0; 2]; 3];
@@ -0,0 +1,18 @@
+#[repr(C)]
+pub struct Counters {
+    hits: u32,
+    misses: u32,
+}
+
+/// Exported under a different symbol name.
+#[export_name = "custom_counters"]
+pub static mut COUNTERS: Counters = Counters { hits: 0, misses: 0 };
+
+#[export_name = "library_version"]
+pub static VERSION: u32 = 3;
+
+#[used]
+#[no_mangle]
+pub static KEEP_ME: u8 = 1;
+
+pub static NOT_EXPORTED: u8 = 2;
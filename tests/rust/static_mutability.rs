@@ -0,0 +1,43 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+extern "C" fn noop() {}
+
+#[no_mangle]
+pub static ORIGIN: Point = Point { x: 0.0, y: 0.0 };
+
+#[no_mangle]
+pub static mut CURSOR: Point = Point { x: 0.0, y: 0.0 };
+
+#[no_mangle]
+pub static VERSION: u32 = 1;
+
+#[no_mangle]
+pub static mut COUNTER: u32 = 0;
+
+#[no_mangle]
+pub static NAME: *const u8 = 0 as *const u8;
+
+#[no_mangle]
+pub static mut NAME_MUT: *const u8 = 0 as *const u8;
+
+#[no_mangle]
+pub static SCRATCH: *mut u8 = 0 as *mut u8;
+
+#[no_mangle]
+pub static mut SCRATCH_MUT: *mut u8 = 0 as *mut u8;
+
+#[no_mangle]
+pub static CALLBACK: extern "C" fn() = noop;
+
+#[no_mangle]
+pub static mut CALLBACK_MUT: extern "C" fn() = noop;
+
+#[no_mangle]
+pub static TABLE: [u8; 4] = [0; 4];
+
+#[no_mangle]
+pub static mut BUFFER: [u8; 4] = [0; 4];
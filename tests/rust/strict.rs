@@ -0,0 +1,24 @@
+/// Not `#[repr(C)]`, accepted as excluded.
+pub struct Handle {
+    id: u32,
+}
+
+/// Private items are skipped quietly.
+struct Private {
+    id: u32,
+}
+
+#[repr(C)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[no_mangle]
+pub extern "C" fn point_new(x: i32, y: i32) -> Point {
+    Point { x, y }
+}
+
+/// Unsupported argument type, accepted as excluded.
+#[no_mangle]
+pub extern "C" fn handle_visit(visitor: impl Fn(u32)) {}
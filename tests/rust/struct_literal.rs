@@ -20,4 +20,4 @@ pub const BAR: Foo = Foo { a: 42, b: 1337, };
 pub const BAZZ: Bar = Bar { a: 42, b: 1337, };
 
 #[no_mangle]
-pub extern "C" fn root(x: Foo, bar: Bar) { }
+pub extern "C" fn root(x: Foo, bar: *const Bar) { }
@@ -0,0 +1,25 @@
+/// cbindgen:padding=4
+#[repr(C)]
+struct Padded {
+    a: u32,
+    b: u8,
+}
+
+/// cbindgen:padding=16
+#[repr(C)]
+struct OnlyPadding;
+
+/// cbindgen:padding=0
+#[repr(C)]
+struct ZeroPadding {
+    a: u32,
+}
+
+/// cbindgen:padding=four
+#[repr(C)]
+struct InvalidPadding {
+    a: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(a: Padded, b: OnlyPadding, c: *const ZeroPadding, d: *const InvalidPadding) {}
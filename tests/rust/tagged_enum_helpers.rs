@@ -0,0 +1,29 @@
+#[repr(C)]
+pub struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[repr(C)]
+pub enum Shape {
+    Circle(Point, f32),
+    Rect { min: Point, max: Point },
+    Empty,
+}
+
+#[repr(u8)]
+pub enum Value {
+    Int(i32),
+    Float(f32),
+    Nothing,
+}
+
+/// cbindgen:tagged-enum-helpers=false
+#[repr(C)]
+pub enum Plain {
+    A(i32),
+    B,
+}
+
+#[no_mangle]
+pub extern "C" fn root(s: Shape, v: Value, p: Plain) {}
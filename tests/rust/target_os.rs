@@ -0,0 +1,54 @@
+#[cfg(target_os = "linux")]
+#[repr(C)]
+pub struct EpollInstance {
+    fd: i32,
+}
+
+#[cfg(target_os = "windows")]
+#[repr(C)]
+pub struct Handle {
+    raw: *mut u8,
+}
+
+#[repr(u8)]
+pub enum Backend {
+    Poll,
+    #[cfg(target_os = "linux")]
+    Epoll,
+    #[cfg(target_os = "macos")]
+    Kqueue,
+    #[cfg(target_os = "windows")]
+    Iocp,
+}
+
+#[repr(C)]
+pub struct Options {
+    flags: u32,
+    backend: Backend,
+}
+
+#[cfg(target_os = "linux")]
+#[no_mangle]
+pub extern "C" fn epoll_create(options: Options) -> EpollInstance {
+    unimplemented!()
+}
+
+#[cfg(target_os = "windows")]
+#[no_mangle]
+pub extern "C" fn handle_open(options: Options) -> Handle {
+    unimplemented!()
+}
+
+#[cfg(any(target_os = "macos", target_os = "ios"))]
+#[no_mangle]
+pub extern "C" fn kqueue_create() -> i32 {
+    0
+}
+
+#[cfg(all(not(target_os = "windows"), target_arch = "x86_64"))]
+#[no_mangle]
+pub extern "C" fn unix_x86_64_only() {}
+
+#[cfg(all(target_os = "linux", feature = "io_uring"))]
+#[no_mangle]
+pub extern "C" fn io_uring_setup() {}
@@ -0,0 +1,18 @@
+#[repr(C)]
+pub struct Vec2<T> {
+    x: T,
+    y: T,
+}
+
+#[repr(C)]
+pub struct Pair<K, V> {
+    key: K,
+    value: V,
+}
+
+pub type IntVec = Vec2<i32>;
+pub type FloatVec = Vec2<f32>;
+pub type Entry = Pair<u32, IntVec>;
+
+#[no_mangle]
+pub extern "C" fn root(a: IntVec, b: FloatVec, c: Entry, d: Vec2<u8>) {}
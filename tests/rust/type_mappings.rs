@@ -0,0 +1,19 @@
+/// Written as `time_t` rather than as a typedef of `int64_t`.
+pub type Timestamp = i64;
+
+/// Opaque in Rust, written as `FILE`.
+pub struct File {
+    _private: [u8; 0],
+}
+
+/// Its mapping to a pointer type is ignored, so it's written as a typedef.
+pub type Buffer = *mut u8;
+
+#[repr(C)]
+pub struct Entry {
+    modified: Timestamp,
+    file: *mut File,
+}
+
+#[no_mangle]
+pub extern "C" fn root(entry: Entry, now: Timestamp, out: Option<&mut File>, buffer: Buffer) {}
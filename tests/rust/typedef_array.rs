@@ -0,0 +1,13 @@
+pub type Mat4 = [[f32; 4]; 4];
+
+pub type Row = [f32; 4];
+
+#[repr(C)]
+pub struct Camera {
+    view: Mat4,
+    projection: Mat4,
+    origin: Row,
+}
+
+#[no_mangle]
+pub extern "C" fn root(camera: Camera, transform: *const Mat4) {}
@@ -0,0 +1,17 @@
+pub type Buf = *mut u8;
+pub type ConstBuf = *const u8;
+pub type ConstPtrToMut = *const *mut u8;
+pub type MutPtrToConst = *mut *const u8;
+pub type ConstRef = &'static u32;
+pub type MutRef = &'static mut u32;
+
+#[no_mangle]
+pub extern "C" fn root(
+    a: Buf,
+    b: ConstBuf,
+    c: ConstPtrToMut,
+    d: MutPtrToConst,
+    e: ConstRef,
+    f: MutRef,
+) {
+}
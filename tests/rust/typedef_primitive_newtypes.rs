@@ -0,0 +1,24 @@
+/// A distance in meters.
+#[repr(C)]
+pub struct Meters(f64);
+
+#[repr(C)]
+pub struct Id {
+    value: u32,
+}
+
+impl Id {
+    pub const INVALID: Id = Id { value: 0 };
+}
+
+#[repr(C)]
+pub struct Handle(*mut u8);
+
+#[repr(C)]
+pub struct Pair {
+    a: u32,
+    b: u32,
+}
+
+#[no_mangle]
+pub extern "C" fn root(m: Meters, id: Id, h: Handle, p: Pair) {}
@@ -0,0 +1,24 @@
+#[repr(C)]
+pub union MyUnion {
+    as_int: u32,
+    as_float: f32,
+    as_bytes: [u8; 4],
+}
+
+#[repr(C)]
+pub union GenericUnion<T: Copy> {
+    value: T,
+    raw: u64,
+}
+
+pub type Alias = MyUnion;
+
+#[no_mangle]
+pub extern "C" fn f(u: MyUnion) -> MyUnion {
+    u
+}
+
+#[no_mangle]
+pub extern "C" fn g(u: GenericUnion<f64>, alias: Alias) -> GenericUnion<f64> {
+    u
+}
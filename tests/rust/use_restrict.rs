@@ -0,0 +1,16 @@
+#[repr(C)]
+pub struct Buffer {
+    data: *mut u8,
+    len: usize,
+}
+
+impl Buffer {
+    #[no_mangle]
+    pub extern "C" fn buffer_clear(&mut self) {}
+}
+
+#[no_mangle]
+pub extern "C" fn copy(dst: &mut Buffer, src: &Buffer, raw: *mut Buffer, len: &mut usize) {}
+
+#[no_mangle]
+pub extern "C" fn optional(dst: Option<&mut Buffer>) {}
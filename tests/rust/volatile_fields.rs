@@ -0,0 +1,43 @@
+#[repr(C)]
+pub struct Registers {
+    /// cbindgen:volatile
+    status: u32,
+    /// cbindgen:volatile
+    data: *const u32,
+    /// cbindgen:volatile
+    control: *mut u8,
+    /// cbindgen:volatile
+    fifo: [u16; 4],
+    plain: *const u32,
+}
+
+#[repr(C)]
+pub struct Wrapper<T> {
+    /// cbindgen:volatile
+    value: T,
+}
+
+#[repr(C)]
+pub struct Flags {
+    /// cbindgen:bitfield=3
+    /// cbindgen:volatile
+    mode: u32,
+    /// cbindgen:bitfield=5
+    rest: u32,
+}
+
+/// Function pointers can't be volatile, so this is opaque.
+#[repr(C)]
+pub struct Callback {
+    /// cbindgen:volatile
+    f: extern "C" fn(),
+}
+
+#[no_mangle]
+pub extern "C" fn root(
+    registers: *mut Registers,
+    wrapper: Wrapper<i32>,
+    flags: Flags,
+    callback: *const Callback,
+) {
+}
@@ -0,0 +1,15 @@
+#[repr(C)]
+pub struct Packet {
+    len: u32,
+    payload: [u8; 0],
+}
+
+#[repr(C)]
+pub struct Marked {
+    count: u32,
+    /// cbindgen:flexible-array
+    items: [u16; 0],
+}
+
+#[no_mangle]
+pub extern "C" fn packet_send(packet: *const Packet, marked: *const Marked) {}